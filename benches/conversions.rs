@@ -0,0 +1,103 @@
+//! Compares the integer based `From`/`Into` conversions of `packed = false`
+//! bitfields with the byte based conversions of packed bitfields.
+//!
+//! Also compares the byte copying `to_le_bytes` of byte-aligned `packed = false`
+//! bitfields with the general conversion through their primitive representation.
+
+#![allow(dead_code)]
+
+mod utils;
+
+use criterion::{
+    black_box,
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use modular_bitfield::{
+    bitfield,
+    specifiers::{
+        B16,
+        B8,
+    },
+};
+use utils::repeat;
+
+criterion_group!(bench_conversions, bench_conversion_variants);
+criterion_main!(bench_conversions);
+
+#[bitfield]
+#[derive(Clone)]
+pub struct Packed {
+    r: u8,
+    g: u8,
+    len: u16,
+    tag: B16,
+}
+
+#[bitfield(bits = 60, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    r: u8,
+    g: u8,
+    len: u16,
+    tag: B16,
+}
+
+#[bitfield(bits = 60, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Aligned {
+    r: u8,
+    g: B8,
+    len: u16,
+}
+
+fn bench_conversion_variants(c: &mut Criterion) {
+    let mut g = c.benchmark_group("conversions");
+    g.bench_function("packed_from_le_bytes", |b| {
+        b.iter(|| {
+            repeat(|| {
+                black_box(Packed::from_le_bytes(black_box([1, 2, 3, 4, 5, 6])));
+            })
+        });
+    });
+    g.bench_function("unpacked_from_le_bytes", |b| {
+        b.iter(|| {
+            repeat(|| {
+                black_box(Unpacked::from_le_bytes(black_box([1, 2, 3, 4, 5, 6, 0, 0])).unwrap());
+            })
+        });
+    });
+    g.bench_function("packed_to_le_bytes", |b| {
+        let input = Packed::new().with_r(1).with_g(2).with_len(3).with_tag(4);
+        b.iter(|| {
+            repeat(|| {
+                black_box(black_box(input.clone()).to_le_bytes());
+            })
+        });
+    });
+    g.bench_function("unpacked_to_le_bytes", |b| {
+        let input = Unpacked::new().with_r(1).with_g(2).with_len(3).with_tag(4);
+        b.iter(|| {
+            repeat(|| {
+                black_box(black_box(input.clone()).to_le_bytes());
+            })
+        });
+    });
+    g.bench_function("unpacked_aligned_to_le_bytes", |b| {
+        let input = Aligned::new().with_r(1).with_g(2).with_len(3);
+        b.iter(|| {
+            repeat(|| {
+                black_box(black_box(input.clone()).to_le_bytes());
+            })
+        });
+    });
+    g.bench_function("unpacked_aligned_to_le_bytes_via_repr", |b| {
+        let input = Aligned::new().with_r(1).with_g(2).with_len(3);
+        b.iter(|| {
+            repeat(|| {
+                black_box(u64::from(black_box(input.clone())).to_le_bytes());
+            })
+        });
+    });
+}
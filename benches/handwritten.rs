@@ -51,6 +51,7 @@ pub struct Handwritten {
     data: [u8; 8],
 }
 
+#[allow(clippy::new_without_default)]
 impl Handwritten {
     /// Creates a new hand-written struct initialized with all zeros.
     pub fn new() -> Self {
@@ -86,7 +87,7 @@ impl Handwritten {
         let mut res = 0;
         res |= (self.data[1] >> 7) as u16;
         res |= (self.data[2] as u16) << 1;
-        res |= (((self.data[3] & 0b0000_1111) as u16) << 9) as u16;
+        res |= ((self.data[3] & 0b0000_1111) as u16) << 9;
         res
     }
 
@@ -4,13 +4,20 @@ use super::{
         ReprKind,
     },
     field_config::{
+        ChecksumConfig,
+        ChecksumEnd,
         FieldConfig,
         SkipWhich,
     },
+    field_info::{
+        FieldInfo,
+        ImplTraitAccessor,
+    },
     BitfieldStruct,
 };
 use crate::errors::CombineError;
 use core::convert::TryFrom;
+use proc_macro2::Span;
 use quote::quote;
 use std::collections::HashMap;
 use syn::{
@@ -28,6 +35,27 @@ impl TryFrom<(&mut Config, syn::ItemStruct)> for BitfieldStruct {
         Self::extract_attributes(&item_struct.attrs, config)?;
         Self::analyse_config_for_fields(&item_struct, config)?;
         config.ensure_no_conflicts()?;
+        Self::ensure_transparent_precondition(&item_struct, config)?;
+        Self::ensure_deref_precondition(&item_struct, config)?;
+        Self::ensure_from_field_precondition(&item_struct, config)?;
+        Self::ensure_widening_from_precondition(config)?;
+        Self::ensure_checksum_precondition(&item_struct, config)?;
+        Self::ensure_payload_len_precondition(&item_struct, config)?;
+        Self::ensure_extract_as_precondition(config)?;
+        Self::ensure_const_enum_precondition(config)?;
+        Self::ensure_non_copy_precondition(config)?;
+        Self::ensure_magic_precondition(config)?;
+        Self::ensure_units_precondition(config)?;
+        Self::ensure_sentinel_precondition(&item_struct, config)?;
+        Self::ensure_tag_precondition(&item_struct, config)?;
+        Self::ensure_variants_precondition(&item_struct, config)?;
+        Self::ensure_ord_precondition(config)?;
+        Self::ensure_impl_trait_precondition(&item_struct, config)?;
+        Self::ensure_float_view_precondition(&item_struct, config)?;
+        Self::ensure_no_accessor_collisions(&item_struct, config)?;
+        Self::ensure_no_const_collisions(&item_struct, config)?;
+        Self::ensure_plain_precondition(&item_struct, config)?;
+        Self::ensure_debug_precondition(config)?;
         Ok(Self { item_struct })
     }
 }
@@ -45,7 +73,18 @@ impl BitfieldStruct {
     }
 
     /// Returns an error if the input struct is generic.
+    ///
+    /// Const generic widths are reported separately since their `[u8; (N + 7) / 8]` storage
+    /// would require the unstable `generic_const_exprs` feature.
     fn ensure_no_generics(item_struct: &syn::ItemStruct) -> Result<()> {
+        if let Some(param) = item_struct.generics.const_params().next() {
+            return Err(format_err!(
+                param.ident,
+                "encountered const generic parameter `{}` for a bitfield struct, generic bit widths \
+                 are not supported since their storage cannot be sized on stable Rust",
+                param.ident,
+            ))
+        }
         if !item_struct.generics.params.is_empty() {
             return Err(format_err_spanned!(
                 item_struct,
@@ -55,6 +94,705 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns an error if `deref` is set for a struct that is not a single unpacked field
+    /// or if `deref_mut` is set without `deref`.
+    fn ensure_deref_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if let (Some(deref_mut), None) = (config.deref_mut.as_ref(), config.deref.as_ref()) {
+            return Err(format_err!(
+                deref_mut.span,
+                "encountered `deref_mut` parameter without `deref` parameter"
+            ))
+        }
+        if let Some(deref) = config.deref.as_ref() {
+            if item_struct.fields.len() != 1 {
+                return Err(format_err!(
+                    deref.span,
+                    "encountered `deref` parameter for a bitfield struct with {} fields, expected exactly 1 field",
+                    item_struct.fields.len(),
+                ))
+            }
+            if config.packed_enabled() {
+                return Err(format_err!(
+                    deref.span,
+                    "encountered `deref` parameter for a packed bitfield struct, expected `packed = false`"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `from_field` is set for a struct that is not a single primitive field
+    /// of a packed bitfield without `#[repr(uN)]`.
+    fn ensure_from_field_precondition(
+        item_struct: &syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        let from_field = match config.from_field.as_ref() {
+            Some(from_field) => from_field,
+            None => return Ok(()),
+        };
+        if item_struct.fields.len() != 1 {
+            return Err(format_err!(
+                from_field.span,
+                "encountered `from_field` parameter for a bitfield struct with {} fields, expected exactly 1 field",
+                item_struct.fields.len(),
+            ))
+        }
+        if !config.packed_enabled() {
+            return Err(format_err!(
+                from_field.span,
+                "encountered `from_field` parameter for an unpacked bitfield struct, expected `packed = true`"
+            ))
+        }
+        if let Some(repr) = config.repr.as_ref() {
+            return Err(format_err!(
+                from_field.span,
+                "encountered `from_field` parameter for a bitfield struct with `#[repr(uN)]`"
+            )
+            .into_combine(format_err!(repr.span, "conflicting `#[repr(uN)]` here")))
+        }
+        for (index, field) in Self::fields(item_struct) {
+            let field_config = config
+                .field_configs
+                .get(&index)
+                .map(|field_config| field_config.value.clone())
+                .unwrap_or_default();
+            let info = FieldInfo::new(index, field, field_config);
+            if !matches!(info.primitive_bits(), Some(1..=64)) {
+                return Err(format_err!(
+                    field.ty,
+                    "encountered field of invalid type for `from_field` parameter, expected a primitive specifier of at most 64 bits"
+                ))
+            }
+            if info.config.skip_setters() || info.cfg_attrs().next().is_some() {
+                return Err(format_err_spanned!(
+                    field,
+                    "encountered field with skipped setters or `#[cfg(..)]` for `from_field` parameter"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `widening_from` is set for a packed bitfield without `#[repr(uN)]`.
+    ///
+    /// Unpacked bitfields always have an integer representation derived from their `bits = N`.
+    fn ensure_widening_from_precondition(config: &Config) -> Result<()> {
+        if let Some(widening_from) = config.widening_from.as_ref() {
+            if config.packed_enabled() && !config.integer_repr_enabled() {
+                return Err(format_err!(
+                    widening_from.span,
+                    "encountered `widening_from` parameter for a packed bitfield struct without `#[repr(uN)]`"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `plain` is set for a tuple struct.
+    fn ensure_plain_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        if let Some(plain) = config.plain.as_ref() {
+            if !matches!(item_struct.fields, syn::Fields::Named(_)) {
+                return Err(format_err!(
+                    plain.span,
+                    "encountered `plain` parameter for a bitfield struct without named fields"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `debug = kind` is set without a generated `Debug` impl.
+    fn ensure_debug_precondition(config: &Config) -> Result<()> {
+        if let Some(debug) = config.debug.as_ref() {
+            if !config.packed_enabled() {
+                return Err(format_err!(
+                    debug.span,
+                    "encountered `debug` parameter for an unpacked bitfield struct, expected `packed = true`"
+                ))
+            }
+            if config.derive_debug.is_none() {
+                return Err(format_err!(
+                    debug.span,
+                    "encountered `debug` parameter for a bitfield struct without `#[derive(Debug)]`"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the getter or setter of a field collides with a method that is
+    /// generated for every bitfield struct, e.g. the getter of a field named `new`.
+    fn ensure_no_accessor_collisions(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        const METHODS: &[&str] = &[
+            "new",
+            "from_le_bytes",
+            "to_le_bytes",
+            "load_le_bytes",
+            "bytes_len",
+            "swap_bytes",
+            "update_byte_le",
+            "update_byte_be",
+            "try_update_byte_be",
+            "is_zero",
+            "is_all_ones",
+            "bit_offset_of",
+        ];
+        const PACKED_METHODS: &[&str] = &[
+            "from_le_bytes_at",
+            "parse_le",
+            "from_bits_iter",
+            "from_bytes_args",
+            "as_bytes_tuple",
+            "from_bits",
+            "to_bits",
+            "write_le_into",
+            "to_u32_words",
+            "from_u32_words",
+            "rotate_left",
+            "rotate_right",
+            "reverse_bits",
+            "hamming_distance",
+            "add_bcd",
+            "try_set_many",
+        ];
+        const UNPACKED_METHODS: &[&str] = &["from_be_bytes", "to_be_bytes"];
+        const BIT_VIEW_METHODS: &[&str] = &[
+            "bit",
+            "set_bit",
+            "iter_bits",
+            "get_bits",
+            "bits_in",
+            "set_bits",
+            "set_bits_range",
+        ];
+        const REPR_METHODS: &[&str] = &[
+            "diff",
+            "changed_fields",
+            "masked",
+            "swap_raw",
+            "patch",
+            "truncated_to_bits",
+        ];
+        const PACKED_REPR_METHODS: &[&str] = &["contains"];
+        // Unpacked bitfields always convert to and from the integer type of their bits.
+        let has_integer_repr = !config.packed_enabled() || config.integer_repr_enabled();
+        let collides = |name: &str| {
+            METHODS.contains(&name)
+                || config.packed_enabled() && PACKED_METHODS.contains(&name)
+                || !config.packed_enabled() && UNPACKED_METHODS.contains(&name)
+                || has_integer_repr && REPR_METHODS.contains(&name)
+                || has_integer_repr
+                    && config.packed_enabled()
+                    && PACKED_REPR_METHODS.contains(&name)
+                || config.bit_view.is_some() && BIT_VIEW_METHODS.contains(&name)
+        };
+        for (index, field) in Self::fields(item_struct) {
+            let ident = match field.ident.as_ref() {
+                Some(ident) => ident,
+                None => continue,
+            };
+            let field_config = config.field_configs.get(&index).map(|config| &config.value);
+            let name = ident.to_string();
+            let name = name.strip_prefix("r#").unwrap_or(&name);
+            let getter = Some(name.to_string())
+                .filter(|_| !field_config.is_some_and(FieldConfig::skip_getters));
+            let setter = Some(format!("set_{}", name))
+                .filter(|_| !field_config.is_some_and(FieldConfig::skip_setters));
+            if let Some(method) = getter.into_iter().chain(setter).find(|method| collides(method)) {
+                return Err(format_err!(
+                    ident,
+                    "encountered field `{}` whose accessor collides with the generated `{}` method, \
+                     consider renaming the field",
+                    name,
+                    method,
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if two fields generate associated constants of the same name.
+    ///
+    /// The `F_MASK` constants of packed bitfields with an integer repr as well as the
+    /// `F_OFFSET` and `F_WIDTH` constants of the `layout` parameter are named after the
+    /// field in upper case, so fields only differing in case would collide.
+    fn ensure_no_const_collisions(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let has_masks = config.packed_enabled() && config.integer_repr_enabled();
+        let suffix = match (has_masks, config.layout.is_some()) {
+            (true, _) => "_MASK",
+            (false, true) => "_OFFSET",
+            (false, false) => return Ok(()),
+        };
+        let mut seen = HashMap::new();
+        for (index, field) in Self::fields(item_struct) {
+            let ident = match field.ident.as_ref() {
+                Some(ident) => ident,
+                None => continue,
+            };
+            if config
+                .field_configs
+                .get(&index)
+                .is_some_and(|config| config.value.skip_all())
+            {
+                continue
+            }
+            let name = syn::ext::IdentExt::unraw(ident).to_string();
+            let const_name = format!("{}{}", name.to_uppercase(), suffix);
+            if let Some(previous) = seen.insert(const_name.clone(), ident) {
+                return Err(format_err!(
+                    ident,
+                    "encountered field `{}` whose generated `{}` constant collides with the one \
+                     of field `{}`, consider renaming the field",
+                    name,
+                    const_name,
+                    syn::ext::IdentExt::unraw(previous),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[checksum(..)]` field is not the only one of a packed bitfield
+    /// struct or does not cover a valid range of other fields.
+    fn ensure_checksum_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut checksums = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.checksum.as_ref()?))
+            })
+            .collect::<Vec<_>>();
+        checksums.sort_by_key(|(index, _)| *index);
+        let mut checksums = checksums.into_iter();
+        let (checksum_index, checksum) = match checksums.next() {
+            Some(checksum) => checksum,
+            None => return Ok(()),
+        };
+        if let Some((_, duplicate)) = checksums.next() {
+            return Err(format_err!(
+                duplicate.span,
+                "encountered multiple `#[checksum(..)]` fields in a bitfield struct"
+            )
+            .into_combine(format_err!(checksum.span, "other `#[checksum(..)]` field here")))
+        }
+        if !config.packed_enabled() {
+            return Err(format_err!(
+                checksum.span,
+                "encountered `#[checksum(..)]` field for an unpacked bitfield struct"
+            ))
+        }
+        let index_of = |name: &syn::Ident| {
+            Self::fields(item_struct)
+                .find(|(_, field)| field.ident.as_ref() == Some(name))
+                .map(|(index, _)| index)
+                .ok_or_else(|| {
+                    format_err!(name, "encountered unknown field `{}` in #[checksum(..)]", name)
+                })
+        };
+        let start = index_of(&checksum.value.start)?;
+        let end = match &checksum.value.end {
+            ChecksumEnd::Exclusive(end) => index_of(end)?,
+            ChecksumEnd::Inclusive(end) => index_of(end)? + 1,
+            ChecksumEnd::Open => item_struct.fields.len(),
+        };
+        if start >= end {
+            return Err(format_err!(
+                checksum.value.start,
+                "encountered empty field range `{}` in #[checksum(..)]",
+                checksum.value.range_to_string(),
+            ))
+        }
+        if (start..end).contains(&checksum_index) {
+            return Err(format_err!(
+                checksum.span,
+                "encountered `#[checksum(..)]` field covering itself"
+            ))
+        }
+        if Self::fields(item_struct).any(|(_, field)| {
+            field.ident.as_ref().is_some_and(|ident| ident == "checksum")
+        }) {
+            // The generated `set_checksum` would clash with the setter of the field.
+            return Err(format_err!(
+                checksum.span,
+                "encountered `#[checksum(..)]` in a bitfield struct with a field named `checksum`"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if there are multiple `#[payload_len]` fields, if the bitfield is
+    /// unpacked or if a field collides with the generated `payload_len` or `split_at`.
+    fn ensure_payload_len_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut payload_lens = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.payload_len.as_ref()?))
+            })
+            .collect::<Vec<_>>();
+        payload_lens.sort_by_key(|(index, _)| *index);
+        let mut payload_lens = payload_lens.into_iter();
+        let payload_len = match payload_lens.next() {
+            Some((_, payload_len)) => payload_len,
+            None => return Ok(()),
+        };
+        if let Some((_, duplicate)) = payload_lens.next() {
+            return Err(format_err!(
+                duplicate.span,
+                "encountered multiple `#[payload_len]` fields in a bitfield struct"
+            )
+            .into_combine(format_err!(payload_len.span, "other `#[payload_len]` field here")))
+        }
+        if !config.packed_enabled() {
+            return Err(format_err!(
+                payload_len.span,
+                "encountered `#[payload_len]` field for an unpacked bitfield struct"
+            ))
+        }
+        if let Some(ident) = Self::fields(item_struct)
+            .filter_map(|(_, field)| field.ident.as_ref())
+            .find(|ident| *ident == "payload_len" || *ident == "split_at")
+        {
+            // The generated methods would clash with the getter of the field.
+            return Err(format_err!(
+                payload_len.span,
+                "encountered `#[payload_len]` in a bitfield struct with a field named `{}`",
+                ident,
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field has an `#[extract_as(..)]` attribute in an unpacked bitfield.
+    fn ensure_extract_as_precondition(config: &Config) -> Result<()> {
+        if config.packed_enabled() {
+            return Ok(())
+        }
+        let extract_as = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.extract_as.as_ref()?))
+            })
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, extract_as)) = extract_as {
+            return Err(format_err!(
+                extract_as.span,
+                "encountered `#[extract_as(..)]` field for an unpacked bitfield struct"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if an `impl_trait = Trait(a, set_a)` parameter names a method that is
+    /// neither the getter nor the setter of a field.
+    fn ensure_impl_trait_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for impl_trait in &config.impl_traits {
+            for method in &impl_trait.value.methods {
+                let accessor = ImplTraitAccessor::resolve(item_struct, method).ok_or_else(|| {
+                    format_err!(
+                        method,
+                        "encountered unknown accessor `{}` in `impl_trait` parameter",
+                        method
+                    )
+                })?;
+                let skipped = match accessor {
+                    ImplTraitAccessor::Getter(_) => "getters",
+                    ImplTraitAccessor::Setter(_) => "setters",
+                };
+                let is_skipped = config
+                    .field_configs
+                    .get(&accessor.index())
+                    .is_some_and(|field_config| match accessor {
+                        ImplTraitAccessor::Getter(_) => field_config.value.skip_getters(),
+                        ImplTraitAccessor::Setter(_) => field_config.value.skip_setters(),
+                    });
+                if is_skipped {
+                    return Err(format_err!(
+                        method,
+                        "encountered accessor `{}` of a field with skipped {} in `impl_trait` parameter",
+                        method,
+                        skipped,
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field of the `float_view` parameter is unknown, not an unsigned
+    /// integer specifier such as `B5` or `u8` or has a skipped getter.
+    fn ensure_float_view_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let float_view = match config.float_view.as_ref() {
+            Some(float_view) => &float_view.value,
+            None => return Ok(()),
+        };
+        for name in [&float_view.mantissa, &float_view.exponent] {
+            let (index, field) = Self::fields(item_struct)
+                .find(|(_, field)| field.ident.as_ref() == Some(name))
+                .ok_or_else(|| {
+                    format_err!(
+                        name,
+                        "encountered unknown field `{}` in `float_view` parameter",
+                        name
+                    )
+                })?;
+            let field_config = config
+                .field_configs
+                .get(&index)
+                .map(|field_config| field_config.value.clone())
+                .unwrap_or_default();
+            let info = FieldInfo::new(index, field, field_config);
+            if info.is_bool() || !info.is_primitive_specifier() {
+                return Err(format_err!(
+                    field.ty,
+                    "encountered field `{}` of invalid type in `float_view` parameter, expected an unsigned integer specifier",
+                    name
+                ))
+            }
+            if info.config.skip_getters() {
+                return Err(format_err!(
+                    name,
+                    "encountered field `{}` with skipped getters in `float_view` parameter",
+                    name
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field has a `#[magic = N]` attribute in an unpacked bitfield.
+    fn ensure_magic_precondition(config: &Config) -> Result<()> {
+        if config.packed_enabled() {
+            return Ok(())
+        }
+        let magic = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.magic.as_ref()?))
+            })
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, magic)) = magic {
+            return Err(format_err!(
+                magic.span,
+                "encountered `#[magic = N]` field for an unpacked bitfield struct"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field has a `#[units = ".."]` attribute in an unpacked bitfield.
+    ///
+    /// Unpacked bitfields do not generate their own `Debug` impl which could display the units.
+    fn ensure_units_precondition(config: &Config) -> Result<()> {
+        if config.packed_enabled() {
+            return Ok(())
+        }
+        let units = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.units.as_ref()?))
+            })
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, units)) = units {
+            return Err(format_err!(
+                units.span,
+                "encountered `#[units = \"..\"]` field for an unpacked bitfield struct"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[sentinel = N]` field is part of an unpacked bitfield,
+    /// a tuple struct whose `get_N` getters already exist or a bitfield with the `max_const`
+    /// parameter whose `MAX` might hold the sentinel.
+    fn ensure_sentinel_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let sentinel = match config.field_configs.get(&index) {
+                Some(field_config) => match field_config.value.sentinel.as_ref() {
+                    Some(sentinel) => sentinel,
+                    None => continue,
+                },
+                None => continue,
+            };
+            if !config.packed_enabled() {
+                return Err(format_err!(
+                    sentinel.span,
+                    "encountered `#[sentinel = N]` field for an unpacked bitfield struct"
+                ))
+            }
+            if field.ident.is_none() {
+                return Err(format_err!(
+                    sentinel.span,
+                    "encountered `#[sentinel = N]` field for a tuple struct"
+                ))
+            }
+            if let Some(max_const) = config.max_const.as_ref() {
+                return Err(format_err!(
+                    sentinel.span,
+                    "encountered `#[sentinel = N]` field for a bitfield struct with `max_const` parameter"
+                )
+                .into_combine(format_err!(max_const.span, "`max_const` parameter here")))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[tag]` field is part of an unpacked bitfield or a tuple struct
+    /// or if more than one field is marked as `#[tag]`.
+    fn ensure_tag_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        let mut previous: Option<Span> = None;
+        for (index, field) in Self::fields(item_struct) {
+            let tag = match config.field_configs.get(&index) {
+                Some(field_config) => match field_config.value.tag.as_ref() {
+                    Some(tag) => tag,
+                    None => continue,
+                },
+                None => continue,
+            };
+            if !config.packed_enabled() {
+                return Err(format_err!(
+                    tag.span,
+                    "encountered `#[tag]` field for an unpacked bitfield struct"
+                ))
+            }
+            if field.ident.is_none() {
+                return Err(format_err!(
+                    tag.span,
+                    "encountered `#[tag]` field for a tuple struct"
+                ))
+            }
+            if let Some(previous) = previous {
+                return Err(format_err!(
+                    tag.span,
+                    "encountered more than one `#[tag]` field"
+                )
+                .into_combine(format_err!(previous, "previous `#[tag]` field here")))
+            }
+            previous = Some(tag.span);
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a `#[variants(..)]` field is part of an unpacked bitfield or if its
+    /// type is not a plain path to an enum whose variants can be named.
+    fn ensure_variants_precondition(item_struct: &syn::ItemStruct, config: &Config) -> Result<()> {
+        for (index, field) in Self::fields(item_struct) {
+            let variants = match config.field_configs.get(&index) {
+                Some(field_config) => match field_config.value.variants.as_ref() {
+                    Some(variants) => variants,
+                    None => continue,
+                },
+                None => continue,
+            };
+            if !config.packed_enabled() {
+                return Err(format_err!(
+                    variants.span,
+                    "encountered `#[variants(..)]` field for an unpacked bitfield struct"
+                ))
+            }
+            if !matches!(&field.ty, syn::Type::Path(ty) if ty.qself.is_none()) {
+                return Err(format_err_spanned!(
+                    field.ty,
+                    "encountered `#[variants(..)]` field whose type is not a path to an enum"
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `ord = fields` is used while a field has no getter to compare.
+    ///
+    /// The bits of such a field would not take part in the comparison which would make
+    /// the generated `Ord` implementation disagree with `PartialEq`.
+    fn ensure_ord_precondition(config: &Config) -> Result<()> {
+        let ord = match config.ord.as_ref() {
+            Some(ord) => ord,
+            None => return Ok(()),
+        };
+        let skipped = config
+            .field_configs
+            .iter()
+            .filter(|(_, field_config)| field_config.value.skip_getters())
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, field_config)) = skipped {
+            return Err(format_err!(
+                ord.span,
+                "encountered `ord = fields` parameter for a bitfield with a field without getters"
+            )
+            .into_combine(format_err!(field_config.span, "field without getters here")))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field has a `#[const_enum]` attribute in an unpacked bitfield.
+    fn ensure_const_enum_precondition(config: &Config) -> Result<()> {
+        if config.packed_enabled() {
+            return Ok(())
+        }
+        let const_enum = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.const_enum.as_ref()?))
+            })
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, const_enum)) = const_enum {
+            return Err(format_err!(
+                const_enum.span,
+                "encountered `#[const_enum]` field for an unpacked bitfield struct"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a field has a `#[non_copy]` attribute in a packed bitfield.
+    fn ensure_non_copy_precondition(config: &Config) -> Result<()> {
+        if !config.packed_enabled() {
+            return Ok(())
+        }
+        let non_copy = config
+            .field_configs
+            .iter()
+            .filter_map(|(index, field_config)| {
+                Some((*index, field_config.value.non_copy.as_ref()?))
+            })
+            .min_by_key(|(index, _)| *index);
+        if let Some((_, non_copy)) = non_copy {
+            return Err(format_err!(
+                non_copy.span,
+                "encountered `#[non_copy]` field for a packed bitfield struct"
+            ))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if `transparent` is set for a struct that is not a single filled field.
+    fn ensure_transparent_precondition(
+        item_struct: &syn::ItemStruct,
+        config: &Config,
+    ) -> Result<()> {
+        if let Some(transparent) = config.transparent.as_ref() {
+            if item_struct.fields.len() != 1 {
+                return Err(format_err!(
+                    transparent.span,
+                    "encountered `transparent` parameter for a bitfield struct with {} fields, expected exactly 1 field",
+                    item_struct.fields.len(),
+                ))
+            }
+            if !config.filled_enabled() {
+                return Err(format_err!(
+                    transparent.span,
+                    "encountered `transparent` parameter for an unfilled bitfield struct"
+                ))
+            }
+        }
+        Ok(())
+    }
+
     /// Extracts the `#[repr(uN)]` annotations from the given `#[bitfield]` struct.
     fn extract_repr_attribute(attr: &syn::Attribute, config: &mut Config) -> Result<()> {
         let path = &attr.path;
@@ -106,7 +844,8 @@ impl BitfieldStruct {
         Ok(())
     }
 
-    /// Extracts the `#[derive(Debug)]` annotations from the given `#[bitfield]` struct.
+    /// Extracts the `#[derive(Debug)]` and other intercepted derive annotations from the given
+    /// `#[bitfield]` struct.
     fn extract_derive_debug_attribute(
         attr: &syn::Attribute,
         config: &mut Config,
@@ -121,6 +860,26 @@ impl BitfieldStruct {
                 syn::NestedMeta::Meta(syn::Meta::Path(path)) => {
                     if path.is_ident("Debug") && config.packed_enabled() {
                         config.derive_debug(meta_span)?;
+                    } else if Self::is_defmt_format_path(&path)
+                        && cfg!(feature = "defmt")
+                        && config.packed_enabled()
+                    {
+                        config.derive_defmt(meta_span)?;
+                    } else if path.is_ident("Hash") && config.packed_enabled() {
+                        config.derive_hash(meta_span)?;
+                    } else if path.is_ident("Clone") || path.is_ident("Copy") {
+                        if let Some(clone_copy) = config.clone_copy.as_ref() {
+                            return Err(format_err!(
+                                meta_span,
+                                "encountered redundant #[derive({})] for a bitfield struct with `clone_copy` parameter",
+                                path.get_ident().expect("checked to be an identifier above"),
+                            )
+                            .into_combine(format_err!(
+                                clone_copy.span,
+                                "`clone_copy` parameter here"
+                            )))
+                        }
+                        retained_derives.push(syn::NestedMeta::Meta(syn::Meta::Path(path)));
                     } else if path.is_ident("BitfieldSpecifier") {
                         config.derive_specifier(meta_span)?;
                     } else {
@@ -150,6 +909,16 @@ impl BitfieldStruct {
         Ok(())
     }
 
+    /// Returns `true` if the given derive path is either `Format` or `defmt::Format`.
+    fn is_defmt_format_path(path: &syn::Path) -> bool {
+        let segments = path.segments.iter().map(|segment| &segment.ident).collect::<Vec<_>>();
+        match segments.as_slice() {
+            [format] => *format == "Format",
+            [defmt, format] => *defmt == "defmt" && *format == "Format",
+            _ => false,
+        }
+    }
+
     /// Analyses and extracts the `#[repr(uN)]` or other annotations from the given struct.
     fn extract_attributes(
         attributes: &[syn::Attribute],
@@ -172,15 +941,41 @@ impl BitfieldStruct {
         item_struct: &syn::ItemStruct,
         config: &mut Config,
     ) -> Result<()> {
+        let mut current_group: Option<(syn::Ident, Span)> = None;
+        let mut closed_groups: HashMap<syn::Ident, Span> = HashMap::new();
         for (index, field) in Self::fields(item_struct) {
             let span = field.span();
             let field_config = Self::extract_field_config(field)?;
+            let group = field_config.group.as_ref();
+            let same_group = matches!(
+                (&current_group, group),
+                (Some((current, _)), Some(group)) if *current == group.value
+            );
+            if !same_group {
+                if let Some((closed, last_span)) = current_group.take() {
+                    closed_groups.insert(closed, last_span);
+                }
+                if let Some(group) = group {
+                    if let Some(previous) = closed_groups.get(&group.value) {
+                        return Err(format_err!(
+                            group.span,
+                            "fields of group `{}` must be adjacent",
+                            group.value
+                        )
+                        .into_combine(format_err!(*previous, "previous field of group here")))
+                    }
+                }
+            }
+            current_group = group.map(|group| (group.value.clone(), group.span));
             config.field_config(index, span, field_config)?;
         }
         Ok(())
     }
 
-    /// Extracts the `#[bits = N]` and `#[skip(..)]` attributes for a given field.
+    /// Extracts the `#[bits = N]`, `#[skip(..)]`, `#[reserved]`, `#[hidden]`, `#[deprecated]`,
+    /// `#[units = ".."]`, `#[checksum(..)]`, `#[eq(ignore)]`, `#[payload_len]`, `#[extract_as(..)]`,
+    /// `#[const_enum]`, `#[non_copy]`, `#[magic = N]`, `#[sentinel = N]`, `#[tag]`,
+    /// `#[variants(..)]` and `#[group(..)]` attributes for a given field.
     fn extract_field_config(field: &syn::Field) -> Result<FieldConfig> {
         let mut config = FieldConfig::default();
         for attr in &field.attrs {
@@ -270,6 +1065,174 @@ impl BitfieldStruct {
                         ))
                     }
                 }
+            } else if attr.path.is_ident("group") {
+                let span = attr.span();
+                let name = attr.parse_args::<syn::Ident>().map_err(|_| {
+                    format_err!(
+                        span,
+                        "encountered invalid format for #[group(name)] field attribute"
+                    )
+                })?;
+                config.group(name, span)?;
+            } else if attr.path.is_ident("reserved") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[reserved] field attribute"
+                    ))
+                }
+                config.reserved(span)?;
+            } else if attr.path.is_ident("hidden") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[hidden] field attribute"
+                    ))
+                }
+                config.hidden(span)?;
+                // The generated accessors re-expand all retained attributes.
+                config.retain_attr(syn::parse_quote_spanned!(span=> #[doc(hidden)]));
+            } else if attr.path.is_ident("units") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Str(lit_str) => {
+                        config.units(lit_str.value(), span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[units = \"..\"]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("magic") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args }).map_err(|_| {
+                        format_err!(
+                            attr.span(),
+                            "encountered invalid format for #[magic = N] field attribute"
+                        )
+                    })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.magic(lit_int.base10_parse::<u128>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[magic = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("sentinel") {
+                let path = &attr.path;
+                let args = &attr.tokens;
+                let name_value: syn::MetaNameValue =
+                    syn::parse2::<_>(quote! { #path #args }).map_err(|_| {
+                        format_err!(
+                            attr.span(),
+                            "encountered invalid format for #[sentinel = N] field attribute"
+                        )
+                    })?;
+                let span = name_value.span();
+                match name_value.lit {
+                    syn::Lit::Int(lit_int) => {
+                        config.sentinel(lit_int.base10_parse::<u128>()?, span)?;
+                    }
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid value type for #[sentinel = N]"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("eq") {
+                let span = attr.span();
+                match attr.parse_args::<syn::Ident>() {
+                    Ok(ident) if ident == "ignore" => config.eq_ignore(span)?,
+                    _ => {
+                        return Err(format_err!(
+                            span,
+                            "encountered invalid format for #[eq(ignore)] field attribute"
+                        ))
+                    }
+                }
+            } else if attr.path.is_ident("payload_len") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[payload_len] field attribute"
+                    ))
+                }
+                config.payload_len(span)?;
+            } else if attr.path.is_ident("tag") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[tag] field attribute"
+                    ))
+                }
+                config.tag(span)?;
+            } else if attr.path.is_ident("non_copy") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[non_copy] field attribute"
+                    ))
+                }
+                config.non_copy(span)?;
+            } else if attr.path.is_ident("const_enum") {
+                let span = attr.span();
+                if !attr.tokens.is_empty() {
+                    return Err(format_err!(
+                        span,
+                        "encountered invalid format for #[const_enum] field attribute"
+                    ))
+                }
+                config.const_enum(span)?;
+            } else if attr.path.is_ident("variants") {
+                let span = attr.span();
+                let variants = attr
+                    .parse_args_with(
+                        syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_separated_nonempty,
+                    )
+                    .map_err(|_| {
+                        format_err!(
+                            span,
+                            "encountered invalid format for #[variants(A, B)] field attribute"
+                        )
+                    })?;
+                config.variants(variants.into_iter().collect(), span)?;
+            } else if attr.path.is_ident("extract_as") {
+                let span = attr.span();
+                let name = attr.parse_args::<syn::Ident>().map_err(|_| {
+                    format_err!(
+                        span,
+                        "encountered invalid format for #[extract_as(Name)] field attribute"
+                    )
+                })?;
+                config.extract_as(name, span)?;
+            } else if attr.path.is_ident("checksum") {
+                let span = attr.span();
+                config.checksum(attr.parse_args::<ChecksumConfig>()?, span)?;
+            } else if attr.path.is_ident("deprecated") {
+                let span = attr.span();
+                config.deprecated(span)?;
+                // The generated accessors call each other and must not warn about that.
+                config.retain_attr(attr.clone());
+                config.retain_attr(syn::parse_quote_spanned!(span=> #[allow(deprecated)]));
             } else {
                 config.retain_attr(attr.clone());
             }
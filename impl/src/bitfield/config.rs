@@ -18,26 +18,132 @@ pub struct Config {
     pub bits: Option<ConfigValue<usize>>,
     pub filled: Option<ConfigValue<bool>>,
     pub packed: Option<ConfigValue<bool>>,
+    pub reorder: Option<ConfigValue<bool>>,
     pub repr: Option<ConfigValue<ReprKind>>,
+    pub transparent: Option<ConfigValue<()>>,
+    pub clone_copy: Option<ConfigValue<()>>,
+    pub track_caller: Option<ConfigValue<()>>,
+    pub zero_const: Option<ConfigValue<()>>,
+    pub max_const: Option<ConfigValue<()>>,
+    pub iter_flags: Option<ConfigValue<()>>,
+    pub allow_empty: Option<ConfigValue<()>>,
+    pub bit_view: Option<ConfigValue<()>>,
+    pub ord: Option<ConfigValue<OrdKind>>,
+    pub endian: Option<ConfigValue<EndianKind>>,
+    pub on_overflow: Option<ConfigValue<OverflowKind>>,
+    pub debug: Option<ConfigValue<DebugKind>>,
+    pub octal: Option<ConfigValue<()>>,
+    pub decoder: Option<ConfigValue<()>>,
+    pub storage: Option<ConfigValue<syn::Ident>>,
+    pub index_by: Option<ConfigValue<syn::Ident>>,
+    pub deref: Option<ConfigValue<()>>,
+    pub deref_mut: Option<ConfigValue<()>>,
+    pub from_field: Option<ConfigValue<()>>,
+    pub widening_from: Option<ConfigValue<()>>,
+    pub accessors_in_trait: Option<ConfigValue<()>>,
+    pub layout: Option<ConfigValue<()>>,
+    pub max_align: Option<ConfigValue<()>>,
+    pub from_str: Option<ConfigValue<()>>,
+    pub chained_setters: Option<ConfigValue<()>>,
+    pub views: Vec<ConfigValue<ViewConfig>>,
+    pub impl_traits: Vec<ConfigValue<ImplTraitConfig>>,
+    pub float_view: Option<ConfigValue<FloatViewConfig>>,
+    pub enumerate: Option<ConfigValue<()>>,
+    pub plain: Option<ConfigValue<()>>,
+    pub borrowed_view: Option<ConfigValue<()>>,
+    pub rich_errors: Option<ConfigValue<()>>,
+    pub by_value_getters: Option<ConfigValue<()>>,
+    pub bcd: Option<ConfigValue<()>>,
+    pub serde: Option<ConfigValue<()>>,
     pub derive_debug: Option<ConfigValue<()>>,
+    pub derive_defmt: Option<ConfigValue<()>>,
+    pub derive_hash: Option<ConfigValue<()>>,
     pub derive_specifier: Option<ConfigValue<()>>,
     pub retained_attributes: Vec<syn::Attribute>,
     pub field_configs: HashMap<usize, ConfigValue<FieldConfig>>,
 }
 
+/// Kinds of orderings generated by the `ord = kind` parameter for a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OrdKind {
+    /// Lexicographic ordering by the values of the fields in declaration order.
+    Fields,
+}
+
+/// Kinds of byte orders generated by the `endian = kind` parameter for a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EndianKind {
+    /// Both the little endian and the big endian families of byte conversions.
+    Both,
+}
+
+/// Kinds of overflow handling selected by the `on_overflow = kind` parameter for a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OverflowKind {
+    /// Out of bounds values are clamped to the maximum value instead of being rejected.
+    Saturate,
+}
+
+/// Kinds of `Debug` output generated by the `debug = kind` parameter for a `#[bitfield]` struct.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DebugKind {
+    /// The values of the fields followed by the raw underlying bytes in hex.
+    Verbose,
+    /// The values of the fields grouped by the byte containing their first bit.
+    ByByte,
+    /// The values of the fields as comma separated `name=value` pairs.
+    Compact,
+}
+
+/// A byte range of a `#[bitfield]` struct exposed via the `view(Name = start..end)` parameter.
+#[derive(Clone)]
+pub struct ViewConfig {
+    /// The name of the generated `#[repr(C)]` view struct.
+    pub name: syn::Ident,
+    /// The index of the first byte covered by the view.
+    pub start: usize,
+    /// The index one past the last byte covered by the view.
+    pub end: usize,
+}
+
+/// The fields of a custom float layout exposed via the `float_view(mantissa = m, exponent = e)`
+/// parameter.
+#[derive(Debug, Clone)]
+pub struct FloatViewConfig {
+    /// The field holding the unsigned mantissa.
+    pub mantissa: syn::Ident,
+    /// The field holding the unsigned biased exponent.
+    pub exponent: syn::Ident,
+    /// The bias subtracted from the exponent, zero unless given via `bias = N`.
+    pub bias: i32,
+}
+
+/// A user provided trait implemented via the `impl_trait = Trait(a, set_a)` parameter.
+#[derive(Clone)]
+pub struct ImplTraitConfig {
+    /// The path of the implemented trait.
+    pub path: syn::Path,
+    /// The names of the getters and setters implementing the methods of the trait.
+    pub methods: Vec<syn::Ident>,
+}
+
 /// Kinds of `#[repr(uN)]` annotations for a `#[bitfield]` struct.
 #[derive(Copy, Clone)]
 pub enum ReprKind {
     /// Found a `#[repr(u8)]` annotation.
-    U8 = 8,
+    U8,
     /// Found a `#[repr(u16)]` annotation.
-    U16 = 16,
+    U16,
     /// Found a `#[repr(u32)]` annotation.
-    U32 = 32,
+    U32,
     /// Found a `#[repr(u64)]` annotation.
-    U64 = 64,
+    U64,
     /// Found a `#[repr(u128)]` annotation.
-    U128 = 128,
+    U128,
+    /// Found a `repr = [u8; N]` parameter with `N` bytes.
+    ByteArray(usize),
+    /// Found a `repr = bool` parameter for a single-bit bitfield.
+    Bool,
 }
 
 impl ReprKind {
@@ -61,6 +167,8 @@ impl ReprKind {
             Self::U32 => 32,
             Self::U64 => 64,
             Self::U128 => 128,
+            Self::ByteArray(len) => len * 8,
+            Self::Bool => 1,
         }
     }
 
@@ -72,13 +180,19 @@ impl ReprKind {
             ReprKind::U32 => quote! { ::core::primitive::u32 },
             ReprKind::U64 => quote! { ::core::primitive::u64 },
             ReprKind::U128 => quote! { ::core::primitive::u128 },
+            ReprKind::ByteArray(len) => quote! { [::core::primitive::u8; #len] },
+            ReprKind::Bool => quote! { ::core::primitive::bool },
         }
     }
 }
 
 impl core::fmt::Debug for ReprKind {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-        write!(f, "#[repr(u{})]", self.bits())
+        match self {
+            Self::ByteArray(len) => write!(f, "repr = [u8; {}]", len),
+            Self::Bool => write!(f, "repr = bool"),
+            _ => write!(f, "#[repr(u{})]", self.bits()),
+        }
     }
 }
 
@@ -104,7 +218,35 @@ impl Config {
         self.filled
             .as_ref()
             .map(|config| config.value)
-            .unwrap_or(true)
+            .unwrap_or(!self.repr_is_bool())
+    }
+
+    /// Returns the name of the byte array storing the bits of a packed bitfield.
+    ///
+    /// This is `bytes` with the given span unless renamed via the `storage = name` parameter.
+    pub fn storage_ident(&self, span: Span) -> syn::Ident {
+        self.storage
+            .as_ref()
+            .map(|storage| storage.value.clone())
+            .unwrap_or_else(|| syn::Ident::new("bytes", span))
+    }
+
+    /// Returns `true` if the `repr = bool` parameter has been provided.
+    fn repr_is_bool(&self) -> bool {
+        matches!(
+            self.repr,
+            Some(ConfigValue {
+                value: ReprKind::Bool,
+                ..
+            })
+        )
+    }
+
+    /// Returns `true` if the bitfield has a `#[repr(uN)]` of an unsigned integer type.
+    pub fn integer_repr_enabled(&self) -> bool {
+        self.repr
+            .as_ref()
+            .is_some_and(|repr| !matches!(repr.value, ReprKind::ByteArray(_) | ReprKind::Bool))
     }
 
     /// Returns the value of the `packed` parameter if provided and otherwise `true`.
@@ -117,7 +259,20 @@ impl Config {
 
     fn ensure_no_bits_and_repr_conflict(&self) -> Result<()> {
         if let (Some(bits), Some(repr)) = (self.bits.as_ref(), self.repr.as_ref()) {
-            if bits.value != repr.value.bits() {
+            if bits.value > repr.value.bits() {
+                return Err(format_err!(
+                    bits.span,
+                    "encountered `bits = {}` exceeding the {} bits of {:?}",
+                    bits.value,
+                    repr.value.bits(),
+                    repr.value,
+                )
+                .into_combine(format_err!(repr.span, "{:?} here", repr.value)))
+            }
+            // Unpacked bitfields only use the `repr` as storage which may
+            // have unused bits if the bitfield is not filled.
+            let unused_bits_allowed = !self.packed_enabled() && !self.filled_enabled();
+            if bits.value != repr.value.bits() && !unused_bits_allowed {
                 return Err(format_err!(
                     Span::call_site(),
                     "encountered conflicting `bits = {}` and {:?} parameters",
@@ -138,9 +293,14 @@ impl Config {
     }
 
     pub fn ensure_no_repr_and_filled_conflict(&self) -> Result<()> {
-        if let (Some(repr), Some(filled @ ConfigValue { value: false, .. })) =
-            (self.repr.as_ref(), self.filled.as_ref())
-        {
+        // A `repr = bool` bitfield consists of a single bit and thus can never be filled.
+        let filled_required = !self.repr_is_bool();
+        if let (Some(repr), Some(filled)) = (self.repr.as_ref(), self.filled.as_ref()) {
+            // Unfilled unpacked bitfields define their width via `bits = N` instead.
+            let unpacked_with_bits = !self.packed_enabled() && self.bits.is_some();
+            if filled.value == filled_required || unpacked_with_bits {
+                return Ok(())
+            }
             return Err(format_err!(
                 Span::call_site(),
                 "encountered conflicting `{:?}` and `filled = {}` parameters",
@@ -161,10 +321,347 @@ impl Config {
         Ok(())
     }
 
+    fn ensure_no_byte_array_repr_and_unpacked_conflict(&self) -> Result<()> {
+        if let (
+            Some(repr @ ConfigValue { value: ReprKind::ByteArray(_) | ReprKind::Bool, .. }),
+            Some(packed @ ConfigValue { value: false, .. }),
+        ) = (self.repr.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `{:?}` and `packed = {}` parameters",
+                repr.value,
+                packed.value,
+            )
+            .into_combine(format_err!(
+                repr.span,
+                "conflicting `{:?}` here",
+                repr.value
+            ))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_storage_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(storage), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.storage.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `storage = {}` and `packed = {}` parameters",
+                storage.value,
+                packed.value,
+            )
+            .into_combine(format_err!(
+                storage.span,
+                "conflicting `storage = {}` here",
+                storage.value,
+            ))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_views_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(view), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.views.first(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `view({})` and `packed = {}` parameters",
+                view.value.name,
+                packed.value,
+            )
+            .into_combine(format_err!(
+                view.span,
+                "conflicting `view({})` here",
+                view.value.name,
+            ))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_enumerate_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(enumerate), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.enumerate.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `enumerate` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(enumerate.span, "conflicting `enumerate` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_zero_const_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(zero_const), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.zero_const.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `zero_const` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(zero_const.span, "conflicting `zero_const` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_max_const_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(max_const), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.max_const.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `max_const` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(max_const.span, "conflicting `max_const` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_on_overflow_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(on_overflow), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.on_overflow.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `on_overflow` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(on_overflow.span, "conflicting `on_overflow` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_bit_view_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(bit_view), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.bit_view.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `bit_view` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(bit_view.span, "conflicting `bit_view` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_borrowed_view_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(borrowed_view), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.borrowed_view.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `borrowed_view` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(borrowed_view.span, "conflicting `borrowed_view` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_rich_errors_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(rich_errors), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.rich_errors.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `rich_errors` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(rich_errors.span, "conflicting `rich_errors` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_by_value_getters_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(by_value_getters), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.by_value_getters.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `by_value_getters` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(by_value_getters.span, "conflicting `by_value_getters` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_bcd_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(bcd), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.bcd.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `bcd` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(bcd.span, "conflicting `bcd` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_max_align_and_unpacked_conflict(&self) -> Result<()> {
+        if let (Some(max_align), Some(packed @ ConfigValue { value: false, .. })) =
+            (self.max_align.as_ref(), self.packed.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `max_align` and `packed = {}` parameters",
+                packed.value,
+            )
+            .into_combine(format_err!(max_align.span, "conflicting `max_align` here"))
+            .into_combine(format_err!(
+                packed.span,
+                "conflicting `packed = {}` here",
+                packed.value,
+            )))
+        }
+        Ok(())
+    }
+
+    fn ensure_no_max_align_and_transparent_conflict(&self) -> Result<()> {
+        if let (Some(max_align), Some(transparent)) =
+            (self.max_align.as_ref(), self.transparent.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `max_align` and `transparent` parameters",
+            )
+            .into_combine(format_err!(max_align.span, "conflicting `max_align` here"))
+            .into_combine(format_err!(transparent.span, "conflicting `transparent` here")))
+        }
+        Ok(())
+    }
+
+    /// Returns an error if both `impl_trait` and `accessors_in_trait` parameters are given.
+    ///
+    /// The accessors of the generated extension trait call each other by method syntax which
+    /// would be ambiguous with the methods of the implemented trait.
+    fn ensure_no_impl_trait_and_accessors_in_trait_conflict(&self) -> Result<()> {
+        if let (Some(impl_trait), Some(accessors_in_trait)) =
+            (self.impl_traits.first(), self.accessors_in_trait.as_ref())
+        {
+            return Err(format_err!(
+                Span::call_site(),
+                "encountered conflicting `impl_trait` and `accessors_in_trait` parameters",
+            )
+            .into_combine(format_err!(impl_trait.span, "conflicting `impl_trait` here"))
+            .into_combine(format_err!(
+                accessors_in_trait.span,
+                "conflicting `accessors_in_trait` here"
+            )))
+        }
+        Ok(())
+    }
+
+    /// Returns the alignment in bytes for the `max_align` parameter.
+    ///
+    /// This is the size of the smallest native integer type able to hold the bits of the
+    /// bitfield as given by its `bits = N` parameter or `#[repr(uN)]` annotation.
+    ///
+    /// Returns `None` if the `max_align` parameter is missing or the bit width is unknown.
+    pub fn max_align_bytes(&self) -> Option<usize> {
+        self.max_align.as_ref()?;
+        let bits = match (self.bits.as_ref(), self.repr.as_ref()) {
+            (Some(bits), _) => bits.value,
+            (None, Some(repr)) => repr.value.bits(),
+            (None, None) => return None,
+        };
+        let bytes = bits.div_ceil(8);
+        Some(bytes.next_power_of_two().clamp(1, 16))
+    }
+
     /// Ensures that there are no conflicting configuration parameters.
     pub fn ensure_no_conflicts(&self) -> Result<()> {
         self.ensure_no_bits_and_repr_conflict()?;
         self.ensure_no_repr_and_filled_conflict()?;
+        self.ensure_no_byte_array_repr_and_unpacked_conflict()?;
+        self.ensure_no_zero_const_and_unpacked_conflict()?;
+        self.ensure_no_max_const_and_unpacked_conflict()?;
+        self.ensure_no_on_overflow_and_unpacked_conflict()?;
+        self.ensure_no_bit_view_and_unpacked_conflict()?;
+        self.ensure_no_storage_and_unpacked_conflict()?;
+        self.ensure_no_max_align_and_unpacked_conflict()?;
+        self.ensure_no_views_and_unpacked_conflict()?;
+        self.ensure_no_enumerate_and_unpacked_conflict()?;
+        self.ensure_no_borrowed_view_and_unpacked_conflict()?;
+        self.ensure_no_rich_errors_and_unpacked_conflict()?;
+        self.ensure_no_by_value_getters_and_unpacked_conflict()?;
+        self.ensure_no_bcd_and_unpacked_conflict()?;
+        self.ensure_no_max_align_and_transparent_conflict()?;
+        self.ensure_no_impl_trait_and_accessors_in_trait_conflict()?;
+        if let (Some(max_align), None) = (self.max_align.as_ref(), self.max_align_bytes()) {
+            return Err(format_err!(
+                max_align.span,
+                "encountered `max_align` parameter without `bits = N` parameter or `#[repr(uN)]`"
+            ))
+        }
         Ok(())
     }
 
@@ -239,6 +736,407 @@ impl Config {
         Ok(())
     }
 
+    /// Sets the `reorder: bool` #[bitfield] parameter to the given value.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn reorder(&mut self, value: bool, span: Span) -> Result<()> {
+        Self::set_once(&mut self.reorder, "reorder", value, span)
+    }
+
+    /// Stores the value of the parameter with the given name into its slot.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    fn set_once<T>(
+        slot: &mut Option<ConfigValue<T>>,
+        name: &str,
+        value: T,
+        span: Span,
+    ) -> Result<()>
+    where
+        T: core::fmt::Debug + 'static,
+    {
+        match slot {
+            Some(previous) => Err(Self::raise_duplicate_error(name, span, previous)),
+            None => {
+                *slot = Some(ConfigValue::new(value, span));
+                Ok(())
+            }
+        }
+    }
+
+    /// Sets the given identifier parameter if it has not been set before.
+    ///
+    /// Unlike `set_once` this reports the previous identifier by its name.
+    fn set_ident_once(
+        slot: &mut Option<ConfigValue<syn::Ident>>,
+        name: &str,
+        value: syn::Ident,
+        span: Span,
+    ) -> Result<()> {
+        match slot {
+            Some(previous) => {
+                let previous = ConfigValue::new(previous.value.to_string(), previous.span);
+                Err(Self::raise_duplicate_error(name, span, &previous))
+            }
+            None => {
+                *slot = Some(ConfigValue::new(value, span));
+                Ok(())
+            }
+        }
+    }
+
+    /// Sets the `transparent` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn transparent(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.transparent, "transparent", (), span)
+    }
+
+    /// Sets the `clone_copy` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn clone_copy(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.clone_copy, "clone_copy", (), span)
+    }
+
+    /// Sets the `track_caller` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn track_caller(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.track_caller, "track_caller", (), span)
+    }
+
+    /// Sets the `zero_const` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn zero_const(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.zero_const, "zero_const", (), span)
+    }
+
+    /// Sets the `max_const` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn max_const(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.max_const, "max_const", (), span)
+    }
+
+    /// Sets the `iter_flags` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn iter_flags(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.iter_flags, "iter_flags", (), span)
+    }
+
+    /// Sets the `allow_empty` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn allow_empty(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.allow_empty, "allow_empty", (), span)
+    }
+
+    /// Sets the `bit_view` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn bit_view(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.bit_view, "bit_view", (), span)
+    }
+
+    /// Sets the `octal` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn octal(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.octal, "octal", (), span)
+    }
+
+    /// Sets the `decoder` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn decoder(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.decoder, "decoder", (), span)
+    }
+
+    /// Sets the `storage = name` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn storage(&mut self, name: syn::Ident, span: Span) -> Result<()> {
+        Self::set_ident_once(&mut self.storage, "storage", name, span)
+    }
+
+    /// Sets the `index_by = Enum` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn index_by(&mut self, name: syn::Ident, span: Span) -> Result<()> {
+        Self::set_ident_once(&mut self.index_by, "index_by", name, span)
+    }
+
+    /// Sets the `deref` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn deref(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.deref, "deref", (), span)
+    }
+
+    /// Sets the `deref_mut` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn deref_mut(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.deref_mut, "deref_mut", (), span)
+    }
+
+    /// Sets the `from_field` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_field(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.from_field, "from_field", (), span)
+    }
+
+    /// Sets the `widening_from` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn widening_from(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.widening_from, "widening_from", (), span)
+    }
+
+    /// Sets the `accessors_in_trait` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn accessors_in_trait(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.accessors_in_trait, "accessors_in_trait", (), span)
+    }
+
+    /// Sets the `layout` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn layout(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.layout, "layout", (), span)
+    }
+
+    /// Sets the `max_align` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn max_align(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.max_align, "max_align", (), span)
+    }
+
+    /// Sets the `from_str` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn from_str(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.from_str, "from_str", (), span)
+    }
+
+    /// Sets the `chained_setters` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn chained_setters(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.chained_setters, "chained_setters", (), span)
+    }
+
+    /// Sets the `enumerate` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn enumerate(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.enumerate, "enumerate", (), span)
+    }
+
+    /// Sets the `plain` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn plain(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.plain, "plain", (), span)
+    }
+
+    /// Sets the `borrowed_view` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn borrowed_view(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.borrowed_view, "borrowed_view", (), span)
+    }
+
+    /// Sets the `rich_errors` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn rich_errors(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.rich_errors, "rich_errors", (), span)
+    }
+
+    /// Sets the `by_value_getters` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn by_value_getters(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.by_value_getters, "by_value_getters", (), span)
+    }
+
+    /// Sets the `bcd` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn bcd(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.bcd, "bcd", (), span)
+    }
+
+    /// Sets the `serde` #[bitfield] flag.
+    ///
+    /// # Errors
+    ///
+    /// If the flag has already been set.
+    pub fn serde(&mut self, span: Span) -> Result<()> {
+        Self::set_once(&mut self.serde, "serde", (), span)
+    }
+
+    /// Registers another `view(Name = start..end)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If a view with the same name has already been registered.
+    pub fn view(&mut self, view: ViewConfig, span: Span) -> Result<()> {
+        if let Some(previous) = self.views.iter().find(|previous| previous.value.name == view.name) {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `view({})` parameter",
+                view.name,
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous `view({})` parameter here",
+                previous.value.name,
+            )))
+        }
+        self.views.push(ConfigValue::new(view, span));
+        Ok(())
+    }
+
+    /// Sets the `float_view(mantissa = m, exponent = e)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn float_view(&mut self, float_view: FloatViewConfig, span: Span) -> Result<()> {
+        Self::set_once(&mut self.float_view, "float_view", float_view, span)
+    }
+
+    /// Registers another `impl_trait = Trait(a, set_a)` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the same trait has already been registered.
+    pub fn impl_trait(&mut self, impl_trait: ImplTraitConfig, span: Span) -> Result<()> {
+        let path = &impl_trait.path;
+        let path = quote::quote!(#path).to_string();
+        if let Some(previous) = self.impl_traits.iter().find(|previous| {
+            let previous = &previous.value.path;
+            quote::quote!(#previous).to_string() == path
+        }) {
+            return Err(format_err!(
+                span,
+                "encountered duplicate `impl_trait = {}` parameter",
+                path,
+            )
+            .into_combine(format_err!(
+                previous.span,
+                "previous `impl_trait = {}` parameter here",
+                path,
+            )))
+        }
+        self.impl_traits.push(ConfigValue::new(impl_trait, span));
+        Ok(())
+    }
+
+    /// Sets the `endian = kind` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn endian(&mut self, kind: EndianKind, span: Span) -> Result<()> {
+        Self::set_once(&mut self.endian, "endian", kind, span)
+    }
+
+    /// Sets the `on_overflow = kind` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn on_overflow(&mut self, kind: OverflowKind, span: Span) -> Result<()> {
+        Self::set_once(&mut self.on_overflow, "on_overflow", kind, span)
+    }
+
+    /// Sets the `ord = kind` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn ord(&mut self, kind: OrdKind, span: Span) -> Result<()> {
+        Self::set_once(&mut self.ord, "ord", kind, span)
+    }
+
+    /// Sets the `debug = kind` #[bitfield] parameter.
+    ///
+    /// # Errors
+    ///
+    /// If the parameter has already been set.
+    pub fn debug(&mut self, kind: DebugKind, span: Span) -> Result<()> {
+        Self::set_once(&mut self.debug, "debug", kind, span)
+    }
+
     /// Registers the `#[repr(uN)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
@@ -273,6 +1171,44 @@ impl Config {
         Ok(())
     }
 
+    /// Registers the `#[derive(defmt::Format)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(defmt::Format)]` attribute has already been found.
+    pub fn derive_defmt(&mut self, span: Span) -> Result<()> {
+        match &self.derive_defmt {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(defmt::Format)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_defmt = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
+    /// Registers the `#[derive(Hash)]` attribute for the #[bitfield] macro.
+    ///
+    /// # Errors
+    ///
+    /// If a `#[derive(Hash)]` attribute has already been found.
+    pub fn derive_hash(&mut self, span: Span) -> Result<()> {
+        match &self.derive_hash {
+            Some(previous) => {
+                return Err(Self::raise_duplicate_error(
+                    "#[derive(Hash)]",
+                    span,
+                    previous,
+                ))
+            }
+            None => self.derive_hash = Some(ConfigValue::new((), span)),
+        }
+        Ok(())
+    }
+
     /// Registers the `#[derive(BitfieldSpecifier)]` attribute for the #[bitfield] macro.
     ///
     /// # Errors
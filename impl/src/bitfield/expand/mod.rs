@@ -1,5 +1,9 @@
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
 use quote::{
+    format_ident,
     quote,
     quote_spanned,
 };
@@ -12,8 +16,12 @@ use super::{
     BitfieldStruct,
     config::{
         Config,
+        ReprKind,
+    },
+    field_info::{
+        FieldInfo,
+        ImplTraitAccessor,
     },
-    field_info::FieldInfo,
 };
 
 mod packed;
@@ -34,7 +42,7 @@ impl BitfieldStruct {
         let span = value.span();
 
         quote_spanned!(span=> {
-            (((#value - 1) / 8) + 1) * 8
+            (#value / 8 + (#value % 8 != 0) as usize) * 8
         })
     }
 
@@ -50,7 +58,58 @@ impl BitfieldStruct {
                     #value
                 )
             })
-            .unwrap_or_else(|| self.generate_bitfield_size())
+            .unwrap_or_else(|| self.generate_bitfield_size(config))
+    }
+
+    /// Generates the expression denoting the amount of bits the field contributes.
+    ///
+    /// Fields annotated with `#[bits = N]` occupy `N` bits which may be more than
+    /// the bits of their specifier. Fields that are annotated with `#[cfg(..)]`
+    /// contribute no bits at all if their configuration predicate does not hold.
+    fn generate_field_bits(info: &FieldInfo<'_>) -> TokenStream2 {
+        let field = info.field;
+        let span = field.span();
+        let ty = &field.ty;
+        let bits = match &info.config.bits {
+            Some(bits) => {
+                let value = bits.value;
+                quote_spanned!(span=> #value)
+            }
+            None => {
+                quote_spanned!(span=>
+                    <#ty as ::modular_bitfield::Specifier>::BITS
+                )
+            }
+        };
+        let predicates = info
+            .cfg_attrs()
+            .map(|attr| {
+                let tokens = &attr.tokens;
+                quote_spanned!(span=> ::core::cfg! #tokens)
+            })
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            return bits
+        }
+        quote_spanned!(span=>
+            (if #( #predicates )&&* { #bits } else { 0usize })
+        )
+    }
+
+    /// Returns the infos of all fields together with the expressions of the bit offsets at
+    /// which each field starts and ends.
+    fn field_ranges<'a, 'b: 'a>(
+        &'a self,
+        config: &'b Config,
+    ) -> impl Iterator<Item = (FieldInfo<'a>, TokenStream2, TokenStream2)> {
+        let span = self.item_struct.span();
+        let mut offset = quote_spanned!(span=> 0usize);
+        self.field_infos(config).map(move |info| {
+            let start = offset.clone();
+            let bits = Self::generate_field_bits(&info);
+            offset = quote_spanned!(span=> #offset + #bits);
+            (info, start, offset.clone())
+        })
     }
 
     /// Generates the expression denoting the sum of all field bit specifier sizes.
@@ -87,19 +146,11 @@ impl BitfieldStruct {
     /// ```
     ///
     /// Which is a compile time evaluatable expression.
-    fn generate_bitfield_size(&self) -> TokenStream2 {
+    fn generate_bitfield_size(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let sum = self
-            .item_struct
-            .fields
-            .iter()
-            .map(|field| {
-                let span = field.span();
-                let ty = &field.ty;
-                quote_spanned!(span=>
-                    <#ty as ::modular_bitfield::Specifier>::BITS
-                )
-            })
+            .field_infos(config)
+            .map(|info| Self::generate_field_bits(&info))
             .fold(quote_spanned!(span=> 0usize), |lhs, rhs| {
                 quote_spanned!(span =>
                     #lhs + #rhs
@@ -121,15 +172,32 @@ impl BitfieldStruct {
     ) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
+        let actual_bits = self.generate_bitfield_size(config);
         let check_ident = match config.filled_enabled() {
             true => quote_spanned!(span => CheckFillsUnalignedBits),
             false => quote_spanned!(span => CheckDoesNotFillUnalignedBits),
         };
+        // Exceeding the required bits is reported for the offending field below.
         let comparator = match config.filled_enabled() {
-            true => quote! { == },
-            false => quote! { > },
+            true => quote! { <= },
+            false => quote! { != },
         };
+        let field_checks = self
+            .field_ranges(config)
+            .map(|(info, _, end)| {
+                let field_span = info.field.span();
+                let error_msg = format!(
+                    "field {} exceeds the {} bits declared by `bits = {}`",
+                    info.name(),
+                    required_bits,
+                    required_bits,
+                );
+                quote_spanned!(field_span=>
+                    #[allow(clippy::identity_op)]
+                    const _: () = ::core::assert!(#end <= #required_bits, #error_msg);
+                )
+            })
+            .collect::<Vec<_>>();
         quote_spanned!(span=>
             #[allow(clippy::identity_op)]
             const _: () = {
@@ -137,6 +205,8 @@ impl BitfieldStruct {
                     type CheckType = [(); (#required_bits #comparator #actual_bits) as usize];
                 }
             };
+
+            #( #field_checks )*
         )
     }
 
@@ -147,7 +217,7 @@ impl BitfieldStruct {
     fn generate_filled_check_for_aligned_bits(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
-        let actual_bits = self.generate_bitfield_size();
+        let actual_bits = self.generate_bitfield_size(config);
         let check_ident = match config.filled_enabled() {
             true => quote_spanned!(span => CheckTotalSizeMultipleOf8),
             false => quote_spanned!(span => CheckTotalSizeIsNotMultipleOf8),
@@ -165,11 +235,11 @@ impl BitfieldStruct {
     /// Generate check for either of the following two cases:
     ///
     /// - `filled = true`: Check if the total number of required bits is
-    ///         - ... the same as `N` if `bits = N` was provided or
-    ///         - ... a multiple of 8, otherwise
+    ///   - ... the same as `N` if `bits = N` was provided or
+    ///   - ... a multiple of 8, otherwise
     /// - `filled = false`: Check if the total number of required bits is
-    ///         - ... smaller than `N` if `bits = N` was provided or
-    ///         - ... NOT a multiple of 8, otherwise
+    ///   - ... smaller than `N` if `bits = N` was provided or
+    ///   - ... NOT a multiple of 8, otherwise
     fn generate_check_for_filled(&self, config: &Config) -> TokenStream2 {
         match config.bits.as_ref() {
             Some(bits_config) => {
@@ -179,8 +249,1340 @@ impl BitfieldStruct {
         }
     }
 
+    /// Generates a check asserting that the bitfield consists of more than 0 bits
+    /// unless the `allow_empty` parameter is set.
+    fn generate_check_for_empty(&self, config: &Config) -> Option<TokenStream2> {
+        if config.allow_empty.is_some() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let error_msg = format!(
+            "bitfield {} has a total of 0 bits, use #[bitfield(allow_empty)] if that was your intent",
+            self.item_struct.ident,
+        );
+        Some(quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = ::core::assert!(#size != 0, #error_msg);
+        ))
+    }
+
+    /// Generates the `#[track_caller]` attribute for panicking accessors if enabled.
+    fn generate_track_caller(config: &Config) -> Option<TokenStream2> {
+        let span = config.track_caller.as_ref()?.span;
+        Some(quote_spanned!(span=> #[track_caller]))
+    }
+
+    /// Wraps the given field accessors into an inherent impl block of the bitfield.
+    ///
+    /// With the `accessors_in_trait` parameter the accessors are instead implemented
+    /// for the generated `#{ident}Accessors` extension trait.
+    ///
+    /// The accessors are allowed to have non snake case names since register fields are
+    /// commonly named like `CR1` after their datasheet.
+    fn generate_accessors_impl(&self, config: &Config, accessors: TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let trait_span = match config.accessors_in_trait.as_ref() {
+            Some(accessors_in_trait) => accessors_in_trait.span,
+            None => {
+                return quote_spanned!(span=>
+                    #[allow(non_snake_case)]
+                    impl #ident {
+                        #accessors
+                    }
+                )
+            }
+        };
+        let vis = &self.item_struct.vis;
+        let trait_ident = format_ident!("{}Accessors", ident);
+        let trait_docs = format!("Accessors for the fields of [`{}`].", ident);
+        let mut item_impl: syn::ItemImpl = syn::parse_quote!(impl #ident { #accessors });
+        let mut signatures = Vec::new();
+        for item in &mut item_impl.items {
+            if let syn::ImplItem::Method(method) = item {
+                method.vis = syn::Visibility::Inherited;
+                method.sig.constness = None;
+                let attrs = method
+                    .attrs
+                    .iter()
+                    .filter(|attr| !attr.path.is_ident("inline") && !attr.path.is_ident("track_caller"));
+                // Patterns such as `mut self` are not allowed in trait method declarations.
+                let mut sig = method.sig.clone();
+                for input in &mut sig.inputs {
+                    match input {
+                        syn::FnArg::Receiver(receiver) => {
+                            if receiver.reference.is_none() {
+                                receiver.mutability = None;
+                            }
+                        }
+                        syn::FnArg::Typed(typed) => {
+                            if let syn::Pat::Ident(pat) = &mut *typed.pat {
+                                pat.mutability = None;
+                            }
+                        }
+                    }
+                }
+                signatures.push(quote_spanned!(span=>
+                    #( #attrs )*
+                    #sig;
+                ));
+                // The deprecation of an accessor is declared by the trait and useless on its impl.
+                method.attrs.retain(|attr| !attr.path.is_ident("deprecated"));
+            }
+        }
+        let items = &item_impl.items;
+        quote_spanned!(trait_span=>
+            #[doc = #trait_docs]
+            #[allow(non_snake_case)]
+            #vis trait #trait_ident: ::core::marker::Sized {
+                #( #signatures )*
+            }
+
+            #[allow(non_snake_case)]
+            impl #trait_ident for #ident {
+                #( #items )*
+            }
+        )
+    }
+
+    /// Generates the `set_f_chained` setter of field `f` for the `chained_setters` parameter.
+    ///
+    /// It sets the field like `set_f` but returns `&mut Self` to allow for chaining.
+    fn generate_chained_setter(bitfield_config: &Config, info: &FieldInfo<'_>) -> Option<TokenStream2> {
+        bitfield_config.chained_setters.as_ref()?;
+        let span = info.field.span();
+        let retained_attrs = &info.config.retained_attrs;
+        let ty = &info.field.ty;
+        let vis = &info.field.vis;
+        let name = info.name();
+        let set_ident = format_ident!("set_{}", info.ident_frag());
+        let set_chained_ident = format_ident!("set_{}_chained", info.ident_frag());
+        let docs = format!(
+            "Sets the value of {} to the given value and returns `self` for chaining.\n\n\
+             #Panics\n\n\
+             If the given value is out of bounds for {}.",
+            name, name,
+        );
+        let track_caller = Self::generate_track_caller(bitfield_config);
+        Some(quote_spanned!(span=>
+            #[doc = #docs]
+            #[inline]
+            #[allow(dead_code)]
+            #track_caller
+            #( #retained_attrs )*
+            #vis fn #set_chained_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> &mut Self {
+                self.#set_ident(new_val);
+                self
+            }
+        ))
+    }
+
+    /// Generates the `#[derive(Clone, Copy)]` for the `clone_copy` parameter.
+    fn generate_clone_copy_derive(config: &Config) -> Option<TokenStream2> {
+        let span = config.clone_copy.as_ref()?.span;
+        Some(quote_spanned!(span=>
+            #[derive(::core::clone::Clone, ::core::marker::Copy)]
+        ))
+    }
+
+    /// Generates a check asserting that the `InOut` types of all fields are `Copy`
+    /// if the `clone_copy` parameter is set.
+    fn generate_clone_copy_check(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.clone_copy.as_ref()?.span;
+        let field_tys = self.item_struct.fields.iter().map(|field| &field.ty);
+        Some(quote_spanned!(span=>
+            const _: () = {
+                fn __bf_assert_copy<T: ::core::marker::Copy>() {}
+                fn __bf_assert_fields_copy() {
+                    #( __bf_assert_copy::<<#field_tys as ::modular_bitfield::Specifier>::InOut>(); )*
+                }
+            };
+        ))
+    }
+
+    /// Generates `to_f64` reconstructing the value of a custom float layout for the
+    /// `float_view(mantissa = m, exponent = e)` parameter via the getters of both fields.
+    fn generate_float_view_method(&self, config: &Config) -> Option<TokenStream2> {
+        let float_view = config.float_view.as_ref()?;
+        let span = float_view.span;
+        let ident = &self.item_struct.ident;
+        let mantissa = &float_view.value.mantissa;
+        let exponent = &float_view.value.exponent;
+        let bias = proc_macro2::Literal::i64_suffixed(i64::from(float_view.value.bias));
+        let docs = format!(
+            "Returns the value represented by the `{}` and `{}` fields as `{} * 2^({} - {})`.",
+            mantissa, exponent, mantissa, exponent, bias,
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl #ident {
+                #[doc = #docs]
+                #[inline]
+                pub fn to_f64(&self) -> ::core::primitive::f64 {
+                    ::modular_bitfield::private::scale_by_pow2(
+                        self.#mantissa() as ::core::primitive::f64,
+                        self.#exponent() as ::core::primitive::i64 - #bias,
+                    )
+                }
+            }
+        ))
+    }
+
+    /// Generates the `IntoIterator` impl over the `bool` fields of the bitfield
+    /// if the `iter_flags` parameter is set.
+    ///
+    /// Yields the name and value of every `bool` field with a getter in declaration order.
+    /// Fields of other types as well as fields with a `#[cfg(..)]` are not yielded.
+    fn generate_flags_iter(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.iter_flags.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let flags = self
+            .field_infos(config)
+            .filter(|info| {
+                info.is_bool() && !info.config.skip_getters() && info.cfg_attrs().next().is_none()
+            })
+            .map(|info| {
+                let name = info.name();
+                let getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("get_{}", info.ident_frag()));
+                quote_spanned!(span=> (#name, self.#getter()))
+            })
+            .collect::<Vec<_>>();
+        let len = flags.len();
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl<'__bf> ::core::iter::IntoIterator for &'__bf #ident {
+                type Item = (&'static ::core::primitive::str, ::core::primitive::bool);
+                type IntoIter = ::core::array::IntoIter<Self::Item, #len>;
+
+                /// Returns an iterator over the names and values of all `bool` fields.
+                #[inline]
+                fn into_iter(self) -> Self::IntoIter {
+                    ::core::iter::IntoIterator::into_iter([ #( #flags ),* ])
+                }
+            }
+        ))
+    }
+
+    /// Generates the `Index<Enum>` impl over the `bool` fields of the bitfield
+    /// if the `index_by = Enum` parameter is set.
+    ///
+    /// Every named `bool` field with a getter maps to the variant of the user enum that is the
+    /// `CamelCase` form of the field name. Fields with a `#[cfg(..)]` are not mapped.
+    fn generate_index_by_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let index_by = config.index_by.as_ref()?;
+        let span = index_by.span;
+        let ident = &self.item_struct.ident;
+        let index_enum = &index_by.value;
+        let arms = self
+            .field_infos(config)
+            .filter(|info| {
+                info.is_bool() && !info.config.skip_getters() && info.cfg_attrs().next().is_none()
+            })
+            .filter_map(|info| info.field.ident.as_ref())
+            .map(|getter| {
+                let variant = format_ident!(
+                    "{}",
+                    camel_case(&getter.to_string()),
+                    span = getter.span()
+                );
+                quote_spanned!(span=> #index_enum::#variant => self.#getter())
+            });
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl ::core::ops::Index<#index_enum> for #ident {
+                type Output = ::core::primitive::bool;
+
+                /// Returns the value of the `bool` field named by the given flag.
+                #[inline]
+                fn index(&self, flag: #index_enum) -> &::core::primitive::bool {
+                    let value = match flag {
+                        #( #arms, )*
+                    };
+                    if value {
+                        &true
+                    } else {
+                        &false
+                    }
+                }
+            }
+        ))
+    }
+
+    /// Generates the streaming `IdentDecoder` for the `decoder` parameter.
+    ///
+    /// The decoder accumulates bytes across calls until enough bytes for
+    /// a whole bitfield are available.
+    fn generate_decoder(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.decoder.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let decoder_ident = format_ident!("{}Decoder", ident);
+        let decoder_docs = format!(
+            "Incrementally decodes [`{}`] from bytes that may arrive in chunks.",
+            ident,
+        );
+        let (output, output_docs) = match config.filled_enabled() {
+            true => (quote_spanned!(span=> #ident), "completed bitfield"),
+            false => {
+                (
+                    quote_spanned!(span=>
+                        ::core::result::Result<#ident, ::modular_bitfield::error::OutOfBounds>
+                    ),
+                    "result of converting the completed bytes",
+                )
+            }
+        };
+        let push_docs = format!(
+            "Pushes the given bytes into the decoder.\n\n\
+             Consumes bytes until a whole bitfield has been accumulated and returns the {} \
+             together with the remaining unconsumed bytes.",
+            output_docs,
+        );
+        Some(quote_spanned!(span=>
+            #[doc = #decoder_docs]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone)]
+            #vis struct #decoder_ident {
+                buffer: [::core::primitive::u8; #ident::BYTES],
+                len: ::core::primitive::usize,
+            }
+
+            impl ::core::default::Default for #decoder_ident {
+                #[inline]
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl #decoder_ident {
+                /// Creates a new decoder without any buffered bytes.
+                #[inline]
+                pub const fn new() -> Self {
+                    Self {
+                        buffer: [0x00; #ident::BYTES],
+                        len: 0,
+                    }
+                }
+
+                /// Returns the number of bytes buffered for the next bitfield.
+                #[inline]
+                pub const fn buffered(&self) -> ::core::primitive::usize {
+                    self.len
+                }
+
+                #[doc = #push_docs]
+                pub fn push_bytes<'__bf>(
+                    &mut self,
+                    bytes: &'__bf [::core::primitive::u8],
+                ) -> (::core::option::Option<#output>, &'__bf [::core::primitive::u8]) {
+                    let __bf_count = ::core::cmp::min(#ident::BYTES - self.len, bytes.len());
+                    let (__bf_consumed, __bf_rest) = bytes.split_at(__bf_count);
+                    self.buffer[self.len..self.len + __bf_count].copy_from_slice(__bf_consumed);
+                    self.len += __bf_count;
+                    if self.len < #ident::BYTES {
+                        return (::core::option::Option::None, __bf_rest)
+                    }
+                    self.len = 0;
+                    (::core::option::Option::Some(#ident::from_le_bytes(self.buffer)), __bf_rest)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `Octal` implementation for the `octal` parameter.
+    ///
+    /// The bitfield is formatted as the unsigned integer of its underlying bits,
+    /// honoring the flags of the formatter.
+    fn generate_octal_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.octal.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let value = match config.packed_enabled() {
+            true => {
+                let size = self.generate_target_or_actual_bitfield_size(config);
+                let storage = config.storage_ident(span);
+                let error_msg = format!(
+                    "bitfield {} must not exceed 128 bits for the `octal` parameter",
+                    ident,
+                );
+                quote_spanned!(span=>
+                    #[allow(clippy::identity_op)]
+                    const _: () = ::core::assert!(#size <= 128, #error_msg);
+                    let mut __bf_bytes = [0x00_u8; 16];
+                    __bf_bytes[..Self::BYTES].copy_from_slice(&self.#storage[..]);
+                    let __bf_value = ::core::primitive::u128::from_le_bytes(__bf_bytes);
+                )
+            }
+            false => {
+                let prim = self.get_repr_or_bits(config).into_quote();
+                quote_spanned!(span=>
+                    let __bf_value: #prim = ::core::clone::Clone::clone(self).into();
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl ::core::fmt::Octal for #ident {
+                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #value
+                    ::core::fmt::Octal::fmt(&__bf_value, __bf_f)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `match` arms of `from_field_map` and `FromStr` which set the field named
+    /// by the arm to a `u128` value.
+    ///
+    /// The given `value` closure yields the expression computing the `u128` value of the field
+    /// with the given name while the `error` enum reports out of bounds values and invalid bit
+    /// patterns through its `OutOfBounds` and `InvalidBitPattern` variants.
+    fn generate_set_field_by_name_arms(
+        &self,
+        config: &Config,
+        span: Span,
+        error: &TokenStream2,
+        value: impl Fn(&str) -> TokenStream2,
+    ) -> Vec<TokenStream2> {
+        self.field_infos(config)
+            .filter(|info| !info.config.skip_setters())
+            .map(|info| {
+                let cfg_attrs = info.cfg_attrs();
+                let name = info.name();
+                let value = value(&name);
+                let ty = &info.field.ty;
+                let set_ident = format_ident!("set_{}", info.ident_frag());
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    #name => {
+                        let __bf_value: ::core::primitive::u128 = #value;
+                        let __bf_bits = <#ty as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32;
+                        if __bf_value.checked_shr(__bf_bits).unwrap_or(0) != 0 {
+                            return ::core::result::Result::Err(#error::OutOfBounds(#name))
+                        }
+                        let __bf_raw = <<#ty as ::modular_bitfield::Specifier>::Bytes as ::core::convert::TryFrom<::core::primitive::u128>>::try_from(__bf_value)
+                            .map_err(|_| #error::OutOfBounds(#name))?;
+                        let __bf_field = <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_raw)
+                            .map_err(|_| #error::InvalidBitPattern(#name))?;
+                        __bf_result.#set_ident(__bf_field);
+                    }
+                )
+            })
+            .collect()
+    }
+
+    /// Generates the `from_field_map` constructor if the `std` crate feature is enabled.
+    ///
+    /// The constructor sets every field named in the given map to its associated value.
+    fn generate_from_field_map(&self, config: &Config) -> Option<TokenStream2> {
+        if !cfg!(feature = "std") {
+            return None
+        }
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let arms = self.generate_set_field_by_name_arms(
+            config,
+            span,
+            &quote_spanned!(span=> ::modular_bitfield::error::FieldError),
+            |_| quote_spanned!(span=> __bf_value),
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl #ident {
+                /// Creates a new bitfield with the fields set to the values of the given map.
+                ///
+                /// Fields missing from the map are zero-initialized.
+                ///
+                /// # Errors
+                ///
+                /// - If a key does not name a field of the bitfield that has setters.
+                /// - If a value is out of bounds or an invalid bit pattern for its field.
+                #[allow(dead_code)]
+                pub fn from_field_map(
+                    map: &::modular_bitfield::private::HashMap<&::core::primitive::str, ::core::primitive::u128>,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::FieldError> {
+                    let mut __bf_result = Self::new();
+                    for (&__bf_name, &__bf_value) in map {
+                        match __bf_name {
+                            #( #arms )*
+                            _ => {
+                                return ::core::result::Result::Err(
+                                    ::modular_bitfield::error::FieldError::UnknownField(
+                                        ::core::convert::From::from(__bf_name)
+                                    )
+                                )
+                            }
+                        }
+                    }
+                    ::core::result::Result::Ok(__bf_result)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `FromStr` impl for the `from_str` parameter.
+    ///
+    /// Parses comma separated `field=value` pairs such as `"a=3,b=true"` and sets
+    /// the fields through their setters. Fields missing from the string are zero-initialized.
+    fn generate_from_str_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.from_str.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let arms = self.generate_set_field_by_name_arms(
+            config,
+            span,
+            &quote_spanned!(span=> ::modular_bitfield::error::ParseFieldsError),
+            |name| {
+                quote_spanned!(span=>
+                    ::modular_bitfield::private::parse_field_value(__bf_value)
+                        .ok_or(::modular_bitfield::error::ParseFieldsError::InvalidValue(#name))?
+                )
+            },
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl ::core::str::FromStr for #ident {
+                type Err = ::modular_bitfield::error::ParseFieldsError;
+
+                /// Parses comma separated `field=value` pairs into the bitfield.
+                ///
+                /// Values are either `true`, `false` or decimal, `0x` prefixed hexadecimal
+                /// or `0b` prefixed binary integers.
+                fn from_str(__bf_str: &::core::primitive::str) -> ::core::result::Result<Self, Self::Err> {
+                    let mut __bf_result = Self::new();
+                    for __bf_pair in __bf_str.split(',') {
+                        let __bf_pair = __bf_pair.trim();
+                        if __bf_pair.is_empty() {
+                            continue
+                        }
+                        let (__bf_name, __bf_value) = __bf_pair
+                            .split_once('=')
+                            .ok_or(::modular_bitfield::error::ParseFieldsError::InvalidSyntax)?;
+                        let __bf_value = __bf_value.trim();
+                        match __bf_name.trim() {
+                            #( #arms )*
+                            _ => return ::core::result::Result::Err(::modular_bitfield::error::ParseFieldsError::UnknownField),
+                        }
+                    }
+                    ::core::result::Result::Ok(__bf_result)
+                }
+            }
+        ))
+    }
+
+    /// Generates the compact `Serialize` and `Deserialize` impls for the `serde` parameter.
+    ///
+    /// The bitfield is serialized as a tuple of its little endian bytes which formats such as
+    /// `postcard` encode without any length prefix. The given `to_le_bytes` expression yields
+    /// the bytes of `self`.
+    fn generate_serde_impls(
+        &self,
+        config: &Config,
+        to_le_bytes: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let span = config.serde.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let expecting = format!("the little endian bytes of `{}`", ident);
+        let from_le_bytes = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    ::core::result::Result::Ok(#ident::from_le_bytes(__bf_bytes))
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    #ident::from_le_bytes(__bf_bytes).map_err(|_| {
+                        ::modular_bitfield::private::serde::de::Error::invalid_value(
+                            ::modular_bitfield::private::serde::de::Unexpected::Bytes(&__bf_bytes),
+                            &self,
+                        )
+                    })
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl ::modular_bitfield::private::serde::Serialize for #ident {
+                fn serialize<__S>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error>
+                where
+                    __S: ::modular_bitfield::private::serde::Serializer,
+                {
+                    use ::modular_bitfield::private::serde::ser::SerializeTuple as _;
+                    let __bf_bytes = #to_le_bytes;
+                    let mut __bf_tuple = serializer.serialize_tuple(__bf_bytes.len())?;
+                    for __bf_byte in &__bf_bytes {
+                        __bf_tuple.serialize_element(__bf_byte)?;
+                    }
+                    __bf_tuple.end()
+                }
+            }
+
+            impl<'de> ::modular_bitfield::private::serde::Deserialize<'de> for #ident {
+                fn deserialize<__D>(deserializer: __D) -> ::core::result::Result<Self, __D::Error>
+                where
+                    __D: ::modular_bitfield::private::serde::Deserializer<'de>,
+                {
+                    struct __BfVisitor;
+
+                    impl<'de> ::modular_bitfield::private::serde::de::Visitor<'de> for __BfVisitor {
+                        type Value = #ident;
+
+                        fn expecting(
+                            &self,
+                            f: &mut ::core::fmt::Formatter,
+                        ) -> ::core::fmt::Result {
+                            f.write_str(#expecting)
+                        }
+
+                        fn visit_seq<__A>(self, mut seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                        where
+                            __A: ::modular_bitfield::private::serde::de::SeqAccess<'de>,
+                        {
+                            let mut __bf_bytes = [0x00_u8; #ident::BYTES];
+                            for (__bf_index, __bf_byte) in __bf_bytes.iter_mut().enumerate() {
+                                *__bf_byte = seq.next_element()?.ok_or_else(|| {
+                                    ::modular_bitfield::private::serde::de::Error::invalid_length(__bf_index, &self)
+                                })?;
+                            }
+                            #from_le_bytes
+                        }
+                    }
+
+                    deserializer.deserialize_tuple(#ident::BYTES, __BfVisitor)
+                }
+            }
+        ))
+    }
+
+    /// Generates the plain `IdentFields` struct and the conversions between it and the bitfield
+    /// if the `plain` parameter is set.
+    ///
+    /// The plain struct has a public field for every field of the bitfield with getters and setters.
+    fn generate_plain_struct(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.plain.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let plain_ident = format_ident!("{}Fields", ident);
+        let fields = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters() && !info.config.skip_setters())
+            .collect::<Vec<_>>();
+        let plain_fields = fields.iter().map(|info| {
+            let cfg_attrs = info.cfg_attrs();
+            let field_ident = &info.field.ident;
+            let ty = &info.field.ty;
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                pub #field_ident: <#ty as ::modular_bitfield::Specifier>::InOut,
+            )
+        });
+        let from_bitfield = fields.iter().map(|info| {
+            let cfg_attrs = info.cfg_attrs();
+            let field_ident = &info.field.ident;
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #field_ident: __bf_bitfield.#field_ident(),
+            )
+        });
+        let into_bitfield = fields.iter().map(|info| {
+            let cfg_attrs = info.cfg_attrs();
+            let field_ident = &info.field.ident;
+            let set_ident = format_ident!("set_{}", info.ident_frag());
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                __bf_result.#set_ident(__bf_fields.#field_ident);
+            )
+        });
+        let eq_fields = fields.iter().map(|info| {
+            let cfg_attrs = info.cfg_attrs();
+            let field_ident = &info.field.ident;
+            // Unpacked bitfields store the values of their fields which are always valid.
+            let value_eq = match config.packed_enabled() {
+                true => {
+                    let get_checked_ident = format_ident!("{}_or_err", info.ident_frag());
+                    quote_spanned!(span=>
+                        ::core::matches!(
+                            self.#get_checked_ident(),
+                            ::core::result::Result::Ok(__bf_value) if __bf_value == __bf_fields.#field_ident
+                        )
+                    )
+                }
+                false => quote_spanned!(span=> self.#field_ident() == __bf_fields.#field_ident),
+            };
+            quote_spanned!(span=>
+                #( #cfg_attrs )*
+                if !(#value_eq) {
+                    return false
+                }
+            )
+        });
+        // The higher-ranked bounds keep the impls from failing to compile for fields whose
+        // values do not implement `PartialEq`, in which case they are simply not available.
+        let eq_bounds = fields.iter().map(|info| {
+            let ty = &info.field.ty;
+            quote_spanned!(span=>
+                for<'__bf> <#ty as ::modular_bitfield::Specifier>::InOut: ::core::cmp::PartialEq,
+            )
+        }).collect::<Vec<_>>();
+        let doc = format!("The fields of [`{}`] as a plain struct without bit packing.", ident);
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #[doc = #doc]
+            #[allow(non_snake_case)]
+            #vis struct #plain_ident {
+                #( #plain_fields )*
+            }
+
+            #allow_deprecated
+            impl ::core::convert::From<&#ident> for #plain_ident {
+                /// Reads all fields of the bitfield.
+                ///
+                /// # Panics
+                ///
+                /// If a field contains an invalid bit pattern.
+                #[inline]
+                fn from(__bf_bitfield: &#ident) -> Self {
+                    Self {
+                        #( #from_bitfield )*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#ident> for #plain_ident {
+                /// Reads all fields of the bitfield.
+                ///
+                /// # Panics
+                ///
+                /// If a field contains an invalid bit pattern.
+                #[inline]
+                fn from(__bf_bitfield: #ident) -> Self {
+                    <Self as ::core::convert::From<&#ident>>::from(&__bf_bitfield)
+                }
+            }
+
+            #allow_deprecated
+            impl ::core::convert::From<#plain_ident> for #ident {
+                /// Packs all fields into a new bitfield.
+                ///
+                /// # Panics
+                ///
+                /// If a field value is out of bounds for its field.
+                #[inline]
+                fn from(__bf_fields: #plain_ident) -> Self {
+                    let mut __bf_result = Self::new();
+                    #( #into_bitfield )*
+                    __bf_result
+                }
+            }
+
+            #allow_deprecated
+            impl ::core::cmp::PartialEq<#plain_ident> for #ident
+            where
+                #( #eq_bounds )*
+            {
+                /// Compares the bitfield field by field to the plain fields.
+                ///
+                /// Fields containing an invalid bit pattern are never equal.
+                #[inline]
+                fn eq(&self, __bf_fields: &#plain_ident) -> ::core::primitive::bool {
+                    #( #eq_fields )*
+                    true
+                }
+            }
+
+            impl ::core::cmp::PartialEq<#ident> for #plain_ident
+            where
+                #( #eq_bounds )*
+            {
+                /// Compares the plain fields field by field to the bitfield.
+                ///
+                /// Fields containing an invalid bit pattern are never equal.
+                #[inline]
+                fn eq(&self, __bf_bitfield: &#ident) -> ::core::primitive::bool {
+                    <#ident as ::core::cmp::PartialEq<Self>>::eq(__bf_bitfield, self)
+                }
+            }
+        ))
+    }
+
+    /// Generates the `PartialOrd` and `Ord` implementations for `#[bitfield(ord = fields)]`.
+    ///
+    /// The fields are compared lexicographically by their values in declaration order.
+    /// Fields without getters do not take part in the comparison.
+    fn generate_ord_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.ord.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let comparisons = self
+            .field_infos(config)
+            .map(|info| {
+                let cfg_attrs = info.cfg_attrs();
+                let getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("get_{}", info.ident_frag()));
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    match ::core::cmp::Ord::cmp(&self.#getter(), &other.#getter()) {
+                        ::core::cmp::Ordering::Equal => {}
+                        __bf_ordering => return __bf_ordering,
+                    }
+                )
+            });
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            impl ::core::cmp::PartialOrd for #ident {
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                    ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+                }
+            }
+
+            #allow_deprecated
+            impl ::core::cmp::Ord for #ident {
+                fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                    #( #comparisons )*
+                    ::core::cmp::Ordering::Equal
+                }
+            }
+        ))
+    }
+
+    /// Generates the `(start, end)` bit ranges of all fields annotated with `#[eq(ignore)]`.
+    ///
+    /// Shared by the generated `PartialEq` and `Hash` implementations so that both agree.
+    fn generate_eq_ignored_ranges(&self, config: &Config) -> Vec<TokenStream2> {
+        let span = self.item_struct.span();
+        self.field_ranges(config)
+            .filter(|(info, _, _)| info.config.eq_ignore.is_some())
+            .map(|(_, start, end)| quote_spanned!(span=> (#start, #end)))
+            .collect()
+    }
+
+    /// Generates the `PartialEq` implementation if any field is annotated with `#[eq(ignore)]`.
+    ///
+    /// The bytes given by `self_bytes` and `other_bytes` are compared bit by bit while the
+    /// bits of all ignored fields are masked out.
+    fn generate_partial_eq_impl(
+        &self,
+        config: &Config,
+        self_bytes: &TokenStream2,
+        other_bytes: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let ignored = self.generate_eq_ignored_ranges(config);
+        if ignored.is_empty() {
+            return None
+        }
+        Some(quote_spanned!(span=>
+            impl ::core::cmp::PartialEq for #ident {
+                #[inline]
+                #[allow(clippy::identity_op)]
+                fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                    ::modular_bitfield::private::bits_eq_ignoring(
+                        #self_bytes,
+                        #other_bytes,
+                        &[ #( #ignored ),* ],
+                    )
+                }
+            }
+        ))
+    }
+
+    /// Generates the impls of the user provided traits of the `impl_trait = Trait(a, set_a)`
+    /// parameters forwarding to the listed getters and setters.
+    ///
+    /// The compiler verifies that the signatures of the trait match the generated accessors.
+    fn generate_impl_trait_impls(&self, config: &Config) -> Option<TokenStream2> {
+        if config.impl_traits.is_empty() {
+            return None
+        }
+        let ident = &self.item_struct.ident;
+        let infos = self.field_infos(config).collect::<Vec<_>>();
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        let impls = config.impl_traits.iter().map(|impl_trait| {
+            let span = impl_trait.span;
+            let path = &impl_trait.value.path;
+            let methods = impl_trait.value.methods.iter().map(|method| {
+                let accessor = ImplTraitAccessor::resolve(&self.item_struct, method)
+                    .expect("checked to be an accessor of the struct during analysis");
+                let span = method.span();
+                let info = &infos[accessor.index()];
+                let ty = &info.field.ty;
+                let cfg_attrs = info.cfg_attrs();
+                match accessor {
+                    ImplTraitAccessor::Getter(_) => {
+                        let receiver = match config.by_value_getters.is_some() {
+                            true => quote_spanned!(span=> *self),
+                            false => quote_spanned!(span=> self),
+                        };
+                        quote_spanned!(span=>
+                            #( #cfg_attrs )*
+                            #[inline]
+                            fn #method(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                                #ident::#method(#receiver)
+                            }
+                        )
+                    }
+                    ImplTraitAccessor::Setter(_) => {
+                        quote_spanned!(span=>
+                            #( #cfg_attrs )*
+                            #[inline]
+                            fn #method(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
+                                #ident::#method(self, new_val)
+                            }
+                        )
+                    }
+                }
+            });
+            quote_spanned!(span=>
+                #allow_deprecated
+                #[allow(non_snake_case)]
+                impl #path for #ident {
+                    #( #methods )*
+                }
+            )
+        });
+        Some(quote!( #( #impls )* ))
+    }
+
+    /// Generates a getter for every field group declared via `#[group(name)]`.
+    ///
+    /// The getter `name()` returns the values of all fields of the group as a tuple
+    /// in declaration order.
+    fn generate_group_getters(&self, config: &Config) -> Option<TokenStream2> {
+        let mut groups: Vec<(syn::Ident, Vec<FieldInfo<'_>>)> = Vec::new();
+        for info in self.field_infos(config) {
+            let name = match &info.config.group {
+                Some(group) => group.value.clone(),
+                None => continue,
+            };
+            match groups.iter_mut().find(|(group, _)| *group == name) {
+                Some((_, infos)) => infos.push(info),
+                None => groups.push((name, vec![info])),
+            }
+        }
+        if groups.is_empty() {
+            return None
+        }
+        let ident = &self.item_struct.ident;
+        let getters = groups.iter().map(|(group, infos)| {
+            let span = group.span();
+            let vis = &infos[0].field.vis;
+            let names = infos.iter().map(FieldInfo::name).collect::<Vec<_>>().join(", ");
+            let docs = format!("Returns the values of {} of group `{}`.", names, group);
+            let tys = infos.iter().map(|info| {
+                let ty = &info.field.ty;
+                quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::InOut)
+            });
+            let values = infos.iter().map(|info| {
+                let getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("get_{}", info.ident_frag()));
+                quote_spanned!(span=> self.#getter())
+            });
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #vis fn #group(&self) -> ( #( #tys, )* ) {
+                    ( #( #values, )* )
+                }
+            )
+        });
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(self.item_struct.span()=>
+            #allow_deprecated
+            impl #ident {
+                #( #getters )*
+            }
+        ))
+    }
+
+    /// Generates the `f_range()` functions returning the bit range of every field `f`
+    /// as well as the hidden `__BF_LAYOUT` constant listing the bit ranges of all fields.
+    ///
+    /// The latter is used by the `assert_bitfield_layout!` macro and the generated
+    /// `bit_offset_of` function.
+    ///
+    /// With the `layout` parameter this also generates the `#{ident}Layout` struct carrying
+    /// the offset and width of every field as associated constants.
+    fn generate_layout_const(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let mut range_fns = Vec::new();
+        let mut layout_consts = Vec::new();
+        let entries = self
+            .field_ranges(config)
+            .map(|(info, start, end)| {
+                let name = info.name();
+                if !info.config.skip_all() {
+                    let field_span = info.field.span();
+                    let vis = &info.field.vis;
+                    let retained_attrs = &info.config.retained_attrs;
+                    let range_ident = info
+                        .field
+                        .ident
+                        .as_ref()
+                        .map(|_| format_ident!("{}_range", info.ident_frag()))
+                        .unwrap_or_else(|| format_ident!("get_{}_range", info.ident_frag()));
+                    let range_docs = format!("Returns the range of bits occupied by {}.", name);
+                    range_fns.push(quote_spanned!(field_span=>
+                        #[doc = #range_docs]
+                        #[inline]
+                        #[allow(dead_code, clippy::identity_op)]
+                        #( #retained_attrs )*
+                        #vis const fn #range_ident() -> ::core::ops::Range<::core::primitive::usize> {
+                            (#start)..(#end)
+                        }
+                    ));
+                    let const_prefix = match &info.field.ident {
+                        Some(ident) => syn::ext::IdentExt::unraw(ident).to_string().to_uppercase(),
+                        None => format!("_{}", info.index),
+                    };
+                    let bits = Self::generate_field_bits(&info);
+                    let offset_ident = format_ident!("{}_OFFSET", const_prefix, span = field_span);
+                    let width_ident = format_ident!("{}_WIDTH", const_prefix, span = field_span);
+                    let offset_docs = format!("The offset in bits of {}.", name);
+                    let width_docs = format!("The width in bits of {}.", name);
+                    layout_consts.push(quote_spanned!(field_span=>
+                        #[doc = #offset_docs]
+                        #[allow(clippy::identity_op)]
+                        #( #retained_attrs )*
+                        pub const #offset_ident: ::core::primitive::usize = #start;
+
+                        #[doc = #width_docs]
+                        #( #retained_attrs )*
+                        pub const #width_ident: ::core::primitive::usize = #bits;
+                    ));
+                }
+                quote_spanned!(span=> (#name, #start, #end))
+            })
+            .collect::<Vec<_>>();
+        let layout_struct = config.layout.as_ref().map(|layout| {
+            let layout_span = layout.span;
+            let vis = &self.item_struct.vis;
+            let layout_ident = format_ident!("{}Layout", ident);
+            let layout_docs = format!("The offsets and widths in bits of the fields of [`{}`].", ident);
+            quote_spanned!(layout_span=>
+                #[doc = #layout_docs]
+                #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+                #vis struct #layout_ident;
+
+                impl #layout_ident {
+                    #( #layout_consts )*
+                }
+            )
+        });
+        quote_spanned!(span=>
+            #[allow(non_snake_case)]
+            impl #ident {
+                #( #range_fns )*
+
+                /// Returns the offset in bits of the field with the given name.
+                ///
+                /// Returns `None` if there is no field with this name.
+                #[inline]
+                pub const fn bit_offset_of(name: &::core::primitive::str) -> ::core::option::Option<::core::primitive::usize> {
+                    ::modular_bitfield::private::layout::field_offset(Self::__BF_LAYOUT, name)
+                }
+
+                #[doc(hidden)]
+                #[allow(clippy::identity_op)]
+                pub const __BF_LAYOUT: &'static [::modular_bitfield::private::layout::FieldLayout] = &[
+                    #( #entries ),*
+                ];
+            }
+
+            #layout_struct
+        )
+    }
+
+    /// Generates the `diff` and `changed_fields` methods for a bitfield convertible to `prim`.
+    ///
+    /// `self_raw` and `other_raw` are expressions converting `self` and `__bf_other`
+    /// to their `prim` representation.
+    fn generate_diff_methods(&self, config: &Config, prim: &TokenStream2, self_raw: &TokenStream2, other_raw: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let entries = self
+            .field_ranges(config)
+            .filter(|(info, _, _)| !info.config.skip_all())
+            .map(|(info, start, end)| {
+                let name = info.name();
+                quote_spanned!(span=> (#name, #start, #end))
+            })
+            .collect::<Vec<_>>();
+        quote_spanned!(span=>
+            /// Returns the bits which differ between `self` and `other`.
+            #[inline]
+            pub fn diff(&self, __bf_other: &Self) -> #prim {
+                (#self_raw) ^ (#other_raw)
+            }
+
+            /// Returns an iterator over the names of the fields which differ between `self` and `other`.
+            ///
+            /// Fields marked with `#[skip]` are never yielded.
+            #[inline]
+            pub fn changed_fields(&self, __bf_other: &Self) -> impl ::core::iter::Iterator<Item = &'static ::core::primitive::str> {
+                #[allow(clippy::identity_op)]
+                const __BF_FIELDS: &[::modular_bitfield::private::layout::FieldLayout] = &[
+                    #( #entries ),*
+                ];
+                let __bf_diff = self.diff(__bf_other);
+                ::core::iter::Iterator::map(
+                    ::core::iter::Iterator::filter(
+                        __BF_FIELDS.iter(),
+                        move |&&(_, __bf_start, __bf_end)| {
+                            let __bf_mask = <#prim>::MAX
+                                .checked_shr((<#prim>::BITS as ::core::primitive::usize - (__bf_end - __bf_start)) as ::core::primitive::u32)
+                                .unwrap_or(0);
+                            __bf_diff.checked_shr(__bf_start as ::core::primitive::u32).unwrap_or(0) & __bf_mask != 0
+                        },
+                    ),
+                    |&(__bf_name, _, _)| __bf_name,
+                )
+            }
+        )
+    }
+
+    /// Generates the `masked` method keeping only the bits of the given repr mask.
+    ///
+    /// `self_raw` must evaluate to the repr of `self` which is converted back via `From<#prim>`.
+    fn generate_masked_method(&self, prim: &TokenStream2, self_raw: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        quote_spanned!(span=>
+            /// Returns the bitfield with only the bits set in `mask` kept and all other bits cleared.
+            #[inline]
+            #[must_use]
+            pub fn masked(self, __bf_mask: #prim) -> Self {
+                <Self as ::core::convert::From<#prim>>::from((#self_raw) & __bf_mask)
+            }
+        )
+    }
+
+    /// Generates the `swap_raw` method replacing the whole bitfield by the given repr value.
+    ///
+    /// `self_raw` must evaluate to the repr of `self` which is converted back via `From<#prim>`.
+    fn generate_swap_raw_method(&self, config: &Config, prim: &TokenStream2, self_raw: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        quote_spanned!(span=>
+            /// Replaces the bitfield by the given raw value and returns the previous raw value.
+            ///
+            /// # Errors
+            ///
+            /// If `new` has bits set beyond the bits of the bitfield in which case the bitfield
+            /// is left unchanged.
+            #[inline]
+            #[allow(clippy::identity_op)]
+            pub fn swap_raw(
+                &mut self,
+                new: #prim,
+            ) -> ::core::result::Result<#prim, ::modular_bitfield::error::OutOfBounds> {
+                let __bf_bits: ::core::primitive::usize = #size;
+                if __bf_bits < <#prim>::BITS as ::core::primitive::usize && new >> __bf_bits != 0 {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+                let __bf_old = #self_raw;
+                *self = <Self as ::core::convert::From<#prim>>::from(new);
+                ::core::result::Result::Ok(__bf_old)
+            }
+        )
+    }
+
+    /// Generates the `patch` method overwriting the bits selected by a mask of the repr type.
+    ///
+    /// `self_raw` must evaluate to the repr of `self` which is converted back via `From<#prim>`.
+    fn generate_patch_method(&self, config: &Config, prim: &TokenStream2, self_raw: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        quote_spanned!(span=>
+            /// Overwrites the bits set in `mask` by the corresponding bits of `value` and keeps
+            /// all other bits, i.e. `self = (self & !mask) | (value & mask)`.
+            ///
+            /// # Errors
+            ///
+            /// If the patched value has bits set beyond the bits of the bitfield in which case
+            /// the bitfield is left unchanged.
+            #[inline]
+            #[allow(clippy::identity_op)]
+            pub fn patch(
+                &mut self,
+                mask: #prim,
+                value: #prim,
+            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                let __bf_bits: ::core::primitive::usize = #size;
+                let __bf_patched = ((#self_raw) & !mask) | (value & mask);
+                if __bf_bits < <#prim>::BITS as ::core::primitive::usize && __bf_patched >> __bf_bits != 0 {
+                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                }
+                *self = <Self as ::core::convert::From<#prim>>::from(__bf_patched);
+                ::core::result::Result::Ok(())
+            }
+        )
+    }
+
+    /// Generates `store_into`, `load_from` and `fetch_update_in` converting between the bitfield
+    /// and the atomic integer of its repr if the `atomic` crate feature is enabled.
+    ///
+    /// `self_raw` must evaluate to the repr of `self` which is converted back via `From<#prim>`.
+    /// Reprs without an atomic integer counterpart generate nothing.
+    fn generate_atomic_methods(&self, kind: ReprKind, prim: &TokenStream2, self_raw: &TokenStream2) -> Option<TokenStream2> {
+        if !cfg!(feature = "atomic") {
+            return None
+        }
+        let span = self.item_struct.span();
+        let (atomic, width) = match kind {
+            ReprKind::U8 => (quote_spanned!(span=> AtomicU8), "8"),
+            ReprKind::U16 => (quote_spanned!(span=> AtomicU16), "16"),
+            ReprKind::U32 => (quote_spanned!(span=> AtomicU32), "32"),
+            ReprKind::U64 => (quote_spanned!(span=> AtomicU64), "64"),
+            ReprKind::U128 | ReprKind::ByteArray(_) | ReprKind::Bool => return None,
+        };
+        Some(quote_spanned!(span=>
+            /// Stores the bitfield into the given atomic integer.
+            #[cfg(target_has_atomic = #width)]
+            #[inline]
+            pub fn store_into(
+                &self,
+                atomic: &::core::sync::atomic::#atomic,
+                order: ::core::sync::atomic::Ordering,
+            ) {
+                atomic.store(#self_raw, order)
+            }
+
+            /// Loads the bitfield from the given atomic integer.
+            #[cfg(target_has_atomic = #width)]
+            #[inline]
+            pub fn load_from(
+                atomic: &::core::sync::atomic::#atomic,
+                order: ::core::sync::atomic::Ordering,
+            ) -> Self {
+                <Self as ::core::convert::From<#prim>>::from(atomic.load(order))
+            }
+
+            /// Atomically reads, modifies and writes back the bitfield stored in the given
+            /// atomic integer, retrying while other threads interfere.
+            ///
+            /// Behaves like `fetch_update` of the atomic integer with `f` operating on bitfields.
+            ///
+            /// # Errors
+            ///
+            /// If `f` returns `None` in which case the current bitfield is returned.
+            #[cfg(target_has_atomic = #width)]
+            #[inline]
+            pub fn fetch_update_in<F>(
+                atomic: &::core::sync::atomic::#atomic,
+                set_order: ::core::sync::atomic::Ordering,
+                fetch_order: ::core::sync::atomic::Ordering,
+                mut f: F,
+            ) -> ::core::result::Result<Self, Self>
+            where
+                F: ::core::ops::FnMut(Self) -> ::core::option::Option<Self>,
+            {
+                atomic
+                    .fetch_update(set_order, fetch_order, |__bf_raw| {
+                        f(<Self as ::core::convert::From<#prim>>::from(__bf_raw))
+                            .map(<#prim as ::core::convert::From<Self>>::from)
+                    })
+                    .map(<Self as ::core::convert::From<#prim>>::from)
+                    .map_err(<Self as ::core::convert::From<#prim>>::from)
+            }
+        ))
+    }
+
+    /// Generates the `truncated_to_bits` method returning the repr value with only its low
+    /// `n` bits kept.
+    ///
+    /// `self_raw` must evaluate to the repr of `self`.
+    fn generate_truncated_to_bits_method(&self, config: &Config, prim: &TokenStream2, self_raw: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        quote_spanned!(span=>
+            /// Returns the raw value of the bitfield with only the low `n` bits kept.
+            ///
+            /// # Panics
+            ///
+            /// If `n` exceeds the bits of the bitfield.
+            #[inline]
+            #[allow(clippy::identity_op)]
+            pub fn truncated_to_bits(&self, n: ::core::primitive::usize) -> #prim {
+                let __bf_bits: ::core::primitive::usize = #size;
+                ::core::assert!(
+                    n <= __bf_bits,
+                    "cannot truncate to {} bits since the bitfield only has {} bits",
+                    n,
+                    __bf_bits,
+                );
+                let __bf_mask = (1 as #prim)
+                    .checked_shl(n as ::core::primitive::u32)
+                    .map_or(<#prim>::MAX, |__bf_bit| __bf_bit - 1);
+                (#self_raw) & __bf_mask
+            }
+        )
+    }
+
+    /// Generates `From<#ident>` impls for all unsigned integers wider than the `#[repr(uN)]`
+    /// if the `widening_from` flag is set.
+    ///
+    /// The conversions go through the `From<#ident>` impl of the repr itself which must be
+    /// bounded by the same `where_clause`.
+    fn generate_widening_from_impls(
+        &self,
+        config: &Config,
+        repr: ReprKind,
+        where_clause: &TokenStream2,
+    ) -> Option<TokenStream2> {
+        let span = config.widening_from.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let prim = repr.into_quote();
+        let wider = [ReprKind::U16, ReprKind::U32, ReprKind::U64, ReprKind::U128]
+            .iter()
+            .filter(|wider| {
+                !matches!(repr, ReprKind::ByteArray(_) | ReprKind::Bool) && wider.bits() > repr.bits()
+            })
+            .map(|wider| wider.into_quote());
+        Some(quote_spanned!(span=>
+            #(
+                impl ::core::convert::From<#ident> for #wider
+                #where_clause
+                {
+                    #[inline]
+                    fn from(__bf_bitfield: #ident) -> Self {
+                        <Self as ::core::convert::From<#prim>>::from(
+                            <#prim as ::core::convert::From<#ident>>::from(__bf_bitfield),
+                        )
+                    }
+                }
+            )*
+        ))
+    }
+
+    /// Returns `#[allow(deprecated)]` if any field of the bitfield is `#[deprecated]`.
+    ///
+    /// Generated impls calling the accessors of all fields use this to not warn on their own.
+    fn generate_allow_deprecated(&self, config: &Config) -> Option<TokenStream2> {
+        self.field_infos(config)
+            .find_map(|info| info.config.deprecated.as_ref().map(|deprecated| deprecated.span))
+            .map(|span| quote_spanned!(span=> #[allow(deprecated)]))
+    }
+
     /// Generates code to check for the bit size arguments of bitfields.
     fn expand_bits_checks_for_field(&self, field_info: FieldInfo<'_>) -> TokenStream2 {
+        let name = field_info.name();
         let FieldInfo {
             index: _,
             field,
@@ -192,11 +1594,15 @@ impl BitfieldStruct {
                 let ty = &field.ty;
                 let expected_bits = bits.value;
                 let span = bits.span;
+                let error_msg = format!(
+                    "field {} with #[bits = {}] is narrower than its specifier",
+                    name, expected_bits,
+                );
                 Some(quote_spanned!(span =>
-                    let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]> =
-                        ::modular_bitfield::private::checks::BitsCheck::<[(); #expected_bits]>{
-                            arr: [(); <#ty as ::modular_bitfield::Specifier>::BITS]
-                        };
+                    ::core::assert!(
+                        #expected_bits >= <#ty as ::modular_bitfield::Specifier>::BITS,
+                        #error_msg,
+                    );
                 ))
             }
             None => None,
@@ -208,3 +1614,15 @@ impl BitfieldStruct {
         )
     }
 }
+
+/// Converts the given `snake_case` name of a field into the `CamelCase` name of its flag.
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .flat_map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase());
+            first.into_iter().chain(chars)
+        })
+        .collect()
+}
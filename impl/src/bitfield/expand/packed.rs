@@ -4,7 +4,8 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Token;
 use crate::bitfield::BitfieldStruct;
-use crate::bitfield::config::{Config, ReprKind};
+use crate::bitfield::config::{Config, DebugKind, OverflowKind, ReprKind};
+use crate::bitfield::field_config::{ChecksumAlgo, ChecksumConfig, ChecksumEnd};
 use crate::bitfield::field_info::FieldInfo;
 
 impl BitfieldStruct {
@@ -12,26 +13,118 @@ impl BitfieldStruct {
     pub fn expand_packed(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let check_filled = self.generate_check_for_filled(config);
+        let check_empty = self.generate_check_for_empty(config);
         let struct_definition = self.generate_struct(config);
         let constructor_definition = self.generate_constructor(config);
         let specifier_impl = self.generate_specifier_impl(config);
 
         let byte_conversion_impls = self.expand_byte_conversion_impls(config);
+        let from_bytes_args_impl = self.generate_from_bytes_args_impl(config);
         let byte_update_impls = self.generate_byte_update_impls(config);
+        let word_conversion_impls = self.generate_word_conversion_impls(config);
+        let from_bytes_at_impl = self.generate_from_bytes_at_impl(config);
+        let parse_le_impl = self.generate_parse_le_impl(config);
+        let be_bytes_impls = self.generate_be_bytes_impls(config);
+        let from_bits_iter_impl = self.generate_from_bits_iter_impl(config);
+        let enumerate_impl = self.generate_enumerate_impl(config);
+        let try_from_bytes_all_impl = self.generate_try_from_bytes_all_impl(config);
+        let heapless_impl = self.generate_heapless_impl(config);
+        let validate_impl = self.generate_validate_impl(config);
+        let payload_impls = self.generate_payload_impls(config);
+        let extracted_types = self.generate_extracted_types(config);
+        let bit_view_impls = self.generate_bit_view_impls(config);
+        let bits_range_impls = self.generate_bits_range_impls(config);
+        let borrow_impl = self.generate_borrow_impl(config);
+        let rotate_impls = self.generate_rotate_impls(config);
+        let is_zero_impls = self.generate_is_zero_impls(config);
+        let hamming_distance_impl = self.generate_hamming_distance_impl(config);
+        let bcd_impls = self.generate_bcd_impls(config);
+        let view_impls = self.generate_view_impls(config);
+        let borrowed_view = self.generate_borrowed_view(config);
+        let by_value_getters_check = self.generate_by_value_getters_check(config);
         let getters_and_setters = self.expand_getters_and_setters(config);
         let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
+        let layout_const = self.generate_layout_const(config);
         let debug_impl = self.generate_debug_impl(config);
+        let defmt_impl = self.generate_defmt_impl(config);
+        let try_from_u128_impl = self.generate_try_from_u128_impl(config);
+        let from_field_impl = self.generate_from_field_impl(config);
+        let flags_iter = self.generate_flags_iter(config);
+        let index_by_impl = self.generate_index_by_impl(config);
+        let float_view_method = self.generate_float_view_method(config);
+        let group_getters = self.generate_group_getters(config);
+        let impl_trait_impls = self.generate_impl_trait_impls(config);
+        let ord_impl = self.generate_ord_impl(config);
+        let storage = config.storage_ident(span);
+        let try_set_many_method = self.generate_try_set_many_method(config);
+        let serde_impls = self.generate_serde_impls(
+            config,
+            &quote_spanned!(span=> self.#storage),
+        );
+        let partial_eq_impl = self.generate_partial_eq_impl(
+            config,
+            &quote_spanned!(span=> &self.#storage[..]),
+            &quote_spanned!(span=> &other.#storage[..]),
+        );
+        let hash_impl = self.generate_hash_impl(config);
+        let from_field_map = self.generate_from_field_map(config);
+        let from_str_impl = self.generate_from_str_impl(config);
+        let plain_struct = self.generate_plain_struct(config);
+        let octal_impl = self.generate_octal_impl(config);
+        let decoder = self.generate_decoder(config);
 
         quote_spanned!(span=>
             #struct_definition
             #check_filled
+            #check_empty
             #constructor_definition
             #byte_conversion_impls
+            #from_bytes_args_impl
+            #try_from_u128_impl
+            #from_field_impl
             #byte_update_impls
+            #word_conversion_impls
+            #from_bytes_at_impl
+            #parse_le_impl
+            #be_bytes_impls
+            #from_bits_iter_impl
+            #enumerate_impl
+            #try_from_bytes_all_impl
+            #heapless_impl
+            #serde_impls
+            #try_set_many_method
+            #validate_impl
+            #payload_impls
+            #extracted_types
+            #bit_view_impls
+            #bits_range_impls
+            #borrow_impl
+            #rotate_impls
+            #is_zero_impls
+            #hamming_distance_impl
+            #bcd_impls
+            #view_impls
+            #borrowed_view
+            #by_value_getters_check
             #getters_and_setters
             #specifier_impl
+            #layout_const
             #repr_impls_and_checks
             #debug_impl
+            #defmt_impl
+            #flags_iter
+            #index_by_impl
+            #float_view_method
+            #group_getters
+            #impl_trait_impls
+            #ord_impl
+            #partial_eq_impl
+            #hash_impl
+            #from_field_map
+            #from_str_impl
+            #plain_struct
+            #octal_impl
+            #decoder
         )
     }
 
@@ -42,6 +135,7 @@ impl BitfieldStruct {
     pub fn generate_specifier_impl(&self, config: &Config) -> Option<TokenStream2> {
         config.derive_specifier.as_ref()?;
         let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
         let ident = &self.item_struct.ident;
         let bits = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&bits);
@@ -67,7 +161,7 @@ impl BitfieldStruct {
                 ) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
                     ::core::result::Result::Ok(
                         <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::array_into_bytes(
-                            value.bytes
+                            value.#storage
                         )
                     )
                 }
@@ -85,7 +179,7 @@ impl BitfieldStruct {
                     }
                     let __bf_bytes = bytes.to_le_bytes();
                     ::core::result::Result::Ok(Self {
-                        bytes: <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
+                        #storage: <[(); #next_divisible_by_8] as ::modular_bitfield::private::ArrayBytesConversion>::bytes_into_array(bytes)
                     })
                 }
             }
@@ -93,9 +187,23 @@ impl BitfieldStruct {
     }
 
     /// Generates the core::fmt::Debug impl if `#[derive(Debug)]` is included.
+    ///
+    /// With `debug = verbose` the raw underlying bytes are appended in hex.
     pub fn generate_debug_impl(&self, config: &Config) -> Option<TokenStream2> {
         config.derive_debug.as_ref()?;
         let span = self.item_struct.span();
+        match config.debug.as_ref().map(|debug| debug.value) {
+            Some(DebugKind::ByByte) => return Some(self.generate_debug_by_byte_impl(config)),
+            Some(DebugKind::Compact) => return Some(self.generate_debug_compact_impl(config)),
+            _ => (),
+        }
+        let raw = config.debug.as_ref().map(|debug| {
+            let span = debug.span;
+            let storage = config.storage_ident(span);
+            quote_spanned!(span=>
+                __bf_debug.field("raw", &::modular_bitfield::private::RawHex(&self.#storage[..]));
+            )
+        });
         let ident = &self.item_struct.ident;
         let fields = self.field_infos(config).map(|info| {
             let FieldInfo {
@@ -107,6 +215,7 @@ impl BitfieldStruct {
                 return None
             }
             let field_span = field.span();
+            let cfg_attrs = info.cfg_attrs();
             let field_name = info.name();
             let field_ident = info.ident_frag();
             let field_getter = field
@@ -114,22 +223,250 @@ impl BitfieldStruct {
                 .as_ref()
                 .map(|_| format_ident!("{}_or_err", field_ident))
                 .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+            let field_value = Self::generate_debug_field_value(
+                &info,
+                quote_spanned!(field_span=> ::core::result::Result::as_ref(&self.#field_getter())),
+            );
             Some(quote_spanned!(field_span=>
-                .field(
+                #( #cfg_attrs )*
+                __bf_debug.field(
                     #field_name,
-                    self.#field_getter()
+                    #field_value
                         .as_ref()
                         .map(|__bf_field| __bf_field as &dyn (::core::fmt::Debug))
                         .unwrap_or_else(|__bf_err| __bf_err as &dyn (::core::fmt::Debug))
-                )
+                );
             ))
         });
+        let allow_deprecated = self.generate_allow_deprecated(config);
         Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl ::core::fmt::Debug for #ident {
+                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut __bf_debug = __bf_f.debug_struct(::core::stringify!(#ident));
+                    #( #fields )*
+                    #raw
+                    __bf_debug.finish()
+                }
+            }
+        ))
+    }
+
+    /// Wraps the given `Result<&T, &E>` of a field getter into `WithUnits` if the field is
+    /// annotated with `#[units = ".."]` so that its `Debug` output ends with the units.
+    fn generate_debug_field_value(info: &FieldInfo<'_>, value: TokenStream2) -> TokenStream2 {
+        let units = match &info.config.units {
+            Some(units) => units,
+            None => return value,
+        };
+        let span = info.field.span();
+        let units = &units.value;
+        quote_spanned!(span=>
+            ::core::result::Result::map(
+                #value,
+                |__bf_field| ::modular_bitfield::private::WithUnits(__bf_field, #units),
+            )
+        )
+    }
+
+    /// Generates the `Debug` impl for `debug = by_byte` grouping the fields under the index of
+    /// the byte containing their first bit.
+    fn generate_debug_by_byte_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (bindings, entries): (Vec<_>, Vec<_>) = self
+            .field_ranges(config)
+            .filter_map(|(info, start, _)| {
+                if info.config.skip_getters() {
+                    return None
+                }
+                let field_span = info.field.span();
+                let cfg_attrs = info.cfg_attrs().collect::<Vec<_>>();
+                let field_name = info.name();
+                let field_ident = info.ident_frag();
+                let field_getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .map(|_| format_ident!("{}_or_err", field_ident))
+                    .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+                let raw_binding = format_ident!("__bf_raw_{}", info.index);
+                let binding = format_ident!("__bf_field_{}", info.index);
+                let field_value = Self::generate_debug_field_value(
+                    &info,
+                    quote_spanned!(field_span=> ::core::result::Result::as_ref(&#raw_binding)),
+                );
+                let binding_stmts = quote_spanned!(field_span=>
+                    #( #cfg_attrs )*
+                    let #raw_binding = self.#field_getter();
+                    #( #cfg_attrs )*
+                    let #binding = #field_value;
+                );
+                let entry = quote_spanned!(field_span=>
+                    #( #cfg_attrs )*
+                    (
+                        (#start) / 8usize,
+                        #field_name,
+                        #binding
+                            .as_ref()
+                            .map(|__bf_field| __bf_field as &dyn ::core::fmt::Debug)
+                            .unwrap_or_else(|__bf_err| __bf_err as &dyn ::core::fmt::Debug),
+                    )
+                );
+                Some((binding_stmts, entry))
+            })
+            .unzip();
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        quote_spanned!(span=>
+            #allow_deprecated
+            impl ::core::fmt::Debug for #ident {
+                #[allow(clippy::identity_op)]
+                fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    #( #bindings )*
+                    ::core::fmt::Debug::fmt(
+                        &::modular_bitfield::private::DebugByByte(
+                            ::core::stringify!(#ident),
+                            &[ #( #entries ),* ],
+                        ),
+                        __bf_f,
+                    )
+                }
+            }
+        )
+    }
+
+    /// Generates the `Debug` impl for `debug = compact` formatting the fields as comma
+    /// separated `name=value` pairs such as `Reg(a=1,b=2)`.
+    fn generate_debug_compact_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let (bindings, entries): (Vec<_>, Vec<_>) = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters())
+            .map(|info| {
+                let field_span = info.field.span();
+                let cfg_attrs = info.cfg_attrs().collect::<Vec<_>>();
+                let field_name = info.name();
+                let field_ident = info.ident_frag();
+                let field_getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .map(|_| format_ident!("{}_or_err", field_ident))
+                    .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+                let raw_binding = format_ident!("__bf_raw_{}", info.index);
+                let binding = format_ident!("__bf_field_{}", info.index);
+                let field_value = Self::generate_debug_field_value(
+                    &info,
+                    quote_spanned!(field_span=> ::core::result::Result::as_ref(&#raw_binding)),
+                );
+                let binding_stmts = quote_spanned!(field_span=>
+                    #( #cfg_attrs )*
+                    let #raw_binding = self.#field_getter();
+                    #( #cfg_attrs )*
+                    let #binding = #field_value;
+                );
+                let entry = quote_spanned!(field_span=>
+                    #( #cfg_attrs )*
+                    (
+                        #field_name,
+                        #binding
+                            .as_ref()
+                            .map(|__bf_field| __bf_field as &dyn ::core::fmt::Debug)
+                            .unwrap_or_else(|__bf_err| __bf_err as &dyn ::core::fmt::Debug),
+                    )
+                );
+                (binding_stmts, entry)
+            })
+            .unzip();
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        quote_spanned!(span=>
+            #allow_deprecated
             impl ::core::fmt::Debug for #ident {
                 fn fmt(&self, __bf_f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                    __bf_f.debug_struct(::core::stringify!(#ident))
-                        #( #fields )*
-                        .finish()
+                    #( #bindings )*
+                    ::core::fmt::Debug::fmt(
+                        &::modular_bitfield::private::DebugCompact(
+                            ::core::stringify!(#ident),
+                            &[ #( #entries ),* ],
+                        ),
+                        __bf_f,
+                    )
+                }
+            }
+        )
+    }
+
+    /// Generates the `Hash` impl if `#[derive(Hash)]` is included.
+    ///
+    /// Hashes the underlying bytes directly while masking out the bits beyond the bitfield width
+    /// as well as the bits of `#[eq(ignore)]` fields so that it agrees with `PartialEq`.
+    /// Only without any masked bits does it also agree with the `Hash` impl of the bytes.
+    fn generate_hash_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.derive_hash.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let bits = self.generate_target_or_actual_bitfield_size(config);
+        let ignored = self.generate_eq_ignored_ranges(config);
+        Some(quote_spanned!(span=>
+            impl ::core::hash::Hash for #ident {
+                #[inline]
+                #[allow(clippy::identity_op, unused_braces)]
+                fn hash<__BfH: ::core::hash::Hasher>(&self, state: &mut __BfH) {
+                    ::modular_bitfield::private::hash_bits_ignoring(
+                        &self.#storage,
+                        #bits,
+                        &[ #( #ignored ),* ],
+                        state,
+                    )
+                }
+            }
+        ))
+    }
+
+    /// Generates the `defmt::Format` impl if `#[derive(defmt::Format)]` is included
+    /// and the `defmt` crate feature is enabled.
+    fn generate_defmt_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.derive_defmt.as_ref()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let header = format!("{} {{{{ ", ident);
+        let fields = self.field_infos(config).map(|info| {
+            let FieldInfo {
+                index: _,
+                field,
+                config,
+            } = &info;
+            if config.skip_getters() {
+                return None
+            }
+            let field_span = field.span();
+            let cfg_attrs = info.cfg_attrs();
+            let format = format!("{{=str}}{}: {{}}", info.name());
+            let field_ident = info.ident_frag();
+            let field_getter = field
+                .ident
+                .as_ref()
+                .map(|_| format_ident!("{}_or_err", field_ident))
+                .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+            Some(quote_spanned!(field_span=>
+                #( #cfg_attrs )*
+                {
+                    ::defmt::write!(__bf_f, #format, __bf_separator, self.#field_getter());
+                    __bf_separator = ", ";
+                }
+            ))
+        });
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl ::defmt::Format for #ident {
+                #[allow(unused_assignments, unused_mut)]
+                fn format(&self, __bf_f: ::defmt::Formatter<'_>) {
+                    let mut __bf_separator: &::core::primitive::str = "";
+                    ::defmt::write!(__bf_f, #header);
+                    #( #fields )*
+                    ::defmt::write!(__bf_f, " }}");
                 }
             }
         ))
@@ -144,61 +481,365 @@ impl BitfieldStruct {
         let attrs = &config.retained_attributes;
         let vis = &self.item_struct.vis;
         let ident = &self.item_struct.ident;
+        let storage = config.storage_ident(span);
+        let storage_vis = config.storage.as_ref().map(|_| vis);
+        let repr_transparent = config
+            .transparent
+            .as_ref()
+            .map(|transparent| quote_spanned!(transparent.span=> #[repr(transparent)]));
+        let repr_align = config.max_align_bytes().map(|align| {
+            let align = proc_macro2::Literal::usize_unsuffixed(align);
+            quote_spanned!(span=> #[repr(align(#align))])
+        });
+        let clone_copy_derive = Self::generate_clone_copy_derive(config);
+        let clone_copy_check = self.generate_clone_copy_check(config);
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
         quote_spanned!(span=>
             #( #attrs )*
+            #clone_copy_derive
+            #repr_transparent
+            #repr_align
             #[allow(clippy::identity_op)]
             #vis struct #ident
             {
-                bytes: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
+                #storage_vis #storage: [::core::primitive::u8; #next_divisible_by_8 / 8usize],
             }
+
+            #clone_copy_check
         )
     }
 
     /// Generates the constructor for the bitfield that initializes all bytes to zero.
     fn generate_constructor(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let zero_const = config.zero_const.as_ref().map(|zero_const| {
+            let span = zero_const.span;
+            quote_spanned!(span=>
+                /// An instance with zero initialized data usable in constant contexts.
+                pub const ZERO: Self = Self {
+                    #storage: [0u8; #next_divisible_by_8 / 8usize],
+                };
+            )
+        });
+        let max_const = self.generate_max_const(config);
+        let defaults = self.generate_default_inits(config);
+        let magics = self.generate_magic_inits(config);
+        if defaults.is_empty() && magics.is_empty() {
+            return quote_spanned!(span=>
+                impl #ident
+                {
+                    /// Returns an instance with zero initialized data.
+                    #[allow(clippy::identity_op, clippy::new_without_default)]
+                    pub const fn new() -> Self {
+                        Self {
+                            #storage: [0u8; #next_divisible_by_8 / 8usize],
+                        }
+                    }
+
+                    #zero_const
+                    #max_const
+                }
+            )
+        }
+        let docs = match magics.is_empty() {
+            true => {
+                "Returns an instance with zero initialized data apart from the fields whose \
+                 specifier has a non-zero default, e.g. enums with a `#[default]` variant."
+            }
+            false => {
+                "Returns an instance with zero initialized data apart from the \
+                 `#[magic = N]` fields which are initialized to their magic values and the \
+                 fields whose specifier has a non-zero default, e.g. enums with a `#[default]` variant."
+            }
+        };
+        let (asserts, inits): (Vec<_>, Vec<_>) = magics.into_iter().unzip();
         quote_spanned!(span=>
+            #( #asserts )*
+
             impl #ident
             {
-                /// Returns an instance with zero initialized data.
-                #[allow(clippy::identity_op)]
+                #[doc = #docs]
+                #[allow(clippy::identity_op, clippy::new_without_default)]
                 pub const fn new() -> Self {
+                    #[allow(clippy::identity_op)]
+                    const __BF_INIT: [u8; #next_divisible_by_8 / 8usize] = {
+                        let __bf_bytes = [0u8; #next_divisible_by_8 / 8usize];
+                        #( #defaults )*
+                        #( #inits )*
+                        __bf_bytes
+                    };
                     Self {
-                        bytes: [0u8; #next_divisible_by_8 / 8usize],
+                        #storage: __BF_INIT,
                     }
                 }
+
+                #zero_const
+                #max_const
             }
         )
     }
 
-    /// Generates `From` impls for a `#[repr(uN)]` annotated #[bitfield] struct.
+    /// Generates the `MAX` constant for the `max_const` parameter with every field apart from
+    /// the `#[reserved]` fields set to the `Specifier::MAX_BITS` of its specifier.
+    ///
+    /// `#[magic = N]` fields keep their magic value and the `#[checksum(..)]` field is computed
+    /// from the other fields so that `MAX` still validates.
+    fn generate_max_const(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.max_const.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let ranges = self.field_ranges(config).collect::<Vec<_>>();
+        let mut inits = Vec::new();
+        for (info, start, end) in &ranges {
+            if info.config.reserved.is_some() {
+                continue
+            }
+            let ty = &info.field.ty;
+            let cfg_attrs = info.cfg_attrs();
+            let value = match info.config.magic.as_ref() {
+                Some(magic) => {
+                    let magic = proc_macro2::Literal::u128_suffixed(magic.value);
+                    quote_spanned!(span=> #magic)
+                }
+                None => quote_spanned!(span=> <#ty as ::modular_bitfield::Specifier>::MAX_BITS),
+            };
+            inits.push(quote_spanned!(span=>
+                #( #cfg_attrs )*
+                let __bf_bytes = ::modular_bitfield::private::with_bits(
+                    __bf_bytes,
+                    #start,
+                    #end,
+                    #value,
+                );
+            ));
+        }
+        // The checksum is computed last since it depends on the values of the covered fields.
+        let checksum = ranges.iter().find_map(|(info, start, end)| {
+            let checksum = info.config.checksum.as_ref()?;
+            let (over_start, over_end) = Self::checksum_cover(&ranges, &checksum.value);
+            let compute = match checksum.value.algo {
+                ChecksumAlgo::Xor => {
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::xor_checksum(
+                            &__bf_bytes,
+                            #over_start,
+                            #over_end,
+                            (#end) - (#start),
+                        )
+                    )
+                }
+            };
+            Some(quote_spanned!(span=>
+                let __bf_bytes = ::modular_bitfield::private::with_bits(
+                    __bf_bytes,
+                    #start,
+                    #end,
+                    #compute,
+                );
+            ))
+        });
+        Some(quote_spanned!(span=>
+            /// An instance with every field set to its maximum valid value usable in
+            /// constant contexts.
+            ///
+            /// Numeric fields have all their bits set and enum fields hold their variant with
+            /// the largest discriminant. `#[magic = N]` fields keep their magic value, the
+            /// `#[checksum(..)]` field matches the other fields and `#[reserved]` fields
+            /// remain zero.
+            #[allow(clippy::identity_op)]
+            pub const MAX: Self = Self {
+                #storage: {
+                    let __bf_bytes = [0u8; #next_divisible_by_8 / 8usize];
+                    #( #inits )*
+                    #checksum
+                    __bf_bytes
+                },
+            };
+        ))
+    }
+
+    /// Generates the statements writing the `Specifier::DEFAULT_BITS` of every field that is not
+    /// a primitive specifier into the `__bf_bytes` of `new()`.
+    ///
+    /// Primitive specifiers always default to zero and are skipped.
+    fn generate_default_inits(&self, config: &Config) -> Vec<TokenStream2> {
+        let span = self.item_struct.span();
+        let mut defaults = Vec::new();
+        for (info, start, end) in self.field_ranges(config) {
+            if info.is_primitive_specifier() {
+                continue
+            }
+            let ty = &info.field.ty;
+            let cfg_attrs = info.cfg_attrs();
+            defaults.push(quote_spanned!(span=>
+                #( #cfg_attrs )*
+                let __bf_bytes = ::modular_bitfield::private::with_bits(
+                    __bf_bytes,
+                    #start,
+                    #end,
+                    <#ty as ::modular_bitfield::Specifier>::DEFAULT_BITS,
+                );
+            ));
+        }
+        defaults
+    }
+
+    /// Generates the compile time checks that the `#[magic = N]` values fit into their fields
+    /// together with the statements writing them into the `__bf_bytes` of `new()`.
+    fn generate_magic_inits(&self, config: &Config) -> Vec<(TokenStream2, TokenStream2)> {
+        let mut magics = Vec::new();
+        for (info, start, end) in self.field_ranges(config) {
+            let magic = match info.config.magic.as_ref() {
+                Some(magic) => magic,
+                None => continue,
+            };
+            let span = magic.span;
+            let value = proc_macro2::Literal::u128_suffixed(magic.value);
+            let cfg_attrs = info.cfg_attrs().collect::<Vec<_>>();
+            let error_msg = format!(
+                "#[magic = {:#x}] does not fit into field {}",
+                magic.value,
+                info.name()
+            );
+            let assert = quote_spanned!(span=>
+                #( #cfg_attrs )*
+                #[allow(clippy::identity_op)]
+                const _: () = ::core::assert!(
+                    (#end) - (#start) >= 128 || #value >> ((#end) - (#start)) == 0,
+                    #error_msg
+                );
+            );
+            let init = quote_spanned!(span=>
+                #( #cfg_attrs )*
+                let __bf_bytes = ::modular_bitfield::private::with_bits(__bf_bytes, #start, #end, #value);
+            );
+            magics.push((assert, init));
+        }
+        magics
+    }
+
+    /// Generates `From` impls for a `#[repr(uN)]`, `repr = [u8; N]` or `repr = bool` annotated
+    /// #[bitfield] struct.
+    ///
+    /// For `#[repr(uN)]` this also generates a `PartialEq<uN>` impl for direct comparisons
+    /// as well as the `diff` and `changed_fields` methods.
     fn expand_repr_from_impls_and_checks(&self, config: &Config) -> Option<TokenStream2> {
         let ident = &self.item_struct.ident;
         config.repr.as_ref().map(|repr| {
             let kind = &repr.value;
             let span = repr.span;
+            let storage = config.storage_ident(span);
             let prim = kind.into_quote();
             let actual_bits = self.generate_target_or_actual_bitfield_size(config);
+            let diff_methods = self.generate_diff_methods(
+                config,
+                &prim,
+                &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)),
+                &quote_spanned!(span=> <#prim>::from_le_bytes(__bf_other.#storage)),
+            );
+            let masked_method = self.generate_masked_method(&prim, &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)));
             let trait_check_ident = match kind {
                 ReprKind::U8 => quote! { IsU8Compatible },
                 ReprKind::U16 => quote! { IsU16Compatible },
                 ReprKind::U32 => quote! { IsU32Compatible },
                 ReprKind::U64 => quote! { IsU64Compatible },
                 ReprKind::U128 => quote! { IsU128Compatible },
+                ReprKind::ByteArray(_) => {
+                    let repr_bits = kind.bits();
+                    return quote_spanned!(span=>
+                        const _: () = {
+                            let _: ::modular_bitfield::private::checks::BitsCheck::<[(); #repr_bits]> =
+                                ::modular_bitfield::private::checks::BitsCheck::<[(); #repr_bits]>{
+                                    arr: [(); #actual_bits]
+                                };
+                        };
+
+                        impl ::core::convert::From<#prim> for #ident {
+                            #[inline(always)]
+                            fn from(__bf_bytes: #prim) -> Self {
+                                Self { #storage: __bf_bytes }
+                            }
+                        }
+
+                        impl ::core::convert::From<#ident> for #prim {
+                            #[inline(always)]
+                            fn from(__bf_bitfield: #ident) -> Self {
+                                __bf_bitfield.#storage
+                            }
+                        }
+                    )
+                }
+                ReprKind::Bool => {
+                    return quote_spanned!(span=>
+                        const _: () = {
+                            let _: ::modular_bitfield::private::checks::BitsCheck::<[(); 1usize]> =
+                                ::modular_bitfield::private::checks::BitsCheck::<[(); 1usize]>{
+                                    arr: [(); #actual_bits]
+                                };
+                        };
+
+                        impl ::core::convert::From<#prim> for #ident {
+                            #[inline(always)]
+                            fn from(__bf_bool: #prim) -> Self {
+                                Self { #storage: [__bf_bool as ::core::primitive::u8] }
+                            }
+                        }
+
+                        impl ::core::convert::From<#ident> for #prim {
+                            #[inline(always)]
+                            fn from(__bf_bitfield: #ident) -> Self {
+                                __bf_bitfield.#storage[0] & 0x01 != 0
+                            }
+                        }
+                    )
+                }
             };
+            let contains_method = self.generate_contains_method(config, &prim);
+            let field_masks = self.generate_field_masks(config, &prim);
+            let swap_raw_method = self.generate_swap_raw_method(
+                config,
+                &prim,
+                &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)),
+            );
+            let patch_method = self.generate_patch_method(
+                config,
+                &prim,
+                &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)),
+            );
+            let truncated_to_bits_method = self.generate_truncated_to_bits_method(
+                config,
+                &prim,
+                &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)),
+            );
+            let atomic_methods = self.generate_atomic_methods(
+                *kind,
+                &prim,
+                &quote_spanned!(span=> <#prim>::from_le_bytes(self.#storage)),
+            );
+            let widening_from_impls = self.generate_widening_from_impls(
+                config,
+                *kind,
+                &quote_spanned!(span=>
+                    where
+                        [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                ),
+            );
             quote_spanned!(span=>
+                #widening_from_impls
+
                 impl ::core::convert::From<#prim> for #ident
                 where
                     [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
                 {
                     #[inline(always)]
                     fn from(__bf_prim: #prim) -> Self {
-                        Self { bytes: <#prim>::to_le_bytes(__bf_prim) }
+                        Self { #storage: <#prim>::to_le_bytes(__bf_prim) }
                     }
                 }
 
@@ -208,16 +849,161 @@ impl BitfieldStruct {
                 {
                     #[inline(always)]
                     fn from(__bf_bitfield: #ident) -> Self {
-                        <Self>::from_le_bytes(__bf_bitfield.bytes)
+                        <Self>::from_le_bytes(__bf_bitfield.#storage)
+                    }
+                }
+
+                impl ::core::cmp::PartialEq<#prim> for #ident
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    #[inline]
+                    fn eq(&self, __bf_other: &#prim) -> ::core::primitive::bool {
+                        <#prim>::from_le_bytes(self.#storage) == *__bf_other
                     }
                 }
+
+                impl #ident
+                where
+                    [(); #actual_bits]: ::modular_bitfield::private::#trait_check_ident,
+                {
+                    #diff_methods
+                    #masked_method
+                    #swap_raw_method
+                    #patch_method
+                    #truncated_to_bits_method
+                    #atomic_methods
+                    #contains_method
+                    #field_masks
+                }
             )
         })
     }
 
+    /// Generates `contains` checking that all bits of a mask are set.
+    ///
+    /// The masks are meant to be built from the `F_MASK` constants of the fields.
+    fn generate_contains_method(&self, config: &Config, prim: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        quote_spanned!(span=>
+            /// Returns `true` if all bits set in `mask` are also set in the bitfield.
+            #[inline]
+            pub const fn contains(&self, mask: #prim) -> ::core::primitive::bool {
+                <#prim>::from_le_bytes(self.#storage) & mask == mask
+            }
+        )
+    }
+
+    /// Generates a mask constant of the integer repr for every field, named after the field in
+    /// upper case with a `_MASK` suffix, as well as the hidden `__bf_field_mask` function which
+    /// backs the `field_mask!` macro.
+    fn generate_field_masks(&self, config: &Config, prim: &TokenStream2) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let mask_consts = self
+            .field_ranges(config)
+            .filter_map(|(info, start, end)| {
+                if info.config.skip_all() {
+                    return None
+                }
+                let field_span = info.field.span();
+                let retained_attrs = &info.config.retained_attrs;
+                let const_prefix = match &info.field.ident {
+                    Some(ident) => syn::ext::IdentExt::unraw(ident).to_string().to_uppercase(),
+                    None => format!("_{}", info.index),
+                };
+                let const_ident = format_ident!("{}_MASK", const_prefix, span = field_span);
+                let const_docs = format!("The mask of the bits occupied by {}.", info.name());
+                Some(quote_spanned!(field_span=>
+                    #[doc = #const_docs]
+                    #[allow(clippy::identity_op)]
+                    #( #retained_attrs )*
+                    pub const #const_ident: #prim =
+                        ::modular_bitfield::private::layout::range_mask(#start, #end) as #prim;
+                ))
+            })
+            .collect::<Vec<_>>();
+        quote_spanned!(span=>
+            #( #mask_consts )*
+
+            #[doc(hidden)]
+            #[inline]
+            pub const fn __bf_field_mask(name: &::core::primitive::str) -> #prim {
+                ::modular_bitfield::private::layout::field_mask(Self::__BF_LAYOUT, name) as #prim
+            }
+        )
+    }
+
+    /// Returns the number of bytes of the bitfield if it is known without evaluating the bits of
+    /// the specifiers of its fields.
+    ///
+    /// This is the case with a `bits = N` parameter or if all fields are primitive specifiers
+    /// or annotated with `#[bits = N]` and not `#[cfg(..)]` dependent.
+    fn static_bitfield_bytes(&self, config: &Config) -> Option<usize> {
+        let bits = match &config.bits {
+            Some(bits) => bits.value,
+            None => {
+                self.field_infos(config)
+                    .map(|info| {
+                        if info.cfg_attrs().next().is_some() {
+                            return None
+                        }
+                        match &info.config.bits {
+                            Some(bits) => Some(bits.value),
+                            None => info.primitive_bits(),
+                        }
+                    })
+                    .sum::<Option<usize>>()?
+            }
+        };
+        Some(bits.div_ceil(8))
+    }
+
+    /// Generates `from_bytes_args` taking every byte of a small filled bitfield as an argument
+    /// as well as `as_bytes_tuple` returning every byte of a small bitfield as a tuple element.
+    fn generate_from_bytes_args_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let bytes = self.static_bitfield_bytes(config).filter(|bytes| (1..=8).contains(bytes))?;
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let args = (0..bytes)
+            .map(|index| format_ident!("b{}", index, span = span))
+            .collect::<Vec<_>>();
+        let indices = 0..bytes;
+        let tys = args.iter().map(|_| quote_spanned!(span=> ::core::primitive::u8));
+        let from_bytes_args = config.filled_enabled().then(|| {
+            quote_spanned!(span=>
+                /// Converts the given bytes, starting with the least significant byte, into the
+                /// bitfield struct.
+                ///
+                /// This is equivalent to `from_le_bytes` with the bytes as separate arguments.
+                #[inline]
+                #[allow(clippy::too_many_arguments)]
+                pub const fn from_bytes_args(#( #args: ::core::primitive::u8 ),*) -> Self {
+                    Self::from_le_bytes([#( #args ),*])
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #from_bytes_args
+
+                /// Returns the bytes of the bitfield struct as a tuple, starting with the least
+                /// significant byte.
+                ///
+                /// This allows destructuring the bytes, e.g. `let (lo, hi) = x.as_bytes_tuple();`.
+                #[inline]
+                pub const fn as_bytes_tuple(&self) -> ( #( #tys, )* ) {
+                    ( #( self.#storage[#indices], )* )
+                }
+            }
+        ))
+    }
+
     /// Generates routines to allow conversion from and to bytes for the `#[bitfield]` struct.
     fn expand_byte_conversion_impls(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
@@ -226,9 +1012,16 @@ impl BitfieldStruct {
                 quote_spanned!(span=>
                     /// Converts the given bytes directly into the bitfield struct.
                     #[inline(always)]
-                    #[allow(clippy::identity_op)]
+                    #[allow(clippy::identity_op, clippy::redundant_field_names)]
                     pub const fn from_le_bytes(bytes: [u8; #next_divisible_by_8 / 8usize]) -> Self {
-                        Self { bytes }
+                        Self { #storage: bytes }
+                    }
+
+                    /// Loads the given bytes into the bitfield struct in place.
+                    #[inline(always)]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_le_bytes(&mut self, bytes: [u8; #next_divisible_by_8 / 8usize]) {
+                        *self = Self::from_le_bytes(bytes);
                     }
                 )
             }
@@ -240,20 +1033,75 @@ impl BitfieldStruct {
                     ///
                     /// If the given bytes contain bits at positions that are undefined for `Self`.
                     #[inline]
-                    #[allow(clippy::identity_op)]
+                    #[allow(clippy::identity_op, clippy::redundant_field_names)]
                     pub fn from_le_bytes(
                         bytes: [u8; #next_divisible_by_8 / 8usize]
                     ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
                         if bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size))) {
                             return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
                         }
-                        ::core::result::Result::Ok(Self { bytes })
+                        ::core::result::Result::Ok(Self { #storage: bytes })
+                    }
+
+                    /// Loads the given bytes into the bitfield struct in place.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// In this case `self` remains unchanged.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_le_bytes(
+                        &mut self,
+                        bytes: [u8; #next_divisible_by_8 / 8usize]
+                    ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                        *self = Self::from_le_bytes(bytes)?;
+                        ::core::result::Result::Ok(())
                     }
                 )
             }
         };
+        let mask_undefined_bits = (!config.filled_enabled()).then(|| {
+            quote_spanned!(span=>
+                bytes[len - 1] &= 0xFF_u8 >> (#next_divisible_by_8 - #size);
+            )
+        });
+        let swap_bytes = quote_spanned!(span=>
+            /// Returns the bitfield with the order of its underlying bytes reversed.
+            ///
+            /// For unfilled bitfields the undefined bits of the most significant byte
+            /// are cleared after the reversal.
+            #[inline]
+            #[must_use]
+            #[allow(clippy::identity_op, clippy::redundant_field_names)]
+            pub const fn swap_bytes(self) -> Self {
+                let mut bytes = self.#storage;
+                let len = bytes.len();
+                let mut i = 0;
+                while i < len / 2 {
+                    let tmp = bytes[i];
+                    bytes[i] = bytes[len - 1 - i];
+                    bytes[len - 1 - i] = tmp;
+                    i += 1;
+                }
+                #mask_undefined_bits
+                Self { #storage: bytes }
+            }
+        );
         quote_spanned!(span=>
             impl #ident {
+                /// The number of bytes of the underlying byte representation.
+                #[allow(clippy::identity_op)]
+                pub const BYTES: usize = #next_divisible_by_8 / 8usize;
+
+                /// Returns the number of bytes of the underlying byte representation.
+                ///
+                /// Useful to size external buffers, e.g. `[0u8; Self::bytes_len()]`.
+                #[inline(always)]
+                pub const fn bytes_len() -> usize {
+                    Self::BYTES
+                }
+
                 /// Returns the underlying bits.
                 ///
                 /// # Layout
@@ -262,43 +1110,1867 @@ impl BitfieldStruct {
                 /// The returned byte array is laid out in the same way as described
                 /// [here](https://docs.rs/modular-bitfield/#generated-structure).
                 #[inline(always)]
-                #[allow(clippy::identity_op)]
+                #[allow(clippy::identity_op, clippy::wrong_self_convention)]
                 pub const fn to_le_bytes(self) -> [u8; #next_divisible_by_8 / 8usize] {
-                    self.bytes
+                    self.#storage
+                }
+
+                /// Writes the underlying bits into the given uninitialized buffer.
+                ///
+                /// Afterwards all bytes of `out` are initialized and laid out like the bytes
+                /// returned by `to_le_bytes`, without the need to zero `out` beforehand.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn write_le_into(
+                    &self,
+                    out: &mut [::core::mem::MaybeUninit<::core::primitive::u8>; #next_divisible_by_8 / 8usize],
+                ) {
+                    let mut i = 0;
+                    while i < out.len() {
+                        out[i] = ::core::mem::MaybeUninit::new(self.#storage[i]);
+                        i += 1;
+                    }
                 }
 
+                #swap_bytes
+
                 #from_bytes
             }
         )
     }
 
-    fn generate_byte_update_impls(&self, config: &Config) -> TokenStream2 {
+    /// Generates `to_u32_words` and `from_u32_words` splitting the underlying bytes
+    /// into little endian `u32` words, e.g. for 32-bit wide MMIO accesses.
+    fn generate_word_conversion_impls(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
-        quote_spanned!(span=>
-            impl #ident {
-                /// Updates the underlying byte.
-                ///
-                /// # Layout
-                ///
+        let words = quote_spanned!(span=>
+            ::core::primitive::usize::div_ceil(#next_divisible_by_8 / 8usize, 4usize)
+        );
+        let from_words = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    /// Converts the given little endian `u32` words into the bitfield struct.
+                    ///
+                    /// The padding bytes of the last word are ignored.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_u32_words(words: [::core::primitive::u32; #words]) -> Self {
+                        Self::from_le_bytes(Self::__bf_words_to_bytes(words))
+                    }
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    /// Converts the given little endian `u32` words into the bitfield struct.
+                    ///
+                    /// The padding bytes of the last word are ignored.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given words contain bits at positions that are undefined for `Self`.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_u32_words(
+                        words: [::core::primitive::u32; #words],
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        Self::from_le_bytes(Self::__bf_words_to_bytes(words))
+                    }
+                )
+            }
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                /// Returns the underlying bytes as little endian `u32` words.
+                ///
+                /// If the number of bytes is not divisible by 4 the last word is padded with zeros.
+                #[inline]
+                #[allow(clippy::identity_op, clippy::wrong_self_convention)]
+                pub const fn to_u32_words(self) -> [::core::primitive::u32; #words] {
+                    let bytes = self.#storage;
+                    let mut words = [0u32; #words];
+                    let mut i = 0;
+                    while i < bytes.len() {
+                        words[i / 4] |= (bytes[i] as ::core::primitive::u32) << (8 * (i % 4));
+                        i += 1;
+                    }
+                    words
+                }
+
+                #[inline]
+                #[allow(clippy::identity_op)]
+                const fn __bf_words_to_bytes(
+                    words: [::core::primitive::u32; #words],
+                ) -> [::core::primitive::u8; #next_divisible_by_8 / 8usize] {
+                    let mut bytes = [0u8; #next_divisible_by_8 / 8usize];
+                    let mut i = 0;
+                    while i < bytes.len() {
+                        bytes[i] = (words[i / 4] >> (8 * (i % 4))) as ::core::primitive::u8;
+                        i += 1;
+                    }
+                    bytes
+                }
+
+                #from_words
+            }
+        )
+    }
+
+    /// Generates `from_le_bytes_at` which extracts the bitfield from a buffer starting
+    /// at an arbitrary, not necessarily byte aligned, bit offset.
+    fn generate_from_bytes_at_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        quote_spanned!(span=>
+            impl #ident {
+                /// Extracts the bitfield from the given little endian buffer starting at `bit_offset`.
+                ///
+                /// The bit offset does not need to be a multiple of 8, bits are counted from the
+                /// least significant bit of the first byte of `buf`.
+                ///
+                /// # Errors
+                ///
+                /// If `buf` does not contain enough bits after `bit_offset` for `Self`.
+                #[inline]
+                #[allow(clippy::identity_op, clippy::redundant_field_names)]
+                pub fn from_le_bytes_at(
+                    buf: &[::core::primitive::u8],
+                    bit_offset: ::core::primitive::usize,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    let __bf_bits: ::core::primitive::usize = #size;
+                    let __bf_end = bit_offset
+                        .checked_add(__bf_bits)
+                        .ok_or(::modular_bitfield::error::OutOfBounds)?;
+                    if __bf_end > buf.len().saturating_mul(8) {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let __bf_start = bit_offset / 8;
+                    let __bf_shift = bit_offset % 8;
+                    let mut bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    let mut i = 0;
+                    while i < bytes.len() {
+                        let __bf_lo = buf[__bf_start + i] >> __bf_shift;
+                        let __bf_hi = match (__bf_shift, buf.get(__bf_start + i + 1)) {
+                            (0, _) | (_, ::core::option::Option::None) => 0x00_u8,
+                            (_, ::core::option::Option::Some(__bf_next)) => __bf_next << (8 - __bf_shift),
+                        };
+                        bytes[i] = __bf_lo | __bf_hi;
+                        i += 1;
+                    }
+                    if let ::core::option::Option::Some(__bf_last) = bytes.last_mut() {
+                        *__bf_last &= 0xFF_u8 >> (#next_divisible_by_8 - #size);
+                    }
+                    ::core::result::Result::Ok(Self { #storage: bytes })
+                }
+            }
+        )
+    }
+
+    /// Generates `parse_le` which decodes the bitfield from the start of a buffer and returns
+    /// it together with the unconsumed rest of the buffer, as well as the `TryFrom<&[u8]>` impl
+    /// which requires the buffer to hold exactly the bytes of the bitfield.
+    fn generate_parse_le_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let from_bytes = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self::from_le_bytes(__bf_bytes)),
+            false => quote_spanned!(span=> Self::from_le_bytes(__bf_bytes)?),
+        };
+        quote_spanned!(span=>
+            impl #ident {
+                /// Decodes the bitfield from the start of the given little endian buffer and
+                /// returns it together with the remaining unconsumed bytes.
+                ///
+                /// # Errors
+                ///
+                /// If `buf` is shorter than `Self::BYTES` or its leading bytes contain bits at
+                /// positions that are undefined for `Self`.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn parse_le(
+                    buf: &[::core::primitive::u8],
+                ) -> ::core::result::Result<(Self, &[::core::primitive::u8]), ::modular_bitfield::error::OutOfBounds> {
+                    if buf.len() < Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let (__bf_head, __bf_rest) = buf.split_at(Self::BYTES);
+                    let mut __bf_bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    __bf_bytes.copy_from_slice(__bf_head);
+                    ::core::result::Result::Ok((#from_bytes, __bf_rest))
+                }
+            }
+
+            impl ::core::convert::TryFrom<&[::core::primitive::u8]> for #ident {
+                type Error = ::modular_bitfield::error::OutOfBounds;
+
+                /// Decodes the bitfield from the given buffer of exactly `Self::BYTES` bytes.
+                /// See `parse_le` for decoding the bitfield from the start of a longer buffer.
+                ///
+                /// # Errors
+                ///
+                /// If the length of `buf` differs from `Self::BYTES` or `buf` contains bits at
+                /// positions that are undefined for `Self`.
+                #[inline]
+                fn try_from(buf: &[::core::primitive::u8]) -> ::core::result::Result<Self, Self::Error> {
+                    if buf.len() != Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    Self::parse_le(buf).map(|(__bf_value, _)| __bf_value)
+                }
+            }
+        )
+    }
+
+    /// Generates `try_set_many` applying a batch of fallible writes transactionally.
+    ///
+    /// The writes are applied to a copy of the bytes before committing it to `self`.
+    fn generate_try_set_many_method(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        quote_spanned!(span=>
+            impl #ident {
+                /// Applies the given batch of writes to a copy of the bitfield and commits the
+                /// copy only if all of them succeed.
+                ///
+                /// Use the `set_f_checked` setters within `updates` and propagate their errors
+                /// via `?`.
+                ///
+                /// # Errors
+                ///
+                /// If `updates` fails in which case `self` is left unchanged.
+                #[inline]
+                pub fn try_set_many<F>(
+                    &mut self,
+                    updates: F,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds>
+                where
+                    F: ::core::ops::FnOnce(&mut Self) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds>,
+                {
+                    let mut __bf_copy = Self { #storage: self.#storage };
+                    updates(&mut __bf_copy)?;
+                    *self = __bf_copy;
+                    ::core::result::Result::Ok(())
+                }
+            }
+        )
+    }
+
+    /// Generates the big endian family of byte conversions for the `endian = both` parameter.
+    ///
+    /// The big endian bytes are the reverse of the little endian bytes of the bitfield.
+    /// Also generates `try_update_byte_le` as the counterpart of `try_update_byte_be`.
+    fn generate_be_bytes_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.endian.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let from_be_bytes = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    /// Converts the given big endian bytes into the bitfield struct.
+                    ///
+                    /// This is the reverse byte order of `from_le_bytes`.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_be_bytes(mut bytes: [u8; #next_divisible_by_8 / 8usize]) -> Self {
+                        bytes.reverse();
+                        Self::from_le_bytes(bytes)
+                    }
+
+                    /// Loads the given big endian bytes into the bitfield struct in place.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_be_bytes(&mut self, bytes: [u8; #next_divisible_by_8 / 8usize]) {
+                        *self = Self::from_be_bytes(bytes);
+                    }
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    /// Converts the given big endian bytes into the bitfield struct.
+                    ///
+                    /// This is the reverse byte order of `from_le_bytes`.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// These are the most significant bits and thus reside in the leading byte.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_be_bytes(
+                        mut bytes: [u8; #next_divisible_by_8 / 8usize],
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        bytes.reverse();
+                        Self::from_le_bytes(bytes)
+                    }
+
+                    /// Loads the given big endian bytes into the bitfield struct in place.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// In this case `self` remains unchanged.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_be_bytes(
+                        &mut self,
+                        bytes: [u8; #next_divisible_by_8 / 8usize],
+                    ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                        *self = Self::from_be_bytes(bytes)?;
+                        ::core::result::Result::Ok(())
+                    }
+                )
+            }
+        };
+        let from_head = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self::from_be_bytes(__bf_bytes)),
+            false => quote_spanned!(span=> Self::from_be_bytes(__bf_bytes)?),
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #from_be_bytes
+
+                /// Returns the underlying bits in big endian byte order.
+                ///
+                /// This is the reverse of the array returned by `to_le_bytes`.
+                #[inline]
+                #[allow(clippy::identity_op, clippy::wrong_self_convention)]
+                pub fn to_be_bytes(self) -> [u8; #next_divisible_by_8 / 8usize] {
+                    let mut bytes = self.to_le_bytes();
+                    bytes.reverse();
+                    bytes
+                }
+
+                /// Decodes the bitfield from the big endian bytes at the start of the given buffer
+                /// and returns it together with the remaining unconsumed bytes.
+                ///
+                /// # Errors
+                ///
+                /// If `buf` is shorter than `Self::BYTES` or its leading bytes contain bits at
+                /// positions that are undefined for `Self`.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn parse_be(
+                    buf: &[::core::primitive::u8],
+                ) -> ::core::result::Result<(Self, &[::core::primitive::u8]), ::modular_bitfield::error::OutOfBounds> {
+                    if buf.len() < Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let (__bf_head, __bf_rest) = buf.split_at(Self::BYTES);
+                    let mut __bf_bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    __bf_bytes.copy_from_slice(__bf_head);
+                    ::core::result::Result::Ok((#from_head, __bf_rest))
+                }
+
+                /// Updates the underlying byte if `byte` is in bounds.
+                ///
+                /// # Layout
+                ///
+                /// This is based on Little Endian indexing, aka, least significant byte is at index 0.
+                ///
+                /// # Errors
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
+                #[inline]
+                pub fn try_update_byte_le(
+                    &mut self,
+                    byte: usize,
+                    value: u8,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    if byte >= Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    self.update_byte_le(byte, value);
+                    ::core::result::Result::Ok(())
+                }
+            }
+        ))
+    }
+
+    /// Generates `from_bits_iter` which builds the bitfield from a sequence of bits together
+    /// with `from_bits` and `to_bits` converting from and to an array of all bits as well as
+    /// the `From` impl for the array delegating to the latter.
+    fn generate_from_bits_iter_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        quote_spanned!(span=>
+            impl #ident {
+                /// Builds the bitfield from the given bits, least significant bit first.
+                ///
+                /// Bits beyond the width of `Self` are ignored and missing bits are zero.
+                #[inline]
+                #[allow(clippy::identity_op, clippy::redundant_field_names)]
+                pub fn from_bits_iter<I>(bits: I) -> Self
+                where
+                    I: ::core::iter::IntoIterator<Item = ::core::primitive::bool>,
+                {
+                    let __bf_len: ::core::primitive::usize = #size;
+                    let mut bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    let __bf_bits = ::core::iter::Iterator::take(
+                        ::core::iter::IntoIterator::into_iter(bits),
+                        __bf_len,
+                    );
+                    for (i, __bf_bit) in ::core::iter::Iterator::enumerate(__bf_bits) {
+                        bytes[i / 8] |= (__bf_bit as ::core::primitive::u8) << (i % 8);
+                    }
+                    Self { #storage: bytes }
+                }
+
+                /// Builds the bitfield from the given array of all its bits, least significant bit first.
+                #[inline]
+                #[allow(clippy::identity_op, unused_braces)]
+                pub fn from_bits(bits: [::core::primitive::bool; #size]) -> Self {
+                    Self::from_bits_iter(bits)
+                }
+
+                /// Returns all bits of the bitfield as an array, least significant bit first.
+                #[inline]
+                #[allow(clippy::identity_op, unused_braces)]
+                pub fn to_bits(&self) -> [::core::primitive::bool; #size] {
+                    let mut bits = [false; #size];
+                    for (i, __bf_bit) in ::core::iter::Iterator::enumerate(bits.iter_mut()) {
+                        *__bf_bit = self.#storage[i / 8] & (0x01 << (i % 8)) != 0;
+                    }
+                    bits
+                }
+            }
+
+            #[allow(clippy::identity_op, unused_braces)]
+            impl ::core::convert::From<#ident> for [::core::primitive::bool; #size] {
+                /// Returns all bits of the bitfield as an array, least significant bit first.
+                #[inline]
+                fn from(bitfield: #ident) -> Self {
+                    bitfield.to_bits()
+                }
+            }
+        )
+    }
+
+    /// Generates `all_combinations` enumerating every valid bitfield if the `enumerate`
+    /// parameter is set.
+    ///
+    /// A combination is valid if all fields with getters contain a valid bit pattern.
+    /// Bitfields wider than 16 bits are rejected at compile time.
+    fn generate_enumerate_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.enumerate.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let check_fields = self
+            .field_infos(config)
+            .filter(|info| !info.config.skip_getters())
+            .map(|info| {
+                let cfg_attrs = info.cfg_attrs();
+                let field_ident = info.ident_frag();
+                let field_getter = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .map(|_| format_ident!("{}_or_err", field_ident))
+                    .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    if __bf_result.#field_getter().is_err() {
+                        return ::core::option::Option::None
+                    }
+                )
+            });
+        let error_msg = format!(
+            "bitfield {} must not exceed 16 bits for the `enumerate` parameter",
+            ident
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            const _: () = ::core::assert!(#size <= 16, #error_msg);
+
+            #allow_deprecated
+            impl #ident {
+                /// Returns an iterator over every valid combination of field values.
+                ///
+                /// Combinations are yielded in ascending order of their underlying bits.
+                #[allow(clippy::identity_op, clippy::redundant_field_names)]
+                pub fn all_combinations() -> impl ::core::iter::Iterator<Item = Self> {
+                    let __bf_bits = (#size) as ::core::primitive::u32;
+                    ::core::iter::Iterator::filter_map(0..(1_u32 << __bf_bits), |__bf_raw| {
+                        let mut bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                        let __bf_len = bytes.len();
+                        bytes.copy_from_slice(&__bf_raw.to_le_bytes()[..__bf_len]);
+                        let __bf_result = Self { #storage: bytes };
+                        #( #check_fields )*
+                        ::core::option::Option::Some(__bf_result)
+                    })
+                }
+            }
+        ))
+    }
+
+    /// Generates `extend_le_into` if the `heapless` crate feature is enabled.
+    fn generate_heapless_impl(&self, config: &Config) -> Option<TokenStream2> {
+        if !cfg!(feature = "heapless") {
+            return None
+        }
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Appends the bytes returned by `to_le_bytes` to the given fixed-capacity vector.
+                ///
+                /// # Errors
+                ///
+                /// If `out` has not enough spare capacity for all bytes in which case it is left
+                /// unchanged.
+                #[inline]
+                #[allow(clippy::result_unit_err)]
+                pub fn extend_le_into<const __BF_CAP: ::core::primitive::usize>(
+                    &self,
+                    out: &mut ::modular_bitfield::private::HeaplessVec<::core::primitive::u8, __BF_CAP>,
+                ) -> ::core::result::Result<(), ()> {
+                    out.extend_from_slice(&self.#storage[..])
+                }
+            }
+        ))
+    }
+
+    /// Generates `try_from_le_bytes_all` if the `alloc` crate feature is enabled.
+    ///
+    /// Unlike the fail-fast getters it collects an error for every field of the given bytes
+    /// that contains an invalid bit pattern. The fields are read from the bytes directly so that
+    /// this does not depend on the receivers of the generated getters.
+    fn generate_try_from_bytes_all_impl(&self, config: &Config) -> Option<TokenStream2> {
+        if !cfg!(feature = "alloc") {
+            return None
+        }
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let check_undefined_bits = (!config.filled_enabled()).then(|| {
+            quote_spanned!(span=>
+                if bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size))) {
+                    __bf_errors.push(::modular_bitfield::error::FieldError::UndefinedBits);
+                }
+            )
+        });
+        let check_fields = self
+            .field_ranges(config)
+            .filter(|(info, _, _)| !info.config.skip_getters())
+            .map(|(info, start, _)| {
+                let cfg_attrs = info.cfg_attrs();
+                let name = info.name();
+                let ty = &info.field.ty;
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    if <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                        ::modular_bitfield::private::read_specifier::<#ty>(&bytes[..], #start),
+                    )
+                    .is_err()
+                    {
+                        __bf_errors.push(::modular_bitfield::error::FieldError::InvalidBitPattern(#name));
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl #ident {
+                /// Converts the given bytes into the bitfield struct, validating all of its fields.
+                ///
+                /// # Errors
+                ///
+                /// Returns an error for every field that contains an invalid bit pattern
+                /// as well as for bits set at positions that are undefined for `Self`.
+                #[allow(clippy::identity_op, clippy::redundant_field_names)]
+                pub fn try_from_le_bytes_all(
+                    bytes: [u8; #next_divisible_by_8 / 8usize],
+                ) -> ::core::result::Result<Self, ::modular_bitfield::private::Vec<::modular_bitfield::error::FieldError>> {
+                    let mut __bf_errors = ::modular_bitfield::private::Vec::new();
+                    #check_undefined_bits
+                    #( #check_fields )*
+                    if __bf_errors.is_empty() {
+                        ::core::result::Result::Ok(Self { #storage: bytes })
+                    } else {
+                        ::core::result::Result::Err(__bf_errors)
+                    }
+                }
+            }
+        ))
+    }
+
+    /// Generates `payload_len` and `split_at` for a bitfield with a `#[payload_len]` field
+    /// describing the length in bytes of a variable payload following the bitfield.
+    fn generate_payload_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let (ty, start) = self.field_ranges(config).find_map(|(info, start, _)| {
+            info.config.payload_len.as_ref()?;
+            Some((info.field.ty.clone(), start))
+        })?;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let header = match config.filled_enabled() {
+            true => quote_spanned!(span=> Self::from_le_bytes(__bf_bytes)),
+            false => {
+                quote_spanned!(span=>
+                    ::core::result::Result::ok(Self::from_le_bytes(__bf_bytes))?
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns the length in bytes of the payload following the bitfield as stored
+                /// in its `#[payload_len]` field.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn payload_len(&self) -> ::core::primitive::usize {
+                    ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #start)
+                        as ::core::primitive::usize
+                }
+
+                /// Splits the given buffer into the bitfield at its start, the payload following
+                /// it and the remaining bytes after the payload.
+                ///
+                /// Returns `None` if the buffer is too short for the bitfield or its payload.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn split_at(
+                    buffer: &[::core::primitive::u8],
+                ) -> ::core::option::Option<(Self, &[::core::primitive::u8], &[::core::primitive::u8])> {
+                    if buffer.len() < Self::BYTES {
+                        return ::core::option::Option::None
+                    }
+                    let (__bf_header, __bf_rest) = buffer.split_at(Self::BYTES);
+                    let mut __bf_bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    __bf_bytes.copy_from_slice(__bf_header);
+                    let __bf_header = #header;
+                    let __bf_len = __bf_header.payload_len();
+                    if __bf_rest.len() < __bf_len {
+                        return ::core::option::Option::None
+                    }
+                    let (__bf_payload, __bf_rest) = __bf_rest.split_at(__bf_len);
+                    ::core::option::Option::Some((__bf_header, __bf_payload, __bf_rest))
+                }
+            }
+        ))
+    }
+
+    /// Generates the standalone wrapper types of all `#[extract_as(Name)]` fields together with
+    /// the methods converting the fields of the bitfield from and into them.
+    fn generate_extracted_types(&self, config: &Config) -> Option<TokenStream2> {
+        let extracted = self
+            .field_infos(config)
+            .filter_map(|info| {
+                let name = &info.config.extract_as.as_ref()?.value;
+                Some(self.generate_extracted_type(&info, name))
+            })
+            .collect::<Vec<_>>();
+        if extracted.is_empty() {
+            return None
+        }
+        Some(quote! { #( #extracted )* })
+    }
+
+    /// Generates the standalone wrapper type `name` for the given field.
+    ///
+    /// The wrapper type stores the raw bits of a valid value of the field and itself implements
+    /// `Specifier` so that it can be used as the type of fields of other bitfields.
+    fn generate_extracted_type(&self, info: &FieldInfo<'_>, name: &syn::Ident) -> TokenStream2 {
+        let span = info.field.span();
+        let struct_ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let field_vis = &info.field.vis;
+        let ty = &info.field.ty;
+        let field_ident = info.ident_frag();
+        let field_name = info.name();
+        let get_raw_ident = format_ident!("get_{}_raw", field_ident);
+        let set_raw_ident = format_ident!("set_{}_raw", field_ident);
+        let extract_ident = format_ident!("extract_{}", field_ident);
+        let set_extracted_ident = format_ident!("set_{}_extracted", field_ident);
+        let with_extracted_ident = format_ident!("with_{}_extracted", field_ident);
+        let type_docs = format!(
+            "The standalone type of the field {}.{}.",
+            struct_ident, field_name
+        );
+        let new_assert_msg = format!("value out of bounds for {}", name);
+        let extract_assert_msg = format!(
+            "value contains invalid bit pattern for field {}.{}",
+            struct_ident, field_name
+        );
+        let extract_docs = format!(
+            "Returns the value of {} as its standalone type [`{}`].\n\n\
+             #Panics\n\n\
+             If {} contains an invalid bit pattern.",
+            field_name, name, field_name,
+        );
+        let set_extracted_docs = format!(
+            "Sets the value of {} from its standalone type [`{}`].",
+            field_name, name,
+        );
+        let with_extracted_docs = format!(
+            "Returns a copy of the bitfield with the value of {} set from its standalone type [`{}`].",
+            field_name, name,
+        );
+        let extract = (!info.config.skip_getters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #extract_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #field_vis fn #extract_ident(&self) -> #name {
+                    ::core::result::Result::expect(#name::from_bits(self.#get_raw_ident()), #extract_assert_msg)
+                }
+            )
+        });
+        let set_extracted = (!info.config.skip_setters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #set_extracted_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #field_vis fn #set_extracted_ident(&mut self, value: #name) {
+                    self.#set_raw_ident(value.into_bits())
+                }
+
+                #[doc = #with_extracted_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #field_vis fn #with_extracted_ident(mut self, value: #name) -> Self {
+                    self.#set_extracted_ident(value);
+                    self
+                }
+            )
+        });
+        quote_spanned!(span=>
+            #[doc = #type_docs]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #[repr(transparent)]
+            #vis struct #name {
+                bits: <#ty as ::modular_bitfield::Specifier>::Bytes,
+            }
+
+            impl #name {
+                /// Creates a new instance from the given value.
+                ///
+                /// # Panics
+                ///
+                /// If the given value is out of bounds.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn new(value: <#ty as ::modular_bitfield::Specifier>::InOut) -> Self {
+                    ::core::result::Result::expect(Self::new_checked(value), #new_assert_msg)
+                }
+
+                /// Creates a new instance from the given value.
+                ///
+                /// # Errors
+                ///
+                /// If the given value is out of bounds.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn new_checked(
+                    value: <#ty as ::modular_bitfield::Specifier>::InOut,
+                ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                    ::core::result::Result::Ok(Self {
+                        bits: <#ty as ::modular_bitfield::Specifier>::into_bytes(value)?,
+                    })
+                }
+
+                /// Returns the value.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn get(self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    match <#ty as ::modular_bitfield::Specifier>::from_bytes(self.bits) {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => ::core::unreachable!(),
+                    }
+                }
+
+                /// Creates a new instance from the given raw bits.
+                ///
+                /// # Errors
+                ///
+                /// If the given bits are not a valid bit pattern.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn from_bits(
+                    bits: <#ty as ::modular_bitfield::Specifier>::Bytes,
+                ) -> ::core::result::Result<
+                    Self,
+                    ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>,
+                > {
+                    <#ty as ::modular_bitfield::Specifier>::from_bytes(bits)?;
+                    ::core::result::Result::Ok(Self { bits })
+                }
+
+                /// Returns the raw bits.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn into_bits(self) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                    self.bits
+                }
+            }
+
+            impl ::modular_bitfield::Specifier for #name {
+                const BITS: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
+                type Bytes = <#ty as ::modular_bitfield::Specifier>::Bytes;
+                type InOut = Self;
+
+                #[inline]
+                fn into_bytes(
+                    input: Self::InOut,
+                ) -> ::core::result::Result<Self::Bytes, ::modular_bitfield::error::OutOfBounds> {
+                    ::core::result::Result::Ok(input.bits)
+                }
+
+                #[inline]
+                fn from_bytes(
+                    bytes: Self::Bytes,
+                ) -> ::core::result::Result<Self::InOut, ::modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+                    Self::from_bits(bytes)
+                }
+            }
+
+            impl #struct_ident {
+                #extract
+                #set_extracted
+            }
+        )
+    }
+
+    /// Returns the bit offsets at which the fields covered by the given `#[checksum(..)]`
+    /// start and end.
+    fn checksum_cover(
+        ranges: &[(FieldInfo<'_>, TokenStream2, TokenStream2)],
+        checksum: &ChecksumConfig,
+    ) -> (TokenStream2, TokenStream2) {
+        let range_of = |name: &syn::Ident| {
+            ranges
+                .iter()
+                .find(|(info, _, _)| info.field.ident.as_ref() == Some(name))
+                .map(|(_, start, end)| (start.clone(), end.clone()))
+                .expect("checked to be a field of the struct during analysis")
+        };
+        let (over_start, _) = range_of(&checksum.start);
+        let over_end = match &checksum.end {
+            ChecksumEnd::Exclusive(end) => range_of(end).0,
+            ChecksumEnd::Inclusive(end) => range_of(end).1,
+            ChecksumEnd::Open => {
+                let (_, _, end) = ranges.last().expect("bitfields have at least one field");
+                end.clone()
+            }
+        };
+        (over_start, over_end)
+    }
+
+    /// Generates `validate` checking that all `#[reserved]` fields are zero and that the
+    /// `#[checksum(..)]` field matches, together with `set_checksum` recomputing the latter.
+    fn generate_validate_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let ranges = self.field_ranges(config).collect::<Vec<_>>();
+        let checks = ranges
+            .iter()
+            .filter(|(info, _, _)| info.config.reserved.is_some())
+            .map(|(info, start, end)| {
+                let cfg_attrs = info.cfg_attrs();
+                let name = info.name();
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    if !::modular_bitfield::private::bits_are_zero(&self.#storage[..], #start, #end) {
+                        return ::core::result::Result::Err(::core::convert::From::from(
+                            ::modular_bitfield::error::NonZeroReserved { field: #name },
+                        ))
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        let checksum = ranges.iter().find_map(|(info, start, end)| {
+            let checksum = info.config.checksum.as_ref()?;
+            let (over_start, over_end) = Self::checksum_cover(&ranges, &checksum.value);
+            let width = quote_spanned!(span=> (#end) - (#start));
+            let name = info.name();
+            let error_msg = format!("#[checksum(..)] field {} must not exceed 128 bits", name);
+            let compute = match checksum.value.algo {
+                ChecksumAlgo::Xor => {
+                    quote_spanned!(span=>
+                        ::modular_bitfield::private::xor_checksum(
+                            &self.#storage[..],
+                            #over_start,
+                            #over_end,
+                            #width,
+                        )
+                    )
+                }
+            };
+            let impls = quote_spanned!(span=>
+                const _: () = ::core::assert!(#width <= 128, #error_msg);
+
+                impl #ident {
+                    /// Returns the expected checksum of the bits covered by the `#[checksum(..)]` field.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    fn __bf_expected_checksum(&self) -> ::core::primitive::u128 {
+                        #compute
+                    }
+
+                    /// Returns the checksum currently stored in the `#[checksum(..)]` field.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    fn __bf_found_checksum(&self) -> ::core::primitive::u128 {
+                        ::modular_bitfield::private::xor_checksum(
+                            &self.#storage[..],
+                            #start,
+                            #end,
+                            #width,
+                        )
+                    }
+
+                    /// Recomputes the `#[checksum(..)]` field from the bits it covers.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn set_checksum(&mut self) {
+                        let __bf_checksum = self.__bf_expected_checksum();
+                        self.#storage = ::modular_bitfield::private::with_bits(
+                            self.#storage,
+                            #start,
+                            #end,
+                            __bf_checksum,
+                        );
+                    }
+                }
+            );
+            Some((impls, name))
+        });
+        let magic_checks = ranges
+            .iter()
+            .filter_map(|(info, start, end)| {
+                let magic = info.config.magic.as_ref()?;
+                let cfg_attrs = info.cfg_attrs();
+                let name = info.name();
+                let value = proc_macro2::Literal::u128_suffixed(magic.value);
+                Some(quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    {
+                        let __bf_found = ::modular_bitfield::private::read_bits(&self.#storage[..], #start, #end);
+                        if __bf_found != #value {
+                            return ::core::result::Result::Err(
+                                ::modular_bitfield::error::ValidateError::MagicMismatch {
+                                    field: #name,
+                                    expected: #value,
+                                    found: __bf_found,
+                                },
+                            )
+                        }
+                    }
+                ))
+            })
+            .collect::<Vec<_>>();
+        if checks.is_empty() && checksum.is_none() && magic_checks.is_empty() {
+            return None
+        }
+        let (checksum_impls, name) = checksum.unzip();
+        let (error, check_checksum) = match name {
+            Some(name) => {
+                (
+                    quote_spanned!(span=> ::modular_bitfield::error::ValidateError),
+                    Some(quote_spanned!(span=>
+                        let __bf_expected = self.__bf_expected_checksum();
+                        let __bf_found = self.__bf_found_checksum();
+                        if __bf_expected != __bf_found {
+                            return ::core::result::Result::Err(
+                                ::modular_bitfield::error::ValidateError::ChecksumMismatch {
+                                    field: #name,
+                                    expected: __bf_expected,
+                                    found: __bf_found,
+                                },
+                            )
+                        }
+                    )),
+                )
+            }
+            None if !magic_checks.is_empty() => {
+                (quote_spanned!(span=> ::modular_bitfield::error::ValidateError), None)
+            }
+            None => (quote_spanned!(span=> ::modular_bitfield::error::NonZeroReserved), None),
+        };
+        Some(quote_spanned!(span=>
+            #checksum_impls
+
+            impl #ident {
+                /// Checks that all bits of the `#[reserved]` fields are zero, that the
+                /// `#[magic = N]` fields contain their magic values and that the
+                /// `#[checksum(..)]` field matches the bits it covers.
+                ///
+                /// # Errors
+                ///
+                /// If any `#[reserved]` field has a bit set, reporting the first such field,
+                /// if any `#[magic = N]` field differs from its magic value or if the checksum
+                /// does not match.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub fn validate(&self) -> ::core::result::Result<(), #error> {
+                    #( #checks )*
+                    #( #magic_checks )*
+                    #check_checksum
+                    ::core::result::Result::Ok(())
+                }
+            }
+        ))
+    }
+
+    /// Generates the `TryFrom<InOut>` impl constructing a single-field bitfield from its field value.
+    ///
+    /// Only generated with the `from_field` flag whose preconditions ensure that the bitfield has
+    /// a single primitive specifier field of at most 64 bits and no `#[repr(uN)]`.
+    fn generate_from_field_impl(&self, config: &Config) -> Option<TokenStream2> {
+        config.from_field.as_ref()?;
+        let info = self.field_infos(config).next()?;
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
+        let in_out = info.primitive_in_out()?;
+        let error = match config.rich_errors {
+            Some(_) => quote_spanned!(span=> ::modular_bitfield::error::FieldOutOfBounds),
+            None => quote_spanned!(span=> ::modular_bitfield::error::OutOfBounds),
+        };
+        // With `on_overflow = saturate` out of bounds values are clamped like in the setters.
+        let (errors_docs, body) = match config.on_overflow.as_ref().map(|on_overflow| on_overflow.value) {
+            Some(OverflowKind::Saturate) => {
+                let with_ident = format_ident!("with_{}", info.ident_frag());
+                (
+                    quote_spanned!(span=>
+                        /// # Saturation
+                        ///
+                        /// If the given value is out of bounds for the field it is clamped
+                        /// to the maximum value of the field.
+                    ),
+                    quote_spanned!(span=> ::core::result::Result::Ok(Self::new().#with_ident(__bf_value))),
+                )
+            }
+            None => {
+                let with_checked_ident = format_ident!("with_{}_checked", info.ident_frag());
+                (
+                    quote_spanned!(span=>
+                        /// # Errors
+                        ///
+                        /// If the given value is out of bounds for the field.
+                    ),
+                    quote_spanned!(span=> Self::new().#with_checked_ident(__bf_value)),
+                )
+            }
+        };
+        let docs = format!(
+            "Constructs the bitfield from the value of its single field {}.",
+            info.name(),
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #allow_deprecated
+            impl ::core::convert::TryFrom<#in_out> for #ident {
+                type Error = #error;
+
+                #[doc = #docs]
+                ///
+                #errors_docs
+                #[inline]
+                fn try_from(__bf_value: #in_out) -> ::core::result::Result<Self, #error> {
+                    #body
+                }
+            }
+        ))
+    }
+
+    /// Generates the `TryFrom<u128>` impl which works for bitfields of any width.
+    ///
+    /// Bitfields with a `#[repr(u128)]` already implement it through their `From<u128>` impl.
+    fn generate_try_from_u128_impl(&self, config: &Config) -> Option<TokenStream2> {
+        if let Some(ReprKind::U128) = config.repr.as_ref().map(|repr| repr.value) {
+            return None
+        }
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        // With `on_overflow = saturate` out of bounds values are clamped to all bits set instead.
+        let (docs, out_of_bounds) = match config.on_overflow.as_ref().map(|on_overflow| on_overflow.value) {
+            Some(OverflowKind::Saturate) => (
+                quote_spanned!(span=>
+                    /// # Saturation
+                    ///
+                    /// If the value has bits set beyond the bit width of the bitfield
+                    /// all bits of the bitfield are set.
+                ),
+                quote_spanned!(span=>
+                    let __bf_max_value = !::core::primitive::u128::MAX
+                        .checked_shl((#size) as ::core::primitive::u32)
+                        .unwrap_or(0);
+                    let __bf_value = ::core::cmp::min(__bf_value, __bf_max_value);
+                ),
+            ),
+            None => (
+                quote_spanned!(span=>
+                    /// # Errors
+                    ///
+                    /// If the value has bits set beyond the bit width of the bitfield.
+                ),
+                quote_spanned!(span=>
+                    if __bf_value.checked_shr((#size) as ::core::primitive::u32).unwrap_or(0) != 0 {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                ),
+            ),
+        };
+        Some(quote_spanned!(span=>
+            impl ::core::convert::TryFrom<::core::primitive::u128> for #ident {
+                type Error = ::modular_bitfield::error::OutOfBounds;
+
+                /// Converts the given value into the bitfield struct.
+                ///
+                #docs
+                #[inline]
+                #[allow(clippy::identity_op, clippy::redundant_field_names)]
+                fn try_from(
+                    __bf_value: ::core::primitive::u128
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    #out_of_bounds
+                    let __bf_le_bytes = __bf_value.to_le_bytes();
+                    let mut bytes = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    let __bf_len = ::core::cmp::min(bytes.len(), __bf_le_bytes.len());
+                    bytes[..__bf_len].copy_from_slice(&__bf_le_bytes[..__bf_len]);
+                    ::core::result::Result::Ok(Self { #storage: bytes })
+                }
+            }
+        ))
+    }
+
+    /// Generates the bit-vector view of the bitfield if the `bit_view` parameter is set.
+    ///
+    /// This treats the whole bitfield as a sequence of `bool` values, one per bit.
+    fn generate_bit_view_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.bit_view.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// The number of bits of the bit-vector view.
+                #[allow(clippy::identity_op)]
+                pub const BIT_LEN: usize = #size;
+
+                /// Returns the bit at the given index.
+                ///
+                /// The bit at index 0 is the least significant bit of the first byte.
+                ///
+                /// # Panics
+                ///
+                /// If `index` is out of bounds for the bits of the bitfield.
+                #[inline]
+                pub fn bit(&self, index: usize) -> bool {
+                    ::core::assert!(
+                        index < Self::BIT_LEN,
+                        "bit index out of bounds: the len is {} but the index is {}",
+                        Self::BIT_LEN,
+                        index,
+                    );
+                    self.#storage[index / 8] & (0x01 << (index % 8)) != 0
+                }
+
+                /// Sets the bit at the given index to `value`.
+                ///
+                /// # Panics
+                ///
+                /// If `index` is out of bounds for the bits of the bitfield.
+                #[inline]
+                pub fn set_bit(&mut self, index: usize, value: bool) {
+                    ::core::assert!(
+                        index < Self::BIT_LEN,
+                        "bit index out of bounds: the len is {} but the index is {}",
+                        Self::BIT_LEN,
+                        index,
+                    );
+                    let mask = 0x01 << (index % 8);
+                    if value {
+                        self.#storage[index / 8] |= mask;
+                    } else {
+                        self.#storage[index / 8] &= !mask;
+                    }
+                }
+
+                /// Returns an iterator over all bits, starting with the bit at index 0.
+                #[inline]
+                pub fn iter_bits(&self) -> impl ::core::iter::Iterator<Item = bool> + '_ {
+                    (0..Self::BIT_LEN).map(move |index| self.bit(index))
+                }
+            }
+
+            impl ::core::ops::Index<usize> for #ident {
+                type Output = bool;
+
+                #[inline]
+                fn index(&self, index: usize) -> &bool {
+                    if self.bit(index) {
+                        &true
+                    } else {
+                        &false
+                    }
+                }
+            }
+        ))
+    }
+
+    fn generate_byte_update_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        quote_spanned!(span=>
+            impl #ident {
+                /// Updates the underlying byte.
+                ///
+                /// # Layout
+                ///
                 /// This is based on Little Endian indexing, aka, least significant byte is at index 0.
                 #[inline(always)]
                 #[allow(clippy::identity_op)]
-                pub fn update_byte_le(&mut self, byte: usize, value: u8) {
-                    self.bytes[byte] = value;
+                pub fn update_byte_le(&mut self, byte: usize, value: u8) {
+                    self.#storage[byte] = value;
+                }
+
+                /// Updates the underlying byte.
+                ///
+                /// # Layout
+                ///
+                /// This is based on Big Endian indexing, aka, most significant byte is at index 0.
+                ///
+                /// # Panics
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
+                #[inline(always)]
+                #[allow(clippy::identity_op)]
+                pub fn update_byte_be(&mut self, byte: usize, value: u8) {
+                    ::core::debug_assert!(
+                        byte < Self::BYTES,
+                        "big endian byte index out of bounds: the len is {} but the index is {}",
+                        Self::BYTES,
+                        byte,
+                    );
+                    self.#storage[Self::BYTES - byte - 1] = value;
+                }
+
+                /// Updates the underlying byte if `byte` is in bounds.
+                ///
+                /// # Layout
+                ///
+                /// This is based on Big Endian indexing, aka, most significant byte is at index 0.
+                ///
+                /// # Errors
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
+                #[inline]
+                pub fn try_update_byte_be(
+                    &mut self,
+                    byte: usize,
+                    value: u8,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    if byte >= Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    self.update_byte_be(byte, value);
+                    ::core::result::Result::Ok(())
+                }
+            }
+        )
+    }
+
+    /// Generates a `#[repr(C)]` struct for every `view(Name = start..end)` parameter together
+    /// with the getter and setter extracting and writing back its byte range.
+    fn generate_view_impls(&self, config: &Config) -> Option<TokenStream2> {
+        if config.views.is_empty() {
+            return None
+        }
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let views = config.views.iter().map(|view| {
+            let span = view.span;
+            let name = &view.value.name;
+            let start = view.value.start;
+            let end = view.value.end;
+            let len = end - start;
+            let getter = format_ident!("{}", snake_case(&name.to_string()), span = name.span());
+            let setter = format_ident!("set_{}", getter);
+            let getter_docs = format!("Returns the bytes `{}..{}` as [`{}`] view.", start, end, name);
+            let setter_docs = format!("Writes the given [`{}`] view back to the bytes `{}..{}`.", name, start, end);
+            let error_msg = format!("view `{}` is out of bounds for bitfield `{}`", name, ident);
+            quote_spanned!(span=>
+                /// A `#[repr(C)]` view of a byte range of a `#[bitfield]` struct.
+                #[repr(C)]
+                #[derive(::core::fmt::Debug, ::core::marker::Copy, ::core::clone::Clone, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+                #vis struct #name {
+                    bytes: [::core::primitive::u8; #len],
+                }
+
+                impl #name {
+                    /// The number of bytes covered by the view.
+                    pub const BYTES: ::core::primitive::usize = #len;
+
+                    /// Creates the view from its little endian bytes.
+                    #[inline]
+                    pub const fn from_le_bytes(bytes: [::core::primitive::u8; #len]) -> Self {
+                        Self { bytes }
+                    }
+
+                    /// Returns the little endian bytes of the view.
+                    #[inline]
+                    pub const fn to_le_bytes(self) -> [::core::primitive::u8; #len] {
+                        self.bytes
+                    }
+                }
+
+                const _: () = ::core::assert!(#end <= #next_divisible_by_8 / 8usize, #error_msg);
+
+                impl #ident {
+                    #[doc = #getter_docs]
+                    #[inline]
+                    pub fn #getter(&self) -> #name {
+                        let mut bytes = [0x00_u8; #len];
+                        bytes.copy_from_slice(&self.#storage[#start..#end]);
+                        #name { bytes }
+                    }
+
+                    #[doc = #setter_docs]
+                    #[inline]
+                    pub fn #setter(&mut self, view: #name) {
+                        self.#storage[#start..#end].copy_from_slice(&view.bytes);
+                        if let ::core::option::Option::Some(__bf_last) = self.#storage.last_mut() {
+                            *__bf_last &= 0xFF_u8 >> (#next_divisible_by_8 - #size);
+                        }
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #( #views )*
+        ))
+    }
+
+    /// Generates the `IdentView<'a>` wrapper around borrowed bytes for the `borrowed_view`
+    /// parameter.
+    ///
+    /// The view has read-only getters for every field with getters that read the field
+    /// directly from the borrowed bytes without copying them into a bitfield.
+    fn generate_borrowed_view(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.borrowed_view.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let vis = &self.item_struct.vis;
+        let view_ident = format_ident!("{}View", ident);
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let getters = self
+            .field_ranges(config)
+            .filter(|(info, _, _)| !info.config.skip_getters())
+            .map(|(info, start, _)| {
+                let field_span = info.field.span();
+                let ty = &info.field.ty;
+                let vis = &info.field.vis;
+                let name = info.name();
+                let retained_attrs = &info.config.retained_attrs;
+                let field_ident = info.ident_frag();
+                let get_ident = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .cloned()
+                    .unwrap_or_else(|| format_ident!("get_{}", field_ident));
+                let get_checked_ident = info
+                    .field
+                    .ident
+                    .as_ref()
+                    .map(|_| format_ident!("{}_or_err", field_ident))
+                    .unwrap_or_else(|| format_ident!("get_{}_or_err", field_ident));
+                let get_assert_msg = format!(
+                    "value contains invalid bit pattern for field {}.{}",
+                    ident, name
+                );
+                let getter_docs = format!("Returns the value of {} from the borrowed bytes.", name);
+                let checked_getter_docs = format!(
+                    "Returns the value of {} from the borrowed bytes.\n\n\
+                     #Errors\n\n\
+                     If the returned value contains an invalid bit pattern for {}.",
+                    name, name,
+                );
+                quote_spanned!(field_span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.#get_checked_ident().expect(#get_assert_msg)
+                    }
+
+                    #[doc = #checked_getter_docs]
+                    #[inline]
+                    #[allow(dead_code, clippy::identity_op)]
+                    #( #retained_attrs )*
+                    #vis fn #get_checked_ident(
+                        &self,
+                    ) -> ::core::result::Result<
+                        <#ty as ::modular_bitfield::Specifier>::InOut,
+                        ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
+                    > {
+                        <#ty as ::modular_bitfield::Specifier>::from_bytes(
+                            ::modular_bitfield::private::read_specifier::<#ty>(&self.0[..], #start)
+                        )
+                    }
+                )
+            })
+            .collect::<Vec<_>>();
+        let view_docs = format!(
+            "A read-only view of [`{}`] over borrowed bytes.\n\n\
+             The getters read the fields directly from the borrowed bytes without copying them.",
+            ident,
+        );
+        let allow_deprecated = self.generate_allow_deprecated(config);
+        Some(quote_spanned!(span=>
+            #[doc = #view_docs]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::marker::Copy)]
+            #[allow(clippy::identity_op)]
+            #vis struct #view_ident<'__bf>(pub &'__bf [::core::primitive::u8; #next_divisible_by_8 / 8usize]);
+
+            #allow_deprecated
+            #[allow(non_snake_case)]
+            impl<'__bf> #view_ident<'__bf> {
+                #( #getters )*
+            }
+        ))
+    }
+
+    /// Generates a check asserting that the bitfield is `Copy` if the `by_value_getters`
+    /// parameter is set since its getters take `self` by value.
+    fn generate_by_value_getters_check(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.by_value_getters.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        Some(quote_spanned!(span=>
+            const _: () = {
+                fn __bf_assert_copy<T: ::core::marker::Copy>() {}
+                fn __bf_assert_bitfield_copy() {
+                    __bf_assert_copy::<#ident>();
+                }
+            };
+        ))
+    }
+
+    /// Generates the `Borrow<[u8]>` implementation over the underlying bytes.
+    ///
+    /// This is skipped whenever the generated `Ord`, `PartialEq` or `Hash` implementations do not
+    /// agree with the ones of the borrowed bytes, i.e. for `ord = fields`, for `#[eq(ignore)]`
+    /// fields and for unfilled bitfields deriving `Hash` whose padding bits are masked out.
+    fn generate_borrow_impl(&self, config: &Config) -> Option<TokenStream2> {
+        let eq_ignore = self
+            .field_infos(config)
+            .any(|info| info.config.eq_ignore.is_some());
+        let masked_hash = config.derive_hash.is_some() && !config.filled_enabled();
+        if config.ord.is_some() || eq_ignore || masked_hash {
+            return None
+        }
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        Some(quote_spanned!(span=>
+            impl ::core::borrow::Borrow<[::core::primitive::u8]> for #ident {
+                #[inline]
+                fn borrow(&self) -> &[::core::primitive::u8] {
+                    &self.#storage[..]
+                }
+            }
+        ))
+    }
+
+    /// Generates `get_bits`, `bits_in`, `set_bits` and `set_bits_range` to read and write an
+    /// arbitrary range of bits.
+    fn generate_bits_range_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.bit_view.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let repr_type = quote_spanned!(span=>
+            <[(); if #ident::BIT_LEN == 0 {
+                1
+            } else if #ident::BIT_LEN > 128 {
+                128
+            } else {
+                #ident::BIT_LEN
+            }] as ::modular_bitfield::private::SpecifierBytes>::Bytes
+        );
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Returns the `len` bits starting at bit `lo` as the lowest bits of the result.
+                ///
+                /// The bit at index 0 is the least significant bit of the first byte.
+                ///
+                /// # Panics
+                ///
+                /// - If the bits are out of bounds for the bits of the bitfield.
+                /// - If `len` exceeds 128 bits.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn get_bits(
+                    &self,
+                    lo: ::core::primitive::usize,
+                    len: ::core::primitive::usize,
+                ) -> ::core::primitive::u128 {
+                    ::core::assert!(
+                        lo <= #size && len <= #size - lo,
+                        "bit range out of bounds: the len is {} but the range is {}..{}",
+                        #size,
+                        lo,
+                        lo.saturating_add(len),
+                    );
+                    ::core::assert!(len <= 128, "cannot read {} bits into a u128", len);
+                    let mut __bf_value: ::core::primitive::u128 = 0;
+                    for __bf_index in 0..len {
+                        let __bf_bit = lo + __bf_index;
+                        if self.#storage[__bf_bit / 8] & (1_u8 << (__bf_bit % 8)) != 0 {
+                            __bf_value |= 1 << __bf_index;
+                        }
+                    }
+                    __bf_value
+                }
+
+                /// Returns the bits in `range` as the lowest bits of the result.
+                ///
+                /// This is equivalent to `get_bits(range.start, range.len())` but returns the
+                /// smallest unsigned integer type covering all bits of the bitfield.
+                ///
+                /// # Panics
+                ///
+                /// - If `range` is decreasing or out of bounds for the bits of the bitfield.
+                /// - If `range` spans more than 128 bits.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn bits_in(&self, range: ::core::ops::Range<::core::primitive::usize>) -> #repr_type {
+                    ::core::assert!(
+                        range.start <= range.end,
+                        "bit range starts at {} but ends at {}",
+                        range.start,
+                        range.end,
+                    );
+                    self.get_bits(range.start, range.end - range.start) as #repr_type
+                }
+
+                /// Sets the `len` bits starting at bit `lo` to the lowest bits of `value`.
+                ///
+                /// The bit at index 0 is the least significant bit of the first byte.
+                ///
+                /// # Panics
+                ///
+                /// - If the bits are out of bounds for the bits of the bitfield.
+                /// - If `value` does not fit into `len` bits.
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                pub fn set_bits(
+                    &mut self,
+                    lo: ::core::primitive::usize,
+                    len: ::core::primitive::usize,
+                    value: ::core::primitive::u128,
+                ) {
+                    ::core::assert!(
+                        lo <= #size && len <= #size - lo,
+                        "bit range out of bounds: the len is {} but the range is {}..{}",
+                        #size,
+                        lo,
+                        lo.saturating_add(len),
+                    );
+                    ::core::assert!(
+                        value.checked_shr(len as ::core::primitive::u32).unwrap_or(0) == 0,
+                        "value {} does not fit into {} bits",
+                        value,
+                        len,
+                    );
+                    for __bf_index in 0..len {
+                        let __bf_bit = lo + __bf_index;
+                        let __bf_mask = 1_u8 << (__bf_bit % 8);
+                        if (value >> __bf_index) & 1 != 0 {
+                            self.#storage[__bf_bit / 8] |= __bf_mask;
+                        } else {
+                            self.#storage[__bf_bit / 8] &= !__bf_mask;
+                        }
+                    }
+                }
+
+                /// Sets the bits in `range` to the lowest bits of `value`.
+                ///
+                /// This is equivalent to `set_bits(range.start, range.len(), value)`.
+                ///
+                /// # Panics
+                ///
+                /// - If `range` is decreasing or out of bounds for the bits of the bitfield.
+                /// - If `value` does not fit into the bits of `range`.
+                #[inline]
+                #[allow(dead_code)]
+                pub fn set_bits_range(
+                    &mut self,
+                    range: ::core::ops::Range<::core::primitive::usize>,
+                    value: ::core::primitive::u128,
+                ) {
+                    ::core::assert!(
+                        range.start <= range.end,
+                        "bit range starts at {} but ends at {}",
+                        range.start,
+                        range.end,
+                    );
+                    self.set_bits(range.start, range.end - range.start, value)
+                }
+            }
+        ))
+    }
+
+    /// Generates `is_zero` and `is_all_ones` checking the bits of the bitfield.
+    ///
+    /// Only the bits occupied by fields are expected to be set by `is_all_ones`.
+    fn generate_is_zero_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let fields_size = self.generate_bitfield_size(config);
+        quote_spanned!(span=>
+            impl #ident {
+                /// Returns `true` if all bits of the bitfield are zero.
+                #[inline]
+                pub const fn is_zero(&self) -> ::core::primitive::bool {
+                    let mut i = 0;
+                    while i < self.#storage.len() {
+                        if self.#storage[i] != 0x00 {
+                            return false
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+
+                /// Returns `true` if all bits occupied by the fields of the bitfield are set.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                pub const fn is_all_ones(&self) -> ::core::primitive::bool {
+                    let __bf_bits: ::core::primitive::usize = #fields_size;
+                    let mut i = 0;
+                    while i < self.#storage.len() {
+                        let __bf_expected = if __bf_bits >= (i + 1) * 8 {
+                            0xFF_u8
+                        } else if __bf_bits <= i * 8 {
+                            0x00_u8
+                        } else {
+                            0xFF_u8 >> ((i + 1) * 8 - __bf_bits)
+                        };
+                        if self.#storage[i] != __bf_expected {
+                            return false
+                        }
+                        i += 1;
+                    }
+                    true
+                }
+            }
+        )
+    }
+
+    /// Generates `hamming_distance` counting the differing bits of two bitfields.
+    ///
+    /// Only the bits occupied by fields are compared.
+    fn generate_hamming_distance_impl(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let fields_size = self.generate_bitfield_size(config);
+        quote_spanned!(span=>
+            impl #ident {
+                /// Returns the number of bits in which `self` and `other` differ.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op)]
+                pub const fn hamming_distance(&self, other: &Self) -> ::core::primitive::u32 {
+                    let __bf_bits: ::core::primitive::usize = #fields_size;
+                    let mut distance = 0;
+                    let mut i = 0;
+                    while i < self.#storage.len() {
+                        let __bf_mask = if __bf_bits >= (i + 1) * 8 {
+                            0xFF_u8
+                        } else if __bf_bits <= i * 8 {
+                            0x00_u8
+                        } else {
+                            0xFF_u8 >> ((i + 1) * 8 - __bf_bits)
+                        };
+                        distance += ((self.#storage[i] ^ other.#storage[i]) & __bf_mask).count_ones();
+                        i += 1;
+                    }
+                    distance
+                }
+            }
+        )
+    }
+
+    /// Generates `add_bcd` and the `Add` impl for the `bcd` parameter.
+    ///
+    /// The bits occupied by fields are treated as a sequence of binary coded decimal digits
+    /// with the least significant digit in the least significant nibble. Therefore every field
+    /// is required to be a multiple of 4 bits wide.
+    fn generate_bcd_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.bcd.as_ref()?.span;
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let fields_size = self.generate_bitfield_size(config);
+        let asserts = self.field_infos(config).map(|info| {
+            let bits = Self::generate_field_bits(&info);
+            let error_msg = format!(
+                "field {} of a `bcd` bitfield must be a multiple of 4 bits wide",
+                info.name(),
+            );
+            quote_spanned!(info.field.span()=>
+                ::core::assert!((#bits) % 4 == 0, #error_msg);
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl #ident {
+                /// Adds the binary coded decimal digits of `self` and `other` with decimal carry
+                /// from each nibble into the next more significant one.
+                ///
+                /// Returns the sum together with a `bool` indicating whether the most significant
+                /// digit carried out of the bitfield. Digits greater than 9 yield unspecified
+                /// but non-panicking results.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op)]
+                pub const fn add_bcd(self, other: Self) -> (Self, ::core::primitive::bool) {
+                    const _: () = {
+                        #( #asserts )*
+                    };
+                    let __bf_digits: ::core::primitive::usize = (#fields_size) / 4;
+                    let mut sum = self.#storage;
+                    let mut carry = 0_u8;
+                    let mut i = 0;
+                    while i < __bf_digits {
+                        let shift = (i % 2) * 4;
+                        let digit = ((self.#storage[i / 2] >> shift) & 0x0F)
+                            + ((other.#storage[i / 2] >> shift) & 0x0F)
+                            + carry;
+                        carry = digit / 10;
+                        sum[i / 2] = (sum[i / 2] & !(0x0F << shift)) | ((digit % 10) << shift);
+                        i += 1;
+                    }
+                    (Self { #storage: sum }, carry != 0)
+                }
+            }
+
+            impl ::core::ops::Add for #ident {
+                type Output = Self;
+
+                /// Adds the binary coded decimal digits of both bitfields, discarding the carry
+                /// out of the most significant digit.
+                #[inline]
+                fn add(self, other: Self) -> Self {
+                    self.add_bcd(other).0
+                }
+            }
+        ))
+    }
+
+    /// Generates `rotate_left`, `rotate_right` and `reverse_bits` operating on the bits within
+    /// the bit width.
+    fn generate_rotate_impls(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let storage = config.storage_ident(span);
+        let ident = &self.item_struct.ident;
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        quote_spanned!(span=>
+            impl #ident {
+                /// Rotates the bits to the left by `n` bits, wrapping the truncated bits
+                /// around to the least significant bit.
+                ///
+                /// The rotation is within the bit width of the bitfield and not the width
+                /// of its underlying bytes.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op)]
+                pub const fn rotate_left(self, n: u32) -> Self {
+                    let bits: usize = #size;
+                    if bits == 0 {
+                        return self
+                    }
+                    let n = n as usize % bits;
+                    let mut rotated = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    let mut i = 0;
+                    while i < bits {
+                        let src = (i + bits - n) % bits;
+                        if self.#storage[src / 8] & (0x01 << (src % 8)) != 0 {
+                            rotated[i / 8] |= 0x01 << (i % 8);
+                        }
+                        i += 1;
+                    }
+                    Self { #storage: rotated }
                 }
 
-                /// Updates the underlying byte.
+                /// Rotates the bits to the right by `n` bits, wrapping the truncated bits
+                /// around to the most significant bit.
                 ///
-                /// # Layout
+                /// The rotation is within the bit width of the bitfield and not the width
+                /// of its underlying bytes.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op)]
+                pub const fn rotate_right(self, n: u32) -> Self {
+                    let bits: usize = #size;
+                    if bits == 0 {
+                        return self
+                    }
+                    self.rotate_left((bits - n as usize % bits) as u32)
+                }
+
+                /// Reverses the order of the bits, the least significant bit becoming the most
+                /// significant bit and vice versa.
                 ///
-                /// This is based on Big Endian indexing, aka, most significant byte is at index 0.
-                #[inline(always)]
+                /// The reversal is within the bit width of the bitfield and not the width
+                /// of its underlying bytes, so padding bits remain zero.
+                #[inline]
+                #[must_use]
                 #[allow(clippy::identity_op)]
-                pub fn update_byte_be(&mut self, byte: usize, value: u8) {
-                    self.bytes[#next_divisible_by_8 / 8usize - 1 - byte] = value;
+                pub const fn reverse_bits(self) -> Self {
+                    let bits: usize = #size;
+                    if bits == 0 {
+                        return self
+                    }
+                    let mut reversed = [0x00_u8; #next_divisible_by_8 / 8usize];
+                    let mut i = 0;
+                    while i < bits {
+                        let src = bits - i - 1;
+                        if self.#storage[src / 8] & (0x01 << (src % 8)) != 0 {
+                            reversed[i / 8] |= 0x01 << (i % 8);
+                        }
+                        i += 1;
+                    }
+                    Self { #storage: reversed }
                 }
             }
         )
@@ -306,6 +2978,7 @@ impl BitfieldStruct {
 
     fn expand_getters_for_field(
         &self,
+        bitfield_config: &Config,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
     ) -> Option<TokenStream2> {
@@ -319,6 +2992,7 @@ impl BitfieldStruct {
         }
         let struct_ident = &self.item_struct.ident;
         let span = field.span();
+        let storage = bitfield_config.storage_ident(span);
         let ident = info.ident_frag();
         let name = info.name();
 
@@ -333,6 +3007,8 @@ impl BitfieldStruct {
             .as_ref()
             .map(|_| format_ident!("{}_or_err", ident))
             .unwrap_or_else(|| format_ident!("get_{}_or_err", ident));
+        let get_raw_ident = format_ident!("get_{}_raw", ident);
+        let get_option_ident = format_ident!("get_{}_checked", ident);
         let ty = &field.ty;
         let vis = &field.vis;
         let get_assert_msg = format!(
@@ -347,35 +3023,130 @@ impl BitfieldStruct {
              If the returned value contains an invalid bit pattern for {}.",
             name, name,
         );
-        let getters = quote_spanned!(span=>
-            #[doc = #getter_docs]
-            #[inline]
-            #( #retained_attrs )*
-            #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
-                self.#get_checked_ident().expect(#get_assert_msg)
+        let raw_getter_docs = format!(
+            "Returns the raw bits of {} without converting them into its value.",
+            name,
+        );
+        let option_getter_docs = format!(
+            "Returns the value of {} or `None` if it contains an invalid bit pattern.",
+            name,
+        );
+        let track_caller = Self::generate_track_caller(bitfield_config);
+        // With `by_value_getters` the getters take the `Copy` bitfield by value.
+        let receiver = match bitfield_config.by_value_getters.is_some() {
+            true => quote_spanned!(span=> self),
+            false => quote_spanned!(span=> &self),
+        };
+        // Fields of primitive specifiers in bitfields backed by an integer `#[repr(uN)]`
+        // are read directly from that integer which allows for `const` getters.
+        let integer_repr = bitfield_config
+            .repr
+            .as_ref()
+            .map(|repr| repr.value)
+            .filter(|kind| !matches!(kind, ReprKind::ByteArray(_) | ReprKind::Bool));
+        let getter = match integer_repr {
+            Some(kind) if info.is_primitive_specifier() => {
+                let prim = kind.into_quote();
+                let convert = match info.is_bool() {
+                    true => quote_spanned!(span=> __bf_value != 0),
+                    false => quote_spanned!(span=> __bf_value as <#ty as ::modular_bitfield::Specifier>::InOut),
+                };
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    #track_caller
+                    #( #retained_attrs )*
+                    #vis const fn #get_ident(#receiver) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        let __bf_mask: #prim = <#prim>::MAX >> (<#prim>::BITS as ::core::primitive::usize - <#ty as ::modular_bitfield::Specifier>::BITS);
+                        let __bf_value = (<#prim>::from_le_bytes(self.#storage) >> (#offset)) & __bf_mask;
+                        #convert
+                    }
+                )
+            }
+            _ => {
+                quote_spanned!(span=>
+                    #[doc = #getter_docs]
+                    #[inline]
+                    #track_caller
+                    #( #retained_attrs )*
+                    #vis fn #get_ident(#receiver) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                        self.#get_checked_ident().expect(#get_assert_msg)
+                    }
+                )
             }
+        };
+        // `#[const_enum]` fields are decoded via the `const` helper that
+        // `#[derive(BitfieldSpecifier)]` generates for enums.
+        let const_getter = config.const_enum.as_ref().map(|_| {
+            let get_const_ident = format_ident!("get_{}_const", ident);
+            let const_getter_docs = format!(
+                "Returns the value of {} or `None` if it contains an invalid bit pattern.\n\n\
+                 Unlike the other getters this is a `const fn`.",
+                name,
+            );
+            quote_spanned!(span=>
+                #[doc = #const_getter_docs]
+                #[inline]
+                #[allow(dead_code, clippy::identity_op)]
+                #( #retained_attrs )*
+                #vis const fn #get_const_ident(#receiver) -> ::core::option::Option<#ty> {
+                    let __bf_offset: ::core::primitive::usize = #offset;
+                    let mut __bf_value: ::core::primitive::u128 = 0;
+                    let mut __bf_index = 0;
+                    while __bf_index < <#ty as ::modular_bitfield::Specifier>::BITS {
+                        let __bf_bit = __bf_offset + __bf_index;
+                        if self.#storage[__bf_bit / 8] & (1_u8 << (__bf_bit % 8)) != 0 {
+                            __bf_value |= 1_u128 << __bf_index;
+                        }
+                        __bf_index += 1;
+                    }
+                    <#ty>::__bf_from_bytes_const(__bf_value as <#ty as ::modular_bitfield::Specifier>::Bytes)
+                }
+            )
+        });
+        let getters = quote_spanned!(span=>
+            #getter
 
             #[doc = #checked_getter_docs]
             #[inline]
             #[allow(dead_code)]
             #( #retained_attrs )*
             #vis fn #get_checked_ident(
-                &self,
+                #receiver,
             ) -> ::core::result::Result<
                 <#ty as ::modular_bitfield::Specifier>::InOut,
                 ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>
             > {
-                let __bf_read: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    ::modular_bitfield::private::read_specifier::<#ty>(&self.bytes[..], #offset)
-                };
-                <#ty as ::modular_bitfield::Specifier>::from_bytes(__bf_read)
+                <#ty as ::modular_bitfield::Specifier>::from_bytes(self.#get_raw_ident())
+            }
+
+            #[doc = #option_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_option_ident(
+                #receiver,
+            ) -> ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut> {
+                self.#get_checked_ident().ok()
+            }
+
+            #[doc = #raw_getter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #get_raw_ident(#receiver) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
             }
+
+            #const_getter
         );
         Some(getters)
     }
 
     fn expand_setters_for_field(
         &self,
+        bitfield_config: &Config,
         offset: &Punctuated<syn::Expr, syn::Token![+]>,
         info: &FieldInfo<'_>,
     ) -> Option<TokenStream2> {
@@ -389,6 +3160,7 @@ impl BitfieldStruct {
         }
         let struct_ident = &self.item_struct.ident;
         let span = field.span();
+        let storage = bitfield_config.storage_ident(span);
         let retained_attrs = &config.retained_attrs;
 
         let ident = info.ident_frag();
@@ -400,14 +3172,30 @@ impl BitfieldStruct {
         let set_checked_ident = format_ident!("set_{}_checked", ident);
         let with_ident = format_ident!("with_{}", ident);
         let with_checked_ident = format_ident!("with_{}_checked", ident);
+        let set_if_changed_ident = format_ident!("set_{}_if_changed", ident);
+        let set_raw_ident = format_ident!("set_{}_raw", ident);
+        let with_raw_ident = format_ident!("with_{}_raw", ident);
+        let modify_ident = format_ident!("modify_{}", ident);
+        let get_ident = field
+            .ident
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| format_ident!("get_{}", ident));
 
         let set_assert_msg =
             format!("value out of bounds for field {}.{}", struct_ident, name);
+        let saturate = matches!(
+            bitfield_config.on_overflow.as_ref().map(|on_overflow| on_overflow.value),
+            Some(OverflowKind::Saturate)
+        );
+        let out_of_bounds_docs = if saturate {
+            format!("#Saturation\n\nOut of bounds values are clamped to the maximum value of {}.", name)
+        } else {
+            format!("#Panics\n\nIf the given value is out of bounds for {}.", name)
+        };
         let setter_docs = format!(
-            "Sets the value of {} to the given value.\n\n\
-             #Panics\n\n\
-             If the given value is out of bounds for {}.",
-            name, name,
+            "Sets the value of {} to the given value.\n\n{}",
+            name, out_of_bounds_docs,
         );
         let checked_setter_docs = format!(
             "Sets the value of {} to the given value.\n\n\
@@ -417,10 +3205,8 @@ impl BitfieldStruct {
         );
         let with_docs = format!(
             "Returns a copy of the bitfield with the value of {} \
-             set to the given value.\n\n\
-             #Panics\n\n\
-             If the given value is out of bounds for {}.",
-            name, name,
+             set to the given value.\n\n{}",
+            name, out_of_bounds_docs,
         );
         let checked_with_docs = format!(
             "Returns a copy of the bitfield with the value of {} \
@@ -429,10 +3215,149 @@ impl BitfieldStruct {
              If the given value is out of bounds for {}.",
             name, name,
         );
+        let set_if_changed_docs = format!(
+            "Sets the value of {} to the given value if it differs from the current value.\n\n\
+             Returns `true` if the value of {} has been changed.\n\n{}",
+            name, name, out_of_bounds_docs,
+        );
+        let raw_setter_docs = format!(
+            "Sets the raw bits of {} without converting them from its value.\n\n\
+             Bits of the given value beyond the bit width of {} are ignored.",
+            name, name,
+        );
+        let with_raw_docs = format!(
+            "Returns a copy of the bitfield with the raw bits of {} \
+             set to the given bits without converting them from its value.\n\n\
+             Bits of the given value beyond the bit width of {} are ignored.",
+            name, name,
+        );
+        let modify_docs = format!(
+            "Replaces the value of {} with the result of applying `f` to its current value.\n\n\
+             #Panics\n\n\
+             If the current value of {} contains an invalid bit pattern or the \
+             returned value is out of bounds for {}.",
+            name, name, name,
+        );
+        let track_caller = Self::generate_track_caller(bitfield_config);
+        let chained = Self::generate_chained_setter(bitfield_config, info);
+        // Modifying a field requires its getter as well.
+        let modify = (!config.skip_getters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #modify_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #track_caller
+                #( #retained_attrs )*
+                #vis fn #modify_ident(
+                    &mut self,
+                    f: impl ::core::ops::FnOnce(
+                        <#ty as ::modular_bitfield::Specifier>::InOut
+                    ) -> <#ty as ::modular_bitfield::Specifier>::InOut,
+                ) {
+                    let __bf_val = self.#get_ident();
+                    self.#set_ident(f(__bf_val));
+                }
+            )
+        });
+        // Checks the raw value against the bit width of the field and writes it.
+        let write_raw_val = quote_spanned!(span=>
+            let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+            let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+            };
+            let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
+            // We compare base bits with spec bits to drop this condition
+            // if there cannot be invalid inputs.
+            if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
+                return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+            }
+            ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, __bf_raw_val);
+        );
+        let set_checked_body = quote_spanned!(span=>
+            let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+            }?;
+            #write_raw_val
+            ::core::result::Result::Ok(())
+        );
+        // With `on_overflow = saturate` the panicking setters clamp out of bounds values instead.
+        let (set_body, set_if_changed_body) = if saturate {
+            let saturated_raw_val = quote_spanned!(span=>
+                let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
+                let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
+                };
+                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                    match <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val) {
+                        ::core::result::Result::Ok(__bf_raw_val) if __bf_raw_val <= __bf_max_value => __bf_raw_val,
+                        _ => __bf_max_value,
+                    }
+                };
+            );
+            (
+                quote_spanned!(span=>
+                    #saturated_raw_val
+                    ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, __bf_raw_val);
+                ),
+                quote_spanned!(span=>
+                    #saturated_raw_val
+                    let __bf_old_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                        ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
+                    };
+                    if __bf_old_val == __bf_raw_val {
+                        return false
+                    }
+                    ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, __bf_raw_val);
+                    true
+                ),
+            )
+        } else {
+            (
+                quote_spanned!(span=>
+                    self.#set_checked_ident(new_val).expect(#set_assert_msg)
+                ),
+                quote_spanned!(span=>
+                    let __bf_changed = (|| -> ::core::result::Result<::core::primitive::bool, ::modular_bitfield::error::OutOfBounds> {
+                        let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                            <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
+                        }?;
+                        let __bf_old_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
+                            ::modular_bitfield::private::read_specifier::<#ty>(&self.#storage[..], #offset)
+                        };
+                        if __bf_old_val == __bf_raw_val {
+                            return ::core::result::Result::Ok(false)
+                        }
+                        #write_raw_val
+                        ::core::result::Result::Ok(true)
+                    })();
+                    __bf_changed.expect(#set_assert_msg)
+                ),
+            )
+        };
+        // With `rich_errors` the checked setters report the bitfield and field that failed.
+        let (out_of_bounds, set_checked_body) = match bitfield_config.rich_errors.as_ref() {
+            Some(_) => {
+                let struct_name = struct_ident.to_string();
+                (
+                    quote_spanned!(span=> ::modular_bitfield::error::FieldOutOfBounds),
+                    quote_spanned!(span=>
+                        let __bf_result = (|| -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                            #set_checked_body
+                        })();
+                        __bf_result.map_err(|_| ::modular_bitfield::error::FieldOutOfBounds {
+                            bitfield: #struct_name,
+                            field: #name,
+                        })
+                    ),
+                )
+            }
+            None => (quote_spanned!(span=> ::modular_bitfield::error::OutOfBounds), set_checked_body),
+        };
         let setters = quote_spanned!(span=>
             #[doc = #with_docs]
             #[inline]
             #[allow(dead_code)]
+            #track_caller
             #( #retained_attrs )*
             #vis fn #with_ident(
                 mut self,
@@ -449,7 +3374,7 @@ impl BitfieldStruct {
             #vis fn #with_checked_ident(
                 mut self,
                 new_val: <#ty as ::modular_bitfield::Specifier>::InOut,
-            ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+            ) -> ::core::result::Result<Self, #out_of_bounds> {
                 self.#set_checked_ident(new_val)?;
                 ::core::result::Result::Ok(self)
             }
@@ -457,9 +3382,10 @@ impl BitfieldStruct {
             #[doc = #setter_docs]
             #[inline]
             #[allow(dead_code)]
+            #track_caller
             #( #retained_attrs )*
             #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
-                self.#set_checked_ident(new_val).expect(#set_assert_msg)
+                #set_body
             }
 
             #[doc = #checked_setter_docs]
@@ -468,29 +3394,400 @@ impl BitfieldStruct {
             #vis fn #set_checked_ident(
                 &mut self,
                 new_val: <#ty as ::modular_bitfield::Specifier>::InOut
-            ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+            ) -> ::core::result::Result<(), #out_of_bounds> {
+                #set_checked_body
+            }
+
+            #[doc = #set_if_changed_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #track_caller
+            #( #retained_attrs )*
+            #vis fn #set_if_changed_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::primitive::bool {
+                #set_if_changed_body
+            }
+
+            #[doc = #raw_setter_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_raw_ident(&mut self, bits: <#ty as ::modular_bitfield::Specifier>::Bytes) {
                 let __bf_base_bits: ::core::primitive::usize = 8usize * ::core::mem::size_of::<<#ty as ::modular_bitfield::Specifier>::Bytes>();
                 let __bf_max_value: <#ty as ::modular_bitfield::Specifier>::Bytes = {
                     !0 >> (__bf_base_bits - <#ty as ::modular_bitfield::Specifier>::BITS)
                 };
-                let __bf_spec_bits: ::core::primitive::usize = <#ty as ::modular_bitfield::Specifier>::BITS;
-                let __bf_raw_val: <#ty as ::modular_bitfield::Specifier>::Bytes = {
-                    <#ty as ::modular_bitfield::Specifier>::into_bytes(new_val)
-                }?;
-                // We compare base bits with spec bits to drop this condition
-                // if there cannot be invalid inputs.
-                if !(__bf_base_bits == __bf_spec_bits || __bf_raw_val <= __bf_max_value) {
-                    return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
-                }
-                ::modular_bitfield::private::write_specifier::<#ty>(&mut self.bytes[..], #offset, __bf_raw_val);
-                ::core::result::Result::Ok(())
+                ::modular_bitfield::private::write_specifier::<#ty>(&mut self.#storage[..], #offset, bits & __bf_max_value);
+            }
+
+            #[doc = #with_raw_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #with_raw_ident(mut self, bits: <#ty as ::modular_bitfield::Specifier>::Bytes) -> Self {
+                self.#set_raw_ident(bits);
+                self
             }
+
+            #chained
+            #modify
         );
         Some(setters)
     }
 
+    /// Generates the `get_f` getter and the `set_f_opt` and `with_f_opt` setters as well as their
+    /// checked variants of a `#[sentinel = N]` field which map its raw sentinel value `N` to `None`.
+    fn expand_sentinel_accessors_for_field(
+        &self,
+        bitfield_config: &Config,
+        offset: &Punctuated<syn::Expr, syn::Token![+]>,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let sentinel = info.config.sentinel.as_ref()?;
+        let span = sentinel.span;
+        let struct_ident = &self.item_struct.ident;
+        let storage = bitfield_config.storage_ident(span);
+        let track_caller = Self::generate_track_caller(bitfield_config);
+        let field = info.field;
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let bits = Self::generate_field_bits(info);
+        let value = proc_macro2::Literal::u128_suffixed(sentinel.value);
+        let get_ident = field.ident.as_ref()?;
+        let get_raw_ident = format_ident!("get_{}_raw", ident);
+        let get_opt_ident = format_ident!("get_{}", ident);
+        let set_ident = format_ident!("set_{}", ident);
+        let set_raw_ident = format_ident!("set_{}_raw", ident);
+        let set_checked_ident = format_ident!("set_{}_checked", ident);
+        let set_opt_ident = format_ident!("set_{}_opt", ident);
+        let with_opt_ident = format_ident!("with_{}_opt", ident);
+        let set_opt_checked_ident = format_ident!("set_{}_opt_checked", ident);
+        let with_opt_checked_ident = format_ident!("with_{}_opt_checked", ident);
+        let error_msg = format!(
+            "#[sentinel = {:#x}] does not fit into field {}",
+            sentinel.value, name,
+        );
+        let assert_fits = quote_spanned!(span=>
+            #[allow(clippy::identity_op)]
+            const _: () = ::core::assert!(
+                (#bits) >= 128 || #value >> (#bits) == 0,
+                #error_msg
+            );
+        );
+        let sentinel_bytes = quote_spanned!(span=>
+            match <<#ty as ::modular_bitfield::Specifier>::Bytes as ::core::convert::TryFrom<::core::primitive::u128>>::try_from(#value) {
+                ::core::result::Result::Ok(__bf_sentinel) => __bf_sentinel,
+                ::core::result::Result::Err(_) => ::core::unreachable!(),
+            }
+        );
+        let receiver = match bitfield_config.by_value_getters.is_some() {
+            true => quote_spanned!(span=> self),
+            false => quote_spanned!(span=> &self),
+        };
+        let getter = (!info.config.skip_getters()).then(|| {
+            let docs = format!(
+                "Returns the value of {} or `None` if it holds the sentinel value `{:#x}`.\n\n\
+                 #Panics\n\n\
+                 If {} contains an invalid bit pattern.",
+                name, sentinel.value, name,
+            );
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #get_opt_ident(
+                    #receiver,
+                ) -> ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut> {
+                    #assert_fits
+                    if self.#get_raw_ident() == #sentinel_bytes {
+                        return ::core::option::Option::None
+                    }
+                    ::core::option::Option::Some(self.#get_ident())
+                }
+            )
+        });
+        let setters = (!info.config.skip_setters()).then(|| {
+            let set_docs = format!(
+                "Sets the value of {} to the given value or to the sentinel value `{:#x}` for `None`.\n\n\
+                 #Panics\n\n\
+                 If the given value is out of bounds for {} or equal to the sentinel value.",
+                name, sentinel.value, name,
+            );
+            let with_docs = format!(
+                "Returns a copy of the bitfield with the value of {} set to the given value \
+                 or to the sentinel value `{:#x}` for `None`.\n\n\
+                 #Panics\n\n\
+                 If the given value is out of bounds for {} or equal to the sentinel value.",
+                name, sentinel.value, name,
+            );
+            let set_checked_docs = format!(
+                "Sets the value of {} to the given value or to the sentinel value `{:#x}` for `None`.\n\n\
+                 #Errors\n\n\
+                 If the given value is out of bounds for {} or equal to the sentinel value.",
+                name, sentinel.value, name,
+            );
+            let with_checked_docs = format!(
+                "Returns a copy of the bitfield with the value of {} set to the given value \
+                 or to the sentinel value `{:#x}` for `None`.\n\n\
+                 #Errors\n\n\
+                 If the given value is out of bounds for {} or equal to the sentinel value.",
+                name, sentinel.value, name,
+            );
+            let set_assert_msg = format!(
+                "sentinel value {:#x} passed as `Some` for field {}.{}",
+                sentinel.value, struct_ident, name,
+            );
+            // With `rich_errors` the checked setters report the bitfield and field that failed.
+            let (out_of_bounds, sentinel_error) = match bitfield_config.rich_errors.as_ref() {
+                Some(_) => {
+                    let struct_name = struct_ident.to_string();
+                    (
+                        quote_spanned!(span=> ::modular_bitfield::error::FieldOutOfBounds),
+                        quote_spanned!(span=> ::modular_bitfield::error::FieldOutOfBounds {
+                            bitfield: #struct_name,
+                            field: #name,
+                        }),
+                    )
+                }
+                None => {
+                    (
+                        quote_spanned!(span=> ::modular_bitfield::error::OutOfBounds),
+                        quote_spanned!(span=> ::modular_bitfield::error::OutOfBounds),
+                    )
+                }
+            };
+            // The value is written to a copy first so that `self` is left unchanged if the
+            // written bits turn out to be the sentinel value, e.g. due to saturation.
+            let written_bits = quote_spanned!(span=>
+                ::modular_bitfield::private::read_specifier::<#ty>(&__bf_copy.#storage[..], #offset)
+            );
+            quote_spanned!(span=>
+                #[doc = #set_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #track_caller
+                #( #retained_attrs )*
+                #vis fn #set_opt_ident(
+                    &mut self,
+                    new_val: ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) {
+                    #assert_fits
+                    match new_val {
+                        ::core::option::Option::Some(__bf_value) => {
+                            let mut __bf_copy = Self { #storage: self.#storage };
+                            __bf_copy.#set_ident(__bf_value);
+                            ::core::assert!(#written_bits != #sentinel_bytes, #set_assert_msg);
+                            *self = __bf_copy;
+                        }
+                        ::core::option::Option::None => self.#set_raw_ident(#sentinel_bytes),
+                    }
+                }
+
+                #[doc = #with_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #track_caller
+                #( #retained_attrs )*
+                #vis fn #with_opt_ident(
+                    mut self,
+                    new_val: ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) -> Self {
+                    self.#set_opt_ident(new_val);
+                    self
+                }
+
+                #[doc = #set_checked_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #set_opt_checked_ident(
+                    &mut self,
+                    new_val: ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) -> ::core::result::Result<(), #out_of_bounds> {
+                    match new_val {
+                        ::core::option::Option::Some(__bf_value) => {
+                            let mut __bf_copy = Self { #storage: self.#storage };
+                            __bf_copy.#set_checked_ident(__bf_value)?;
+                            if #written_bits == #sentinel_bytes {
+                                return ::core::result::Result::Err(#sentinel_error)
+                            }
+                            *self = __bf_copy;
+                        }
+                        ::core::option::Option::None => self.#set_raw_ident(#sentinel_bytes),
+                    }
+                    ::core::result::Result::Ok(())
+                }
+
+                #[doc = #with_checked_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #with_opt_checked_ident(
+                    mut self,
+                    new_val: ::core::option::Option<<#ty as ::modular_bitfield::Specifier>::InOut>,
+                ) -> ::core::result::Result<Self, #out_of_bounds> {
+                    self.#set_opt_checked_ident(new_val)?;
+                    ::core::result::Result::Ok(self)
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            #getter
+            #setters
+        ))
+    }
+
+    /// Generates the `tag`, `check_tag` and `match_tag` helpers of a `#[tag]` field whose
+    /// value discriminates the interpretation of the remaining fields.
+    fn expand_tag_accessors_for_field(
+        bitfield_config: &Config,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let tag = info.config.tag.as_ref()?;
+        if info.config.skip_getters() {
+            return None
+        }
+        let span = tag.span;
+        let field = info.field;
+        let retained_attrs = &info.config.retained_attrs;
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let get_ident = field.ident.as_ref()?;
+        let get_checked_ident = format_ident!("{}_or_err", get_ident);
+        let receiver = match bitfield_config.by_value_getters.is_some() {
+            true => quote_spanned!(span=> self),
+            false => quote_spanned!(span=> &self),
+        };
+        // A field named `tag` already has a getter of the same name and semantics.
+        let getter = (get_ident != "tag").then(|| {
+            let docs = format!(
+                "Returns the value of the `#[tag]` field {}.\n\n\
+                 #Panics\n\n\
+                 If {} contains an invalid bit pattern. The constructors of the bitfield do \
+                 not validate the tag, use `check_tag` or `match_tag` for that.",
+                name, name,
+            );
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn tag(#receiver) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    self.#get_ident()
+                }
+            )
+        });
+        let check_docs = format!(
+            "Returns the bitfield if its `#[tag]` field {} holds a valid value.\n\n\
+             The constructors of the bitfield do not validate the tag. This is meant to be \
+             chained onto the construction of the bitfield, e.g. from untrusted bytes, so \
+             that `tag()` never panics afterwards.\n\n\
+             #Errors\n\n\
+             If {} contains an invalid bit pattern.",
+            name, name,
+        );
+        let match_docs = format!(
+            "Calls `f` with the value of the `#[tag]` field {} and returns its result.\n\n\
+             This allows to exhaustively `match` on the tag within `f`.\n\n\
+             #Errors\n\n\
+             If {} contains an invalid bit pattern in which case `f` is not called.",
+            name, name,
+        );
+        Some(quote_spanned!(span=>
+            #getter
+
+            #[doc = #check_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn check_tag(
+                self,
+            ) -> ::core::result::Result<
+                Self,
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>,
+            > {
+                self.#get_checked_ident()?;
+                ::core::result::Result::Ok(self)
+            }
+
+            #[doc = #match_docs]
+            #[inline]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn match_tag<__BfR>(
+                #receiver,
+                f: impl ::core::ops::FnOnce(<#ty as ::modular_bitfield::Specifier>::InOut) -> __BfR,
+            ) -> ::core::result::Result<
+                __BfR,
+                ::modular_bitfield::error::InvalidBitPattern<<#ty as ::modular_bitfield::Specifier>::Bytes>,
+            > {
+                ::core::result::Result::Ok(f(self.#get_checked_ident()?))
+            }
+        ))
+    }
+
+    /// Generates the `is_a` predicates of a `#[variants(A, ..)]` field checking whether the
+    /// field holds the enum variant `A`.
+    fn expand_variant_predicates_for_field(
+        bitfield_config: &Config,
+        info: &FieldInfo<'_>,
+    ) -> Option<TokenStream2> {
+        let variants = info.config.variants.as_ref()?;
+        if info.config.skip_getters() {
+            return None
+        }
+        let field = info.field;
+        let retained_attrs = &info.config.retained_attrs;
+        let ident = info.ident_frag();
+        let name = info.name();
+        let ty = &field.ty;
+        let vis = &field.vis;
+        let get_checked_ident = field
+            .ident
+            .as_ref()
+            .map(|_| format_ident!("{}_or_err", ident))
+            .unwrap_or_else(|| format_ident!("get_{}_or_err", ident));
+        let predicates = variants.value.iter().map(|variant| {
+            let span = variant.span();
+            let is_ident = format_ident!(
+                "is_{}",
+                snake_case(&variant.to_string()),
+                span = span,
+            );
+            let receiver = match bitfield_config.by_value_getters.is_some() {
+                true => quote_spanned!(span=> self),
+                false => quote_spanned!(span=> &self),
+            };
+            let docs = format!(
+                "Returns `true` if {} holds the variant `{}`.\n\n\
+                 Returns `false` for any other variant as well as for invalid bit patterns.",
+                name, variant,
+            );
+            quote_spanned!(span=>
+                #[doc = #docs]
+                #[inline]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #is_ident(#receiver) -> ::core::primitive::bool {
+                    ::core::matches!(self.#get_checked_ident(), ::core::result::Result::Ok(#ty::#variant))
+                }
+            )
+        });
+        Some(quote_spanned!(variants.span=>
+            #( #predicates )*
+        ))
+    }
+
     fn expand_getters_and_setters_for_field(
         &self,
+        bitfield_config: &Config,
         offset: &mut Punctuated<syn::Expr, syn::Token![+]>,
         info: FieldInfo<'_>,
     ) -> Option<TokenStream2> {
@@ -498,20 +3795,26 @@ impl BitfieldStruct {
             index: _, field, ..
         } = &info;
         let span = field.span();
-        let ty = &field.ty;
-        let getters = self.expand_getters_for_field(offset, &info);
-        let setters = self.expand_setters_for_field(offset, &info);
+        let getters = self.expand_getters_for_field(bitfield_config, offset, &info);
+        let setters = self.expand_setters_for_field(bitfield_config, offset, &info);
+        let sentinel_accessors =
+            self.expand_sentinel_accessors_for_field(bitfield_config, offset, &info);
+        let tag_accessors = Self::expand_tag_accessors_for_field(bitfield_config, &info);
+        let variant_predicates = Self::expand_variant_predicates_for_field(bitfield_config, &info);
         let getters_and_setters = quote_spanned!(span=>
             #getters
             #setters
+            #sentinel_accessors
+            #tag_accessors
+            #variant_predicates
         );
-        offset.push(syn::parse_quote! { <#ty as ::modular_bitfield::Specifier>::BITS });
+        let bits = Self::generate_field_bits(&info);
+        offset.push(syn::parse_quote! { #bits });
         Some(getters_and_setters)
     }
 
     fn expand_getters_and_setters(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
         let mut offset = {
             let mut offset = Punctuated::<syn::Expr, Token![+]>::new();
             offset.push(syn::parse_quote! { 0usize });
@@ -521,16 +3824,33 @@ impl BitfieldStruct {
             .field_infos(config)
             .map(|field_info| self.expand_bits_checks_for_field(field_info));
         let setters_and_getters = self.field_infos(config).map(|field_info| {
-            self.expand_getters_and_setters_for_field(&mut offset, field_info)
+            self.expand_getters_and_setters_for_field(config, &mut offset, field_info)
         });
+        let accessors_impl =
+            self.generate_accessors_impl(config, quote_spanned!(span=> #( #setters_and_getters )* ));
         quote_spanned!(span=>
             const _: () = {
                 #( #bits_checks )*
             };
 
-            impl #ident {
-                #( #setters_and_getters )*
-            }
+            #accessors_impl
         )
     }
 }
+
+/// Converts the given `CamelCase` name of a view or variant into the `snake_case` name of its
+/// getter or predicate.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.char_indices() {
+        if c.is_ascii_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
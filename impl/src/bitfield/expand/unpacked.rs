@@ -14,48 +14,110 @@ impl BitfieldStruct {
     pub fn expand_unpacked(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let check_filled = self.generate_check_for_filled(config);
+        let check_empty = self.generate_check_for_empty(config);
         let struct_definition = self.generate_struct_unpacked(config);
         let constructor_definition = self.generate_constructor_unpacked(config);
         let specifier_impl = self.generate_specifier_impl(config);
 
         let byte_conversion_impls = self.generate_byte_conversion_impls_unpacked(config);
         let byte_update_impls = self.generate_byte_update_impls_unpacked(config);
+        let be_bytes_impls = self.generate_be_bytes_impls_unpacked(config);
         let getters_and_setters = self.generate_getters_and_setters_unpacked(config);
         let from_into_impl = self.generate_to_from_repr_unpacked(config);
+        let layout_const = self.generate_layout_const(config);
+        let try_from_u128_impl = self.generate_try_from_u128_impl_unpacked(config);
+        let flags_iter = self.generate_flags_iter(config);
+        let index_by_impl = self.generate_index_by_impl(config);
+        let float_view_method = self.generate_float_view_method(config);
+        let group_getters = self.generate_group_getters(config);
+        let impl_trait_impls = self.generate_impl_trait_impls(config);
+        let ord_impl = self.generate_ord_impl(config);
+        let partial_eq_impl = self.generate_partial_eq_impl(
+            config,
+            &quote_spanned!(span=> &self.__bf_to_repr().to_le_bytes()[..]),
+            &quote_spanned!(span=> &other.__bf_to_repr().to_le_bytes()[..]),
+        );
+        let from_field_map = self.generate_from_field_map(config);
+        let from_str_impl = self.generate_from_str_impl(config);
+        let plain_struct = self.generate_plain_struct(config);
+        let octal_impl = self.generate_octal_impl(config);
+        let decoder = self.generate_decoder(config);
+        let serde_impls = self.generate_serde_impls(
+            config,
+            &quote_spanned!(span=> self.__bf_to_repr().to_le_bytes()),
+        );
+        let deref_impls = self.generate_deref_impls(config);
         // let repr_impls_and_checks = self.expand_repr_from_impls_and_checks(config);
 
         quote_spanned!(span=>
             #struct_definition
             #check_filled
+            #check_empty
             #constructor_definition
             #byte_conversion_impls
             #byte_update_impls
+            #be_bytes_impls
             #getters_and_setters
             #specifier_impl
+            #layout_const
             #from_into_impl
+            #try_from_u128_impl
+            #flags_iter
+            #index_by_impl
+            #float_view_method
+            #group_getters
+            #impl_trait_impls
+            #ord_impl
+            #partial_eq_impl
+            #from_field_map
+            #from_str_impl
+            #plain_struct
+            #octal_impl
+            #decoder
+            #serde_impls
+            #deref_impls
             // #bytes_check
             // #repr_impls_and_checks
         )
     }
 
-    /// Generates the constructor for the bitfield that initializes all bytes to zero.
+    /// Generates the constructor for the bitfield that initializes all fields to zero
+    /// apart from the fields whose specifier has a non-zero `Specifier::DEFAULT_BITS`.
     fn generate_constructor_unpacked(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
 
-        let fields = self.field_infos(config).filter(|f| !f.config.skip_all());
-        let field_names = fields.map(|f| &f.field.ident);
-        let fields = self.field_infos(config).filter(|f| !f.config.skip_all());
-        let field_types = fields.map(|f| &f.field.ty);
+        let field_inits = self
+            .field_infos(config)
+            .filter(|f| !f.config.skip_all())
+            .map(|f| {
+                let cfg_attrs = f.cfg_attrs();
+                let field_name = &f.field.ident;
+                let field_type = &f.field.ty;
+                let bytes = match f.is_primitive_specifier() {
+                    true => quote_spanned!(span=> 0),
+                    false => {
+                        quote_spanned!(span=>
+                            <#field_type as ::modular_bitfield::Specifier>::DEFAULT_BITS
+                                as <#field_type as ::modular_bitfield::Specifier>::Bytes
+                        )
+                    }
+                };
+                quote_spanned!(span=>
+                    #( #cfg_attrs )*
+                    #field_name: <#field_type as ::modular_bitfield::Specifier>::from_bytes(#bytes).expect("Failed to initialise field"),
+                )
+            });
 
         quote_spanned!(span=>
             impl #ident
             {
-                /// Returns an instance with zero initialized data.
-                #[allow(clippy::identity_op)]
+                /// Returns an instance with zero initialized data apart from the fields whose
+                /// specifier has a non-zero default, e.g. enums with a `#[default]` variant.
+                #[allow(clippy::identity_op, clippy::new_without_default)]
                 pub fn new() -> Self {
                     Self {
-                        #( #field_names: <#field_types as ::modular_bitfield::Specifier>::from_bytes(0).expect("Failed to initialise field"), )*
+                        #( #field_inits )*
                     }
                 }
             }
@@ -70,6 +132,44 @@ impl BitfieldStruct {
         let next_divisible_by_8 = Self::next_divisible_by_8(&size);
         let repr = self.get_repr_or_bits(config);
         let repr_type = repr.into_quote();
+        let byte_len = Self::byte_len_unpacked(config, &repr_type, &size);
+        // The byte arrays are padded or truncated to the width of the primitive representation
+        // since they might cover fewer bytes, e.g. 3 bytes for `bits = 24` stored in a `u32`.
+        let from_le_value = quote_spanned!(span=>
+            let mut __bf_buf = [0u8; ::core::mem::size_of::<#repr_type>()];
+            __bf_buf[..Self::BYTES].copy_from_slice(&bytes);
+            let value = #repr_type::from_le_bytes(__bf_buf);
+        );
+        let from_be_value = quote_spanned!(span=>
+            let mut __bf_buf = [0u8; ::core::mem::size_of::<#repr_type>()];
+            __bf_buf[(::core::mem::size_of::<#repr_type>() - Self::BYTES)..]
+                .copy_from_slice(&bytes);
+            let value = #repr_type::from_be_bytes(__bf_buf);
+        );
+
+        let to_le_bytes_body = match self.byte_aligned_fields_unpacked(config) {
+            Some(fields) => {
+                let copies = fields.iter().map(|(ident, start, len)| {
+                    let end = start + len;
+                    quote_spanned!(span=>
+                        __bf_bytes[#start..#end].copy_from_slice(&self.#ident.to_le_bytes()[..#len]);
+                    )
+                });
+                quote_spanned!(span=>
+                    let mut __bf_bytes = [0u8; #byte_len];
+                    #( #copies )*
+                    __bf_bytes
+                )
+            }
+            None => {
+                quote_spanned!(span=>
+                    let value: #repr_type = self.into();
+                    let mut __bf_bytes = [0u8; #byte_len];
+                    __bf_bytes.copy_from_slice(&value.to_le_bytes()[..Self::BYTES]);
+                    __bf_bytes
+                )
+            }
+        };
 
         let from_bytes = match config.filled_enabled() {
             true => {
@@ -79,8 +179,25 @@ impl BitfieldStruct {
                     /// Expects Little Endian byte order.
                     #[inline(always)]
                     #[allow(clippy::identity_op)]
-                    pub fn from_le_bytes(bytes: [u8; #next_divisible_by_8 / 8usize]) -> Self {
-                        let value = #repr_type::from_le_bytes(bytes);
+                    pub fn from_le_bytes(bytes: [u8; #byte_len]) -> Self {
+                        #from_le_value
+                        value.into()
+                    }
+
+                    /// Loads the given bytes into the bitfield struct in place.
+                    #[inline(always)]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_le_bytes(&mut self, bytes: [u8; #byte_len]) {
+                        *self = Self::from_le_bytes(bytes);
+                    }
+
+                    /// Converts the given bytes directly into the bitfield struct.
+                    ///
+                    /// Expects Big Endian byte order.
+                    #[inline(always)]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_be_bytes(bytes: [u8; #byte_len]) -> Self {
+                        #from_be_value
                         value.into()
                     }
                 )
@@ -95,13 +212,49 @@ impl BitfieldStruct {
                     #[inline]
                     #[allow(clippy::identity_op)]
                     pub fn from_le_bytes(
-                        bytes: [u8; #next_divisible_by_8 / 8usize]
+                        bytes: [u8; #byte_len]
                     ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
-                        if bytes[(#next_divisible_by_8 / 8usize) - 1] >= (0x01 << (8 - (#next_divisible_by_8 - #size))) {
+                        #from_le_value
+                        let __bf_size: usize = #size;
+                        if value.checked_shr(__bf_size as ::core::primitive::u32).unwrap_or(0) != 0 {
                             return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
                         }
 
-                        let value = #repr_type::from_le_bytes(bytes);
+                        ::core::result::Result::Ok(value.into())
+                    }
+
+                    /// Loads the given bytes into the bitfield struct in place.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// In this case `self` remains unchanged.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_le_bytes(
+                        &mut self,
+                        bytes: [u8; #byte_len]
+                    ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                        *self = Self::from_le_bytes(bytes)?;
+                        ::core::result::Result::Ok(())
+                    }
+
+                    /// Converts the given big endian bytes directly into the bitfield struct.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// These are the most significant bits and thus reside in the leading bytes.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn from_be_bytes(
+                        bytes: [u8; #byte_len]
+                    ) -> ::core::result::Result<Self, ::modular_bitfield::error::OutOfBounds> {
+                        #from_be_value
+                        let __bf_size: usize = #size;
+                        if value.checked_shr(__bf_size as ::core::primitive::u32).unwrap_or(0) != 0 {
+                            return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                        }
 
                         ::core::result::Result::Ok(value.into())
                     }
@@ -111,6 +264,18 @@ impl BitfieldStruct {
 
         quote_spanned!(span=>
             impl #ident {
+                /// The number of bytes of the underlying byte representation.
+                #[allow(clippy::identity_op)]
+                pub const BYTES: usize = #byte_len;
+
+                /// Returns the number of bytes of the underlying byte representation.
+                ///
+                /// Useful to size external buffers, e.g. `[0u8; Self::bytes_len()]`.
+                #[inline(always)]
+                pub const fn bytes_len() -> usize {
+                    Self::BYTES
+                }
+
                 /// Returns the underlying bits.
                 ///
                 /// # Layout
@@ -120,9 +285,37 @@ impl BitfieldStruct {
                 /// [here](https://docs.rs/modular-bitfield/#generated-structure).
                 #[inline(always)]
                 #[allow(clippy::identity_op)]
-                pub fn to_le_bytes(self) -> [u8; #next_divisible_by_8 / 8usize] {
+                pub fn to_le_bytes(self) -> [u8; #byte_len] {
+                    #to_le_bytes_body
+                }
+
+                /// Returns the underlying bits in big endian byte order.
+                ///
+                /// This is the reverse of the array returned by `to_le_bytes`.
+                #[inline(always)]
+                #[allow(clippy::identity_op)]
+                pub fn to_be_bytes(self) -> [u8; #byte_len] {
                     let value: #repr_type = self.into();
-                    value.to_le_bytes()
+                    let mut __bf_bytes = [0u8; #byte_len];
+                    __bf_bytes.copy_from_slice(
+                        &value.to_be_bytes()[(::core::mem::size_of::<#repr_type>() - Self::BYTES)..],
+                    );
+                    __bf_bytes
+                }
+
+                /// Returns the bitfield with the order of its underlying bytes reversed.
+                ///
+                /// For unfilled bitfields the undefined bits of the most significant byte
+                /// are cleared after the reversal.
+                #[inline]
+                #[must_use]
+                #[allow(clippy::identity_op)]
+                pub fn swap_bytes(self) -> Self {
+                    let value: #repr_type = self.into();
+                    let unused_bits = ::core::mem::size_of::<#repr_type>() * 8 - #next_divisible_by_8;
+                    let swapped = value.swap_bytes() >> unused_bits;
+                    let mask = <#repr_type>::MAX >> (::core::mem::size_of::<#repr_type>() * 8 - #size);
+                    (swapped & mask).into()
                 }
 
                 #from_bytes
@@ -130,11 +323,156 @@ impl BitfieldStruct {
         )
     }
 
-    fn generate_byte_update_impls_unpacked(&self, config: &Config) -> TokenStream2 {
+    /// Returns the length of the byte arrays of the byte conversions.
+    ///
+    /// This is the width of the primitive representation if it is given by `#[repr(uN)]` and
+    /// otherwise the number of bytes covering the bits of the bitfield.
+    fn byte_len_unpacked(
+        config: &Config,
+        repr_type: &TokenStream2,
+        size: &TokenStream2,
+    ) -> TokenStream2 {
+        let next_divisible_by_8 = Self::next_divisible_by_8(size);
+        match config.repr.as_ref() {
+            Some(repr) => quote_spanned!(repr.span=> ::core::mem::size_of::<#repr_type>()),
+            None => {
+                let span = size.span();
+                quote_spanned!(span=> #next_divisible_by_8 / 8usize)
+            }
+        }
+    }
+
+    /// Returns the identifiers, byte offsets and byte widths of all fields if every field
+    /// is an integer primitive specifier starting and ending on a byte boundary.
+    ///
+    /// Such bitfields can be converted to bytes by copying each field's bytes directly
+    /// instead of assembling the primitive representation with shifts.
+    fn byte_aligned_fields_unpacked(&self, config: &Config) -> Option<Vec<(syn::Ident, usize, usize)>> {
+        let mut offset = 0;
+        let mut fields = Vec::new();
+        for info in self.field_infos(config) {
+            let bits = info.primitive_bits()?;
+            if bits % 8 != 0 || info.config.bits.is_some() || info.cfg_attrs().next().is_some() {
+                return None
+            }
+            if !info.config.skip_getters() {
+                fields.push((info.field.ident.clone()?, offset / 8, bits / 8));
+            }
+            offset += bits;
+        }
+        Some(fields)
+    }
+
+    /// Generates `load_be_bytes` and `try_update_byte_le` for the `endian = both` parameter.
+    ///
+    /// Unpacked bitfields always provide `from_be_bytes` and `to_be_bytes`.
+    fn generate_be_bytes_impls_unpacked(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.endian.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let repr_type = self.get_repr_or_bits(config).into_quote();
+        let size = self.generate_target_or_actual_bitfield_size(config);
+        let byte_len = Self::byte_len_unpacked(config, &repr_type, &size);
+        let load_be_bytes = match config.filled_enabled() {
+            true => {
+                quote_spanned!(span=>
+                    /// Loads the given big endian bytes into the bitfield struct in place.
+                    #[inline(always)]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_be_bytes(&mut self, bytes: [u8; #byte_len]) {
+                        *self = Self::from_be_bytes(bytes);
+                    }
+                )
+            }
+            false => {
+                quote_spanned!(span=>
+                    /// Loads the given big endian bytes into the bitfield struct in place.
+                    ///
+                    /// # Errors
+                    ///
+                    /// If the given bytes contain bits at positions that are undefined for `Self`.
+                    /// In this case `self` remains unchanged.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn load_be_bytes(
+                        &mut self,
+                        bytes: [u8; #byte_len]
+                    ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                        *self = Self::from_be_bytes(bytes)?;
+                        ::core::result::Result::Ok(())
+                    }
+                )
+            }
+        };
+        Some(quote_spanned!(span=>
+            impl #ident {
+                #load_be_bytes
+
+                /// Updates the underlying byte if `byte` is in bounds.
+                ///
+                /// # Layout
+                ///
+                /// This is based on Little Endian indexing, aka, least significant byte is at index 0.
+                ///
+                /// # Errors
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
+                #[inline]
+                pub fn try_update_byte_le(
+                    &mut self,
+                    byte: usize,
+                    value: u8,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    if byte >= Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    self.update_byte_le(byte, value);
+                    ::core::result::Result::Ok(())
+                }
+            }
+        ))
+    }
+
+    /// Generates the `TryFrom<u128>` impl going through the primitive representation.
+    ///
+    /// Bitfields represented by `u128` already implement it through their `From<u128>` impl.
+    fn generate_try_from_u128_impl_unpacked(&self, config: &Config) -> Option<TokenStream2> {
         let span = self.item_struct.span();
         let ident = &self.item_struct.ident;
         let size = self.generate_target_or_actual_bitfield_size(config);
-        let next_divisible_by_8 = Self::next_divisible_by_8(&size);
+        let repr = self.get_repr_or_bits(config);
+        if let ReprKind::U128 = repr {
+            return None
+        }
+        let repr_type = repr.into_quote();
+
+        Some(quote_spanned!(span=>
+            impl ::core::convert::TryFrom<::core::primitive::u128> for #ident {
+                type Error = ::modular_bitfield::error::OutOfBounds;
+
+                /// Converts the given value into the bitfield struct.
+                ///
+                /// # Errors
+                ///
+                /// If the value has bits set beyond the bit width of the bitfield.
+                #[inline]
+                #[allow(clippy::identity_op)]
+                fn try_from(
+                    __bf_value: ::core::primitive::u128
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    if __bf_value.checked_shr((#size) as ::core::primitive::u32).unwrap_or(0) != 0 {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    let __bf_prim = <#repr_type as ::core::convert::TryFrom<::core::primitive::u128>>::try_from(__bf_value)
+                        .map_err(|_| ::modular_bitfield::error::OutOfBounds)?;
+                    ::core::result::Result::Ok(<Self as ::core::convert::From<#repr_type>>::from(__bf_prim))
+                }
+            }
+        ))
+    }
+
+    fn generate_byte_update_impls_unpacked(&self, config: &Config) -> TokenStream2 {
+        let span = self.item_struct.span();
+        let ident = &self.item_struct.ident;
         let repr = self.get_repr_or_bits(config);
         let repr_type = repr.into_quote();
 
@@ -148,7 +486,7 @@ impl BitfieldStruct {
                 #[inline]
                 #[allow(clippy::identity_op)]
                 pub fn update_byte_le(&mut self, byte: usize, value: u8) {
-                    let int_val_self: #repr_type = (*self).into();
+                    let int_val_self: #repr_type = ::core::clone::Clone::clone(self).into();
                     let mut value_le = int_val_self.to_le_bytes();
 
                     value_le[byte] = value;
@@ -162,17 +500,49 @@ impl BitfieldStruct {
                 /// # Layout
                 ///
                 /// This is based on Big Endian indexing, aka, most significant byte is at index 0.
+                ///
+                /// # Panics
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
                 #[inline]
                 #[allow(clippy::identity_op)]
                 pub fn update_byte_be(&mut self, byte: usize, value: u8) {
-                    let int_val_self: #repr_type = (*self).into();
+                    ::core::debug_assert!(
+                        byte < Self::BYTES,
+                        "big endian byte index out of bounds: the len is {} but the index is {}",
+                        Self::BYTES,
+                        byte,
+                    );
+                    let int_val_self: #repr_type = ::core::clone::Clone::clone(self).into();
                     let mut value_le = int_val_self.to_le_bytes();
 
-                    value_le[(#next_divisible_by_8 / 8usize - 1 - byte)] = value;
+                    value_le[Self::BYTES - byte - 1] = value;
 
                     let new_value = #repr_type::from_le_bytes(value_le);
                     *self = new_value.into();
                 }
+
+                /// Updates the underlying byte if `byte` is in bounds.
+                ///
+                /// # Layout
+                ///
+                /// This is based on Big Endian indexing, aka, most significant byte is at index 0.
+                ///
+                /// # Errors
+                ///
+                /// If `byte` is out of bounds for the underlying bytes.
+                #[inline]
+                pub fn try_update_byte_be(
+                    &mut self,
+                    byte: usize,
+                    value: u8,
+                ) -> ::core::result::Result<(), ::modular_bitfield::error::OutOfBounds> {
+                    if byte >= Self::BYTES {
+                        return ::core::result::Result::Err(::modular_bitfield::error::OutOfBounds)
+                    }
+                    self.update_byte_be(byte, value);
+                    ::core::result::Result::Ok(())
+                }
             }
         )
     }
@@ -186,6 +556,12 @@ impl BitfieldStruct {
         let attrs = &config.retained_attributes;
         let vis = &self.item_struct.vis;
         let ident = &self.item_struct.ident;
+        let repr_transparent = config
+            .transparent
+            .as_ref()
+            .map(|transparent| quote_spanned!(transparent.span=> #[repr(transparent)]));
+        let clone_copy_derive = Self::generate_clone_copy_derive(config);
+        let clone_copy_check = self.generate_clone_copy_check(config);
 
         let bits_checks = self
             .field_infos(config)
@@ -199,7 +575,9 @@ impl BitfieldStruct {
 
         quote_spanned!(span=>
             #( #attrs )*
-            #[allow(clippy::identity_op)]
+            #clone_copy_derive
+            #repr_transparent
+            #[allow(clippy::identity_op, non_snake_case)]
             #vis struct #ident
             {
                 #( #fields_true )*
@@ -208,9 +586,50 @@ impl BitfieldStruct {
             const _: () = {
                 #( #bits_checks )*
             };
+
+            #clone_copy_check
         )
     }
 
+    /// Generates the `Deref` implementation to the single field of the bitfield for the
+    /// `deref` parameter as well as the `DerefMut` implementation for `deref_mut`.
+    fn generate_deref_impls(&self, config: &Config) -> Option<TokenStream2> {
+        let span = config.deref.as_ref()?.span;
+        let ident = &self.item_struct.ident;
+        let info = self.field_infos(config).next()?;
+        let field_ident = &info.field.ident;
+        let ty = &info.field.ty;
+        let deref_mut_impl = config.deref_mut.as_ref().map(|deref_mut| {
+            let span = deref_mut.span;
+            quote_spanned!(span=>
+                impl ::core::ops::DerefMut for #ident {
+                    /// Returns a mutable reference to the value of the single field.
+                    ///
+                    /// # Note
+                    ///
+                    /// Values written through the returned reference bypass the bounds
+                    /// checks of the setters.
+                    #[inline]
+                    fn deref_mut(&mut self) -> &mut Self::Target {
+                        &mut self.#field_ident
+                    }
+                }
+            )
+        });
+        Some(quote_spanned!(span=>
+            impl ::core::ops::Deref for #ident {
+                type Target = <#ty as ::modular_bitfield::Specifier>::InOut;
+
+                #[inline]
+                fn deref(&self) -> &Self::Target {
+                    &self.#field_ident
+                }
+            }
+
+            #deref_mut_impl
+        ))
+    }
+
     fn expand_field_unpacked(
         &self,
         info: FieldInfo<'_>,
@@ -222,8 +641,10 @@ impl BitfieldStruct {
         let span = field.span();
         let ident = &field.ident;
         let ty = &field.ty;
+        let cfg_attrs = info.cfg_attrs();
 
         let field_token = quote_spanned!(span=>
+            #( #cfg_attrs )*
             #ident: <#ty as ::modular_bitfield::Specifier>::InOut,
         );
 
@@ -232,21 +653,17 @@ impl BitfieldStruct {
 
     fn generate_getters_and_setters_unpacked(&self, config: &Config) -> TokenStream2 {
         let span = self.item_struct.span();
-        let ident = &self.item_struct.ident;
 
         let setters_and_getters = self.field_infos(config).map(|field_info| {
-            self.expand_getters_and_setters_for_field_unpacked(field_info)
+            self.expand_getters_and_setters_for_field_unpacked(config, field_info)
         });
 
-        quote_spanned!(span=>
-            impl #ident {
-                #( #setters_and_getters )*
-            }
-        )
+        self.generate_accessors_impl(config, quote_spanned!(span=> #( #setters_and_getters )* ))
     }
 
     fn expand_getters_and_setters_for_field_unpacked(
         &self,
+        bitfield_config: &Config,
         info: FieldInfo<'_>,
     ) -> Option<TokenStream2> {
         let FieldInfo {
@@ -254,8 +671,8 @@ impl BitfieldStruct {
         } = &info;
         let span = field.span();
 
-        let getters = self.expand_getters_for_field_unpacked(&info);
-        let setters = self.expand_setters_for_field_unpacked(&info);
+        let getters = self.expand_getters_for_field_unpacked(bitfield_config, &info);
+        let setters = self.expand_setters_for_field_unpacked(bitfield_config, &info);
 
         let getters_and_setters = quote_spanned!(span=>
             #getters
@@ -267,6 +684,7 @@ impl BitfieldStruct {
 
     fn expand_getters_for_field_unpacked(
         &self,
+        bitfield_config: &Config,
         info: &FieldInfo<'_>,
     ) -> Option<TokenStream2> {
         let FieldInfo {
@@ -295,14 +713,66 @@ impl BitfieldStruct {
         let real_ident = &field.ident;
 
         let getter_docs = format!("Returns the value of {}.\n", name);
+        let get_raw_ident = format_ident!("get_{}_raw", ident);
+        let raw_getter_docs = format!(
+            "Returns the raw bits of {} without converting them into its value.\n",
+            name,
+        );
+        let raw_assert_msg = format!(
+            "value out of bounds for field {}.{}",
+            &self.item_struct.ident, name
+        );
+
+        // Getters copy the stored value in `const` contexts unless the `InOut` type of the
+        // field is marked as `Clone` but not `Copy` via `#[non_copy]`.
+        let getter = match info.config.non_copy {
+            Some(_) => quote_spanned!(span=>
+                #vis fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    ::core::clone::Clone::clone(&self.#real_ident)
+                }
+            ),
+            None => quote_spanned!(span=>
+                #vis const fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
+                    self.#real_ident
+                }
+            ),
+        };
+        // Primitive specifiers are cheap to copy so a borrowing getter would be of no use.
+        let ref_getter = if info.is_primitive_specifier() {
+            None
+        } else {
+            let get_ref_ident = format_ident!("get_{}_ref", ident);
+            let ref_getter_docs = format!("Returns a reference to the value of {}.\n", name);
+            Some(quote_spanned!(span=>
+                #[doc = #ref_getter_docs]
+                #[allow(dead_code)]
+                #[inline(always)]
+                #( #retained_attrs )*
+                #vis const fn #get_ref_ident(&self) -> &<#ty as ::modular_bitfield::Specifier>::InOut {
+                    &self.#real_ident
+                }
+            ))
+        };
 
+        let track_caller = Self::generate_track_caller(bitfield_config);
         let getters = quote_spanned!(span=>
             #[doc = #getter_docs]
             #[allow(dead_code)]
             #[inline(always)]
             #( #retained_attrs )*
-            #vis const fn #get_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::InOut {
-                self.#real_ident
+            #getter
+
+            #ref_getter
+
+            #[doc = #raw_getter_docs]
+            #[allow(dead_code)]
+            #[inline(always)]
+            #track_caller
+            #( #retained_attrs )*
+            #vis fn #get_raw_ident(&self) -> <#ty as ::modular_bitfield::Specifier>::Bytes {
+                <#ty as ::modular_bitfield::Specifier>::into_bytes(
+                    ::core::clone::Clone::clone(&self.#real_ident)
+                ).expect(#raw_assert_msg)
             }
         );
         Some(getters)
@@ -310,6 +780,7 @@ impl BitfieldStruct {
 
     fn expand_setters_for_field_unpacked(
         &self,
+        bitfield_config: &Config,
         info: &FieldInfo<'_>,
     ) -> Option<TokenStream2> {
         let FieldInfo {
@@ -333,6 +804,8 @@ impl BitfieldStruct {
 
         let set_ident = format_ident!("set_{}", ident);
         let with_ident = format_ident!("with_{}", ident);
+        let set_if_changed_ident = format_ident!("set_{}_if_changed", ident);
+        let modify_ident = format_ident!("modify_{}", ident);
         let setter_docs = format!(
             "Sets the value of {} to the given value.\n\n\
              #Panics\n\n\
@@ -347,6 +820,36 @@ impl BitfieldStruct {
             name, name,
         );
 
+        let set_if_changed_docs = format!(
+            "Sets the value of {} to the given value if it differs from the current value.\n\n\
+             Returns `true` if the value of {} has been changed.\n",
+            name, name,
+        );
+
+        let modify_docs = format!(
+            "Replaces the value of {} with the result of applying `f` to its current value.\n",
+            name,
+        );
+        let chained = Self::generate_chained_setter(bitfield_config, info);
+        // Modifying a field requires its getter as well.
+        let modify = (!config.skip_getters()).then(|| {
+            quote_spanned!(span=>
+                #[doc = #modify_docs]
+                #[inline(always)]
+                #[allow(dead_code)]
+                #( #retained_attrs )*
+                #vis fn #modify_ident(
+                    &mut self,
+                    f: impl ::core::ops::FnOnce(
+                        <#ty as ::modular_bitfield::Specifier>::InOut
+                    ) -> <#ty as ::modular_bitfield::Specifier>::InOut,
+                ) {
+                    let __bf_val = ::core::clone::Clone::clone(&self.#real_ident);
+                    self.#set_ident(f(__bf_val));
+                }
+            )
+        });
+
         let setters = quote_spanned!(span=>
             #[doc = #with_docs]
             #[inline(always)]
@@ -367,6 +870,28 @@ impl BitfieldStruct {
             #vis fn #set_ident(&mut self, new_val: <#ty as ::modular_bitfield::Specifier>::InOut) {
                 self.#real_ident = new_val;
             }
+
+            #[doc = #set_if_changed_docs]
+            #[inline(always)]
+            #[allow(dead_code)]
+            #( #retained_attrs )*
+            #vis fn #set_if_changed_ident(
+                &mut self,
+                new_val: <#ty as ::modular_bitfield::Specifier>::InOut
+            ) -> ::core::primitive::bool {
+                let __bf_changed = <#ty as ::modular_bitfield::Specifier>::into_bytes(
+                    ::core::clone::Clone::clone(&self.#real_ident)
+                ).ok() != <#ty as ::modular_bitfield::Specifier>::into_bytes(
+                    ::core::clone::Clone::clone(&new_val)
+                ).ok();
+                if __bf_changed {
+                    self.#set_ident(new_val);
+                }
+                __bf_changed
+            }
+
+            #chained
+            #modify
         );
         Some(setters)
     }
@@ -382,24 +907,41 @@ impl BitfieldStruct {
 
         let mut into_impls = Vec::new();
         let mut from_impls = Vec::new();
+        let mut to_repr_impls = Vec::new();
 
         let repr = self.get_repr_or_bits(config);
         let prim = repr.into_quote();
 
         let input_ident = quote_spanned! {span=> __bf_input_};
         let result_ident = quote_spanned! {span=> __bf_};
+        let self_ident = quote_spanned! {span=> self};
 
         for field in self.field_infos(config) {
-            let ty = &field.field.ty;
-
-            from_impls.push(self.expand_from_for_field(&mut offset, &field, &input_ident));
-            into_impls.push(self.expand_into_for_field(&mut offset, &field, &prim, &input_ident, &result_ident));
+            from_impls.push(self.expand_from_for_field(&mut offset, &field, &prim, &input_ident));
+            into_impls.push(self.expand_into_for_field(&mut offset, &field, &prim, &input_ident, &result_ident, false));
+            to_repr_impls.push(self.expand_into_for_field(&mut offset, &field, &prim, &self_ident, &result_ident, true));
 
-
-            offset.push(syn::parse_quote! { <#ty as ::modular_bitfield::Specifier>::BITS });
+            let bits = Self::generate_field_bits(&field);
+            offset.push(syn::parse_quote! { #bits });
         }
 
+        let diff_methods = self.generate_diff_methods(
+            config,
+            &prim,
+            &quote_spanned!(span=> self.__bf_to_repr()),
+            &quote_spanned!(span=> __bf_other.__bf_to_repr()),
+        );
+        let masked_method = self.generate_masked_method(&prim, &quote_spanned!(span=> self.__bf_to_repr()));
+        let swap_raw_method = self.generate_swap_raw_method(config, &prim, &quote_spanned!(span=> self.__bf_to_repr()));
+        let patch_method = self.generate_patch_method(config, &prim, &quote_spanned!(span=> self.__bf_to_repr()));
+        let truncated_to_bits_method = self.generate_truncated_to_bits_method(config, &prim, &quote_spanned!(span=> self.__bf_to_repr()));
+        let atomic_methods = self.generate_atomic_methods(repr, &prim, &quote_spanned!(span=> self.__bf_to_repr()));
+        let fields_size = self.generate_bitfield_size(config);
+        let widening_from_impls = self.generate_widening_from_impls(config, repr, &TokenStream2::new());
+
         quote_spanned!(span=>
+                #widening_from_impls
+
                 impl ::core::convert::From<#prim> for #ident
                 {
                     #[inline]
@@ -423,10 +965,52 @@ impl BitfieldStruct {
                         #result_ident
                     }
                 }
+
+                impl #ident
+                {
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    fn __bf_to_repr(&self) -> #prim {
+                        let mut #result_ident: #prim = 0;
+
+                        #( #to_repr_impls )*
+
+                        #result_ident
+                    }
+
+                    /// Returns `true` if all bits of the bitfield are zero.
+                    #[inline]
+                    pub fn is_zero(&self) -> ::core::primitive::bool {
+                        self.__bf_to_repr() == 0
+                    }
+
+                    /// Returns `true` if all bits occupied by the fields of the bitfield are set.
+                    #[inline]
+                    #[allow(clippy::identity_op)]
+                    pub fn is_all_ones(&self) -> ::core::primitive::bool {
+                        let __bf_bits: ::core::primitive::usize = #fields_size;
+                        self.__bf_to_repr() == <#prim>::MAX >> (<#prim>::BITS as ::core::primitive::usize - __bf_bits)
+                    }
+
+                    #diff_methods
+                    #masked_method
+                    #swap_raw_method
+                    #patch_method
+                    #truncated_to_bits_method
+                    #atomic_methods
+                }
+
+                impl ::core::cmp::PartialEq<#prim> for #ident
+                {
+                    #[inline]
+                    fn eq(&self, __bf_other: &#prim) -> ::core::primitive::bool {
+                        self.__bf_to_repr() == *__bf_other
+                    }
+                }
             )
     }
 
-    fn expand_into_for_field(&self, offset: &mut Punctuated<Expr, Add>, info: &FieldInfo<'_>, primitive: &TokenStream2, input_ident: &TokenStream2, result_ident: &TokenStream2) -> Option<TokenStream2> {
+    fn expand_into_for_field(&self, offset: &mut Punctuated<Expr, Add>, info: &FieldInfo<'_>, primitive: &TokenStream2, input_ident: &TokenStream2, result_ident: &TokenStream2, by_ref: bool) -> Option<TokenStream2> {
         let FieldInfo {
             index: _, field,
             config, ..
@@ -438,15 +1022,23 @@ impl BitfieldStruct {
         if config.skip_getters() {
             None
         } else {
+            let cfg_attrs = info.cfg_attrs();
+            let value = match by_ref {
+                true => quote_spanned!(span=> ::core::clone::Clone::clone(&#input_ident.#ident)),
+                false => quote_spanned!(span=> #input_ident.#ident),
+            };
             let result = quote_spanned! {span=>
-                #result_ident |= (<#ty as ::modular_bitfield::Specifier>::into_bytes(#input_ident.#ident).unwrap() as #primitive) << (#offset);
+                #( #cfg_attrs )*
+                {
+                    #result_ident |= (<#ty as ::modular_bitfield::Specifier>::into_bytes(#value).unwrap() as #primitive) << (#offset);
+                }
             };
 
             Some(result)
         }
     }
 
-    fn expand_from_for_field(&self, offset: &mut Punctuated<Expr, Add>, info: &FieldInfo<'_>, input_ident: &TokenStream2) -> Option<TokenStream2> {
+    fn expand_from_for_field(&self, offset: &mut Punctuated<Expr, Add>, info: &FieldInfo<'_>, prim: &TokenStream2, input_ident: &TokenStream2) -> Option<TokenStream2> {
         let FieldInfo {
             index: _, field,
             config, ..
@@ -458,15 +1050,23 @@ impl BitfieldStruct {
         if config.skip_setters() {
             None
         } else {
+            let cfg_attrs = info.cfg_attrs();
+            // The mask only depends on the width of the field. A field spanning the whole
+            // primitive would overflow the shift, in which case all bits are kept.
+            let mask = quote_spanned! {span=>
+                <#prim>::checked_shl(1, <#ty as ::modular_bitfield::Specifier>::BITS as ::core::primitive::u32)
+                    .map_or(!0, |__bf_mask| __bf_mask - 1)
+            };
             let result = quote_spanned! {span=>
-                #ident: <#ty as ::modular_bitfield::Specifier>::from_bytes(((#input_ident >> (#offset)) & ((1 << (<#ty as ::modular_bitfield::Specifier>::BITS - #offset + 1)) - 1)) as <#ty as ::modular_bitfield::Specifier>::Bytes).unwrap(),
+                #( #cfg_attrs )*
+                #ident: <#ty as ::modular_bitfield::Specifier>::from_bytes(((#input_ident >> (#offset)) & (#mask)) as <#ty as ::modular_bitfield::Specifier>::Bytes).unwrap(),
             };
 
             Some(result)
         }
     }
 
-    fn get_repr_or_bits(&self, config: &Config) -> ReprKind {
+    pub fn get_repr_or_bits(&self, config: &Config) -> ReprKind {
         if let Some(rep) = config.repr.as_ref() {
             rep.value
         } else if let Some(bits) = config.bits.as_ref() {
@@ -10,6 +10,128 @@ pub struct FieldConfig {
     pub bits: Option<ConfigValue<usize>>,
     /// An encountered `#[skip]` attribute on a field.
     pub skip: Option<ConfigValue<SkipWhich>>,
+    /// An encountered `#[hidden]` attribute on a field.
+    pub hidden: Option<ConfigValue<()>>,
+    /// An encountered `#[group(name)]` attribute on a field.
+    pub group: Option<ConfigValue<syn::Ident>>,
+    /// An encountered `#[reserved]` attribute on a field.
+    pub reserved: Option<ConfigValue<()>>,
+    /// An encountered `#[deprecated]` attribute on a field.
+    pub deprecated: Option<ConfigValue<()>>,
+    /// An encountered `#[units = "..."]` attribute on a field.
+    pub units: Option<ConfigValue<String>>,
+    /// An encountered `#[checksum(over = a..c, algo = xor)]` attribute on a field.
+    pub checksum: Option<ConfigValue<ChecksumConfig>>,
+    /// An encountered `#[eq(ignore)]` attribute on a field.
+    pub eq_ignore: Option<ConfigValue<()>>,
+    /// An encountered `#[payload_len]` attribute on a field.
+    pub payload_len: Option<ConfigValue<()>>,
+    /// An encountered `#[extract_as(Name)]` attribute on a field.
+    pub extract_as: Option<ConfigValue<syn::Ident>>,
+    /// An encountered `#[const_enum]` attribute on a field.
+    pub const_enum: Option<ConfigValue<()>>,
+    /// An encountered `#[non_copy]` attribute on a field.
+    pub non_copy: Option<ConfigValue<()>>,
+    /// An encountered `#[magic = N]` attribute on a field.
+    pub magic: Option<ConfigValue<u128>>,
+    /// An encountered `#[sentinel = N]` attribute on a field.
+    pub sentinel: Option<ConfigValue<u128>>,
+    /// An encountered `#[tag]` attribute on a field.
+    pub tag: Option<ConfigValue<()>>,
+    /// An encountered `#[variants(A, B)]` attribute on a field.
+    pub variants: Option<ConfigValue<Vec<syn::Ident>>>,
+}
+
+/// The arguments of a `#[checksum(over = a..c, algo = xor)]` field attribute.
+#[derive(Clone)]
+pub struct ChecksumConfig {
+    /// The first field covered by the checksum.
+    pub start: syn::Ident,
+    /// The end of the fields covered by the checksum.
+    pub end: ChecksumEnd,
+    /// The algorithm computing the checksum.
+    pub algo: ChecksumAlgo,
+}
+
+impl ChecksumConfig {
+    /// Returns the field range covered by the checksum as written, e.g. `a..=c`.
+    pub fn range_to_string(&self) -> String {
+        match &self.end {
+            ChecksumEnd::Exclusive(end) => format!("{}..{}", self.start, end),
+            ChecksumEnd::Inclusive(end) => format!("{}..={}", self.start, end),
+            ChecksumEnd::Open => format!("{}..", self.start),
+        }
+    }
+}
+
+/// The end of the field range covered by a `#[checksum(..)]` field.
+#[derive(Clone)]
+pub enum ChecksumEnd {
+    /// `a..c` covers the fields up to, but excluding, `c`.
+    Exclusive(syn::Ident),
+    /// `a..=c` covers the fields up to and including `c`.
+    Inclusive(syn::Ident),
+    /// `a..` covers the fields up to and including the last field.
+    Open,
+}
+
+/// Algorithms supported by `#[checksum(..)]` fields.
+#[derive(Copy, Clone)]
+pub enum ChecksumAlgo {
+    /// The XOR of the covered bits split into chunks of the width of the checksum field.
+    Xor,
+}
+
+impl syn::parse::Parse for ChecksumConfig {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut range = None;
+        let mut algo = None;
+        while !input.is_empty() {
+            let name = input.parse::<syn::Ident>()?;
+            input.parse::<syn::Token![=]>()?;
+            if name == "over" && range.is_none() {
+                let start = input.parse::<syn::Ident>()?;
+                let end = if input.peek(syn::Token![..=]) {
+                    input.parse::<syn::Token![..=]>()?;
+                    ChecksumEnd::Inclusive(input.parse::<syn::Ident>()?)
+                } else {
+                    input.parse::<syn::Token![..]>()?;
+                    match input.peek(syn::Ident) {
+                        true => ChecksumEnd::Exclusive(input.parse::<syn::Ident>()?),
+                        false => ChecksumEnd::Open,
+                    }
+                };
+                range = Some((start, end));
+            } else if name == "algo" && algo.is_none() {
+                let value = input.parse::<syn::Ident>()?;
+                if value != "xor" {
+                    return Err(format_err!(
+                        value,
+                        "encountered unsupported checksum algorithm `{}`, expected `xor`",
+                        value,
+                    ))
+                }
+                algo = Some(ChecksumAlgo::Xor);
+            } else {
+                return Err(format_err!(
+                    name,
+                    "encountered unsupported or duplicate `{}` argument for #[checksum(..)]",
+                    name,
+                ))
+            }
+            if input.is_empty() {
+                break
+            }
+            input.parse::<syn::Token![,]>()?;
+        }
+        let (start, end) = range.ok_or_else(|| {
+            format_err!(input.span(), "missing `over = a..c` argument for #[checksum(..)]")
+        })?;
+        let algo = algo.ok_or_else(|| {
+            format_err!(input.span(), "missing `algo = xor` argument for #[checksum(..)]")
+        })?;
+        Ok(Self { start, end, algo })
+    }
 }
 
 /// Controls which parts of the code generation to skip.
@@ -81,6 +203,324 @@ impl FieldConfig {
         Ok(())
     }
 
+    /// Sets the `#[hidden]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The generated getters and setters of a hidden field are marked `#[doc(hidden)]`.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[hidden]`.
+    pub fn hidden(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.hidden {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[hidden]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[hidden]` here")))
+            }
+            None => self.hidden = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[reserved]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// A reserved field generates no accessors, just like a `#[skip]` field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[reserved]` or a `#[skip]`.
+    pub fn reserved(&mut self, span: Span) -> Result<(), syn::Error> {
+        if let Some(ref previous) = self.skip {
+            return Err(format_err!(
+                span,
+                "encountered `#[reserved]` attribute for a `#[skip]` field"
+            )
+            .into_combine(format_err!(previous.span, "`#[skip]` here")))
+        }
+        match self.reserved {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[reserved]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[reserved]` here")))
+            }
+            None => self.reserved = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[deprecated]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The generated getters and setters of a deprecated field are marked `#[deprecated]`.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[deprecated]`.
+    pub fn deprecated(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.deprecated {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[deprecated]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[deprecated]` here")))
+            }
+            None => self.deprecated = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[units = "..."]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The units are appended to the value of the field in the generated `Debug` impl.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[units = "..."]`.
+    pub fn units(&mut self, units: String, span: Span) -> Result<(), syn::Error> {
+        match self.units {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[units = \"..\"]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[units = \"..\"]` here")))
+            }
+            None => self.units = Some(ConfigValue { value: units, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[checksum(..)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[checksum(..)]`.
+    pub fn checksum(&mut self, checksum: ChecksumConfig, span: Span) -> Result<(), syn::Error> {
+        match self.checksum {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[checksum(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[checksum(..)]` here")))
+            }
+            None => self.checksum = Some(ConfigValue { value: checksum, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[eq(ignore)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The bits of an ignored field are masked out by the generated `PartialEq` impl.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[eq(ignore)]`.
+    pub fn eq_ignore(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.eq_ignore {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[eq(ignore)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[eq(ignore)]` here")))
+            }
+            None => self.eq_ignore = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[payload_len]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The field holds the length in bytes of a variable payload following the bitfield.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[payload_len]`.
+    pub fn payload_len(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.payload_len {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[payload_len]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[payload_len]` here")))
+            }
+            None => self.payload_len = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[const_enum]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The field gets a `const` getter returning `None` for invalid bit patterns.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[const_enum]`.
+    pub fn const_enum(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.const_enum {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[const_enum]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[const_enum]` here")))
+            }
+            None => self.const_enum = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[sentinel = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The raw value `N` marks the field as unset for its `Option` accessors.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[sentinel = N]`.
+    pub fn sentinel(&mut self, value: u128, span: Span) -> Result<(), syn::Error> {
+        match self.sentinel {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[sentinel = N]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[sentinel = N]` here")))
+            }
+            None => self.sentinel = Some(ConfigValue { value, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[tag]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The field gets the `tag`, `check_tag` and `match_tag` helpers of a discriminant.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[tag]`.
+    pub fn tag(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.tag {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[tag]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[tag]` here")))
+            }
+            None => self.tag = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[magic = N]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The field is initialized to the magic value by `new()` and checked by `validate()`.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[magic = N]`.
+    pub fn magic(&mut self, value: u128, span: Span) -> Result<(), syn::Error> {
+        match self.magic {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[magic = N]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[magic = N]` here")))
+            }
+            None => self.magic = Some(ConfigValue { value, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[non_copy]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The getter of the field returns a clone of its value and is therefore not `const`.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[non_copy]`.
+    pub fn non_copy(&mut self, span: Span) -> Result<(), syn::Error> {
+        match self.non_copy {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[non_copy]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[non_copy]` here")))
+            }
+            None => self.non_copy = Some(ConfigValue { value: (), span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[extract_as(Name)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The field gets a standalone wrapper type with the given name.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[extract_as(..)]`.
+    pub fn extract_as(&mut self, name: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        match self.extract_as {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[extract_as(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[extract_as(..)]` here")))
+            }
+            None => self.extract_as = Some(ConfigValue { value: name, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[variants(A, B)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// The bitfield gets an `is_a()` predicate for every listed variant `A` of the field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[variants(..)]`.
+    pub fn variants(&mut self, variants: Vec<syn::Ident>, span: Span) -> Result<(), syn::Error> {
+        match self.variants {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[variants(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[variants(..)]` here")))
+            }
+            None => self.variants = Some(ConfigValue { value: variants, span }),
+        }
+        Ok(())
+    }
+
+    /// Sets the `#[group(name)]` if found for a `#[bitfield]` annotated field.
+    ///
+    /// # Errors
+    ///
+    /// If previously already registered a `#[group(..)]`.
+    pub fn group(&mut self, name: syn::Ident, span: Span) -> Result<(), syn::Error> {
+        match self.group {
+            Some(ref previous) => {
+                return Err(format_err!(
+                    span,
+                    "encountered duplicate `#[group(..)]` attribute for field"
+                )
+                .into_combine(format_err!(previous.span, "duplicate `#[group(..)]` here")))
+            }
+            None => self.group = Some(ConfigValue { value: name, span }),
+        }
+        Ok(())
+    }
+
     /// Sets the `#[skip(which)]` if found for a `#[bitfield]` annotated field.
     ///
     /// # Syntax
@@ -95,7 +535,7 @@ impl FieldConfig {
     ///
     /// If previously already registered a `#[skip]` that overlaps with the previous.
     /// E.g. when skipping getters or setters twice. Note that skipping getters followed
-    /// by skipping setters is fine.
+    /// by skipping setters is fine. Also if previously already registered a `#[reserved]`.
     pub fn skip(&mut self, which: SkipWhich, span: Span) -> Result<(), syn::Error> {
         fn raise_skip_error(
             skip_params: &str,
@@ -113,6 +553,13 @@ impl FieldConfig {
                 skip_params
             ))
         }
+        if let Some(ref previous) = self.reserved {
+            return Err(format_err!(
+                span,
+                "encountered `#[skip]` attribute for a `#[reserved]` field"
+            )
+            .into_combine(format_err!(previous.span, "`#[reserved]` here")))
+        }
         match self.skip {
             Some(ref previous) => {
                 match which {
@@ -149,6 +596,7 @@ impl FieldConfig {
             .map(|config| config.value)
             .map(SkipWhich::skip_setters)
             .unwrap_or(false)
+            || self.reserved.is_some()
     }
 
     /// Returns `true` if the config demands that code generation for getters should be skipped.
@@ -158,13 +606,18 @@ impl FieldConfig {
             .map(|config| config.value)
             .map(SkipWhich::skip_getters)
             .unwrap_or(false)
+            || self.reserved.is_some()
     }
 
+    /// Returns `true` if the config demands that code generation for all accessors should be skipped.
+    ///
+    /// This is also the case for `#[reserved]` fields.
     pub fn skip_all(&self) -> bool {
         self.skip
             .as_ref()
             .map(|config| config.value)
             .map(SkipWhich::skip_all)
             .unwrap_or(false)
+            || self.reserved.is_some()
     }
 }
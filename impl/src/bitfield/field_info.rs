@@ -3,6 +3,7 @@ use super::{
     BitfieldStruct,
     Config,
 };
+use quote::format_ident;
 
 /// Compactly stores all shared and useful information about a single `#[bitfield]` field.
 pub struct FieldInfo<'a> {
@@ -37,6 +38,79 @@ impl<'a> FieldInfo<'a> {
         Self::ident_as_string(self.field, self.index)
     }
 
+    /// Returns the `#[cfg(..)]` attributes of the field.
+    pub fn cfg_attrs(&self) -> impl Iterator<Item = &'a syn::Attribute> {
+        Self::cfg_attrs_of(self.field)
+    }
+
+    /// Returns the `#[cfg(..)]` attributes of the given field.
+    pub fn cfg_attrs_of(field: &'a syn::Field) -> impl Iterator<Item = &'a syn::Attribute> {
+        field.attrs.iter().filter(|attr| attr.path.is_ident("cfg"))
+    }
+
+    /// Returns `true` if the field's type is one of the predefined primitive specifiers.
+    ///
+    /// These are `bool`, `u8`, `u16`, `u32`, `u64`, `u128` and `B1` up to `B128`
+    /// which all have a trivially copyable integer or boolean `InOut` type.
+    pub fn is_primitive_specifier(&self) -> bool {
+        self.primitive_in_out().is_some()
+    }
+
+    /// Returns the concrete `InOut` type of the field if it is a primitive specifier.
+    ///
+    /// For example this is `u8` for `B4` and `bool` for `bool`.
+    pub fn primitive_in_out(&self) -> Option<proc_macro2::TokenStream> {
+        if self.primitive_ident()? == "bool" {
+            return Some(quote::quote! { ::core::primitive::bool })
+        }
+        Some(match self.primitive_bits()? {
+            1..=8 => quote::quote! { ::core::primitive::u8 },
+            9..=16 => quote::quote! { ::core::primitive::u16 },
+            17..=32 => quote::quote! { ::core::primitive::u32 },
+            33..=64 => quote::quote! { ::core::primitive::u64 },
+            _ => quote::quote! { ::core::primitive::u128 },
+        })
+    }
+
+    /// Returns the number of bits of the field's specifier if it is a primitive specifier.
+    pub fn primitive_bits(&self) -> Option<usize> {
+        let ident = self.primitive_ident()?;
+        match ident.as_str() {
+            "bool" => Some(1),
+            "u8" => Some(8),
+            "u16" => Some(16),
+            "u32" => Some(32),
+            "u64" => Some(64),
+            "u128" => Some(128),
+            _ => {
+                ident
+                    .strip_prefix('B')
+                    .and_then(|bits| bits.parse::<usize>().ok())
+                    .filter(|bits| (1..=128).contains(bits))
+            }
+        }
+    }
+
+    /// Returns the last path segment of the field's type if it could be a primitive specifier.
+    fn primitive_ident(&self) -> Option<String> {
+        let path = match &self.field.ty {
+            syn::Type::Path(type_path) if type_path.qself.is_none() => &type_path.path,
+            _ => return None,
+        };
+        match path.segments.last() {
+            Some(segment) if segment.arguments.is_empty() => Some(segment.ident.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the field's type is the `bool` specifier.
+    pub fn is_bool(&self) -> bool {
+        match &self.field.ty {
+            syn::Type::Path(type_path) => type_path.qself.is_none() && type_path.path.is_ident("bool"),
+            _ => false,
+        }
+    }
+
     /// Returns the field's identifier at the given index as `String`.
     pub fn ident_as_string(field: &'a syn::Field, index: usize) -> String {
         field
@@ -47,6 +121,45 @@ impl<'a> FieldInfo<'a> {
     }
 }
 
+/// A getter or setter of the field with the given index named by an `impl_trait` parameter.
+#[derive(Copy, Clone)]
+pub enum ImplTraitAccessor {
+    Getter(usize),
+    Setter(usize),
+}
+
+impl ImplTraitAccessor {
+    /// Resolves the given method name to the getter or setter of a field of the struct.
+    ///
+    /// Returns `None` if the name is neither the getter nor the setter of any field.
+    pub fn resolve(item_struct: &syn::ItemStruct, method: &syn::Ident) -> Option<Self> {
+        BitfieldStruct::fields(item_struct).find_map(|(index, field)| {
+            let getter = match &field.ident {
+                Some(ident) => ident.clone(),
+                None => format_ident!("get_{}", index),
+            };
+            let setter = match &field.ident {
+                Some(ident) => format_ident!("set_{}", ident),
+                None => format_ident!("set_{}", index),
+            };
+            if *method == getter {
+                Some(Self::Getter(index))
+            } else if *method == setter {
+                Some(Self::Setter(index))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the index of the field of the accessor.
+    pub fn index(self) -> usize {
+        match self {
+            Self::Getter(index) | Self::Setter(index) => index,
+        }
+    }
+}
+
 impl BitfieldStruct {
     /// Returns an iterator over the names of the fields.
     ///
@@ -58,7 +171,6 @@ impl BitfieldStruct {
             .fields
             .iter()
             .enumerate()
-            .map(|(n, field)| (n, field))
     }
 
     /// Returns an iterator over the names of the fields.
@@ -1,4 +1,14 @@
-use super::config::Config;
+use super::config::{
+    Config,
+    DebugKind,
+    EndianKind,
+    FloatViewConfig,
+    ImplTraitConfig,
+    OrdKind,
+    OverflowKind,
+    ReprKind,
+    ViewConfig,
+};
 use proc_macro2::Span;
 use syn::{MetaNameValue, parse::Result, spanned::Spanned};
 
@@ -25,22 +35,182 @@ where
 /// }
 /// ```
 pub struct ParamArgs {
-    args: syn::AttributeArgs,
+    args: Vec<ParamArg>,
+}
+
+/// A single parameter given to the `#[bitfield]` proc. macro.
+pub enum ParamArg {
+    /// A parameter in the usual attribute meta format, e.g. `bits = 32`.
+    Meta(syn::NestedMeta),
+    /// The `repr = [u8; N]` parameter which takes a type instead of a literal.
+    ReprArray(syn::TypeArray),
+    /// The `ord = kind`, `debug = kind`, `endian = kind`, `on_overflow = kind`, `repr = bool`,
+    /// `storage = name` or `index_by = Enum` parameters which take an identifier instead of a literal.
+    IdentValue(syn::Ident, syn::Ident),
+    /// The `view(Name = start..end)` parameter which takes a name and a byte range.
+    View(ViewArg),
+    /// The `impl_trait = Trait(a, set_a)` parameter which takes a trait path and accessor names.
+    ImplTrait(ImplTraitArg),
+    /// The `float_view(mantissa = m, exponent = e)` parameter which takes field names.
+    FloatView(FloatViewArg),
+}
+
+/// The arguments of a `view(Name = start..end)` parameter.
+pub struct ViewArg {
+    pub view: syn::Ident,
+    pub name: syn::Ident,
+    pub start: syn::LitInt,
+    pub end: syn::LitInt,
+}
+
+impl syn::parse::Parse for ViewArg {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let view = input.parse::<syn::Ident>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let name = content.parse::<syn::Ident>()?;
+        content.parse::<syn::Token![=]>()?;
+        let start = content.parse::<syn::LitInt>()?;
+        content.parse::<syn::Token![..]>()?;
+        let end = content.parse::<syn::LitInt>()?;
+        Ok(Self { view, name, start, end })
+    }
+}
+
+/// The arguments of an `impl_trait = Trait(a, set_a)` parameter.
+pub struct ImplTraitArg {
+    pub impl_trait: syn::Ident,
+    pub path: syn::Path,
+    pub methods: syn::punctuated::Punctuated<syn::Ident, syn::Token![,]>,
+}
+
+impl syn::parse::Parse for ImplTraitArg {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let impl_trait = input.parse::<syn::Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let path = input.call(syn::Path::parse_mod_style)?;
+        let content;
+        syn::parenthesized!(content in input);
+        let methods = content.parse_terminated(syn::Ident::parse)?;
+        Ok(Self { impl_trait, path, methods })
+    }
+}
+
+/// The arguments of a `float_view(mantissa = m, exponent = e, bias = N)` parameter.
+pub struct FloatViewArg {
+    pub float_view: syn::Ident,
+    pub args: syn::punctuated::Punctuated<FloatViewArgValue, syn::Token![,]>,
+}
+
+/// A single `name = field` or `name = N` argument of a `float_view(..)` parameter.
+pub struct FloatViewArgValue {
+    pub name: syn::Ident,
+    pub value: FloatViewValue,
+}
+
+/// The value of a single argument of a `float_view(..)` parameter.
+pub enum FloatViewValue {
+    /// The name of a field, e.g. for `mantissa = m`.
+    Field(syn::Ident),
+    /// An integer, e.g. for `bias = 15`.
+    Int(syn::LitInt),
+}
+
+impl syn::parse::Parse for FloatViewArg {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let float_view = input.parse::<syn::Ident>()?;
+        let content;
+        syn::parenthesized!(content in input);
+        let args = content.parse_terminated(FloatViewArgValue::parse)?;
+        Ok(Self { float_view, args })
+    }
+}
+
+impl syn::parse::Parse for FloatViewArgValue {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        let name = input.parse::<syn::Ident>()?;
+        input.parse::<syn::Token![=]>()?;
+        let value = match input.peek(syn::LitInt) || input.peek(syn::Token![-]) {
+            true => {
+                let negative = input.parse::<Option<syn::Token![-]>>()?;
+                let lit_int = input.parse::<syn::LitInt>()?;
+                match negative {
+                    Some(minus) => {
+                        FloatViewValue::Int(syn::LitInt::new(
+                            &format!("-{}", lit_int.base10_digits()),
+                            minus.span,
+                        ))
+                    }
+                    None => FloatViewValue::Int(lit_int),
+                }
+            }
+            false => FloatViewValue::Field(input.parse::<syn::Ident>()?),
+        };
+        Ok(Self { name, value })
+    }
 }
 
 impl syn::parse::Parse for ParamArgs {
     fn parse(input: syn::parse::ParseStream) -> Result<Self> {
-        let punctuated =
-            <syn::punctuated::Punctuated<_, syn::Token![,]>>::parse_terminated(input)?;
-        Ok(Self {
-            args: punctuated.into_iter().collect::<Vec<_>>(),
-        })
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            if input.peek(syn::Ident)
+                && input.peek2(syn::Token![=])
+                && input.fork().parse::<syn::Ident>()? == "impl_trait"
+            {
+                args.push(ParamArg::ImplTrait(input.parse::<ImplTraitArg>()?));
+            } else if input.peek(syn::Ident)
+                && input.peek2(syn::Token![=])
+                && input.peek3(syn::token::Bracket)
+            {
+                let name = input.parse::<syn::Ident>()?;
+                if name != "repr" {
+                    return Err(unsupported_argument(name))
+                }
+                input.parse::<syn::Token![=]>()?;
+                args.push(ParamArg::ReprArray(input.parse::<syn::TypeArray>()?));
+            } else if input.peek(syn::Ident)
+                && input.peek2(syn::Token![=])
+                && input.peek3(syn::Ident)
+            {
+                let name = input.parse::<syn::Ident>()?;
+                if name != "ord"
+                    && name != "debug"
+                    && name != "endian"
+                    && name != "repr"
+                    && name != "storage"
+                    && name != "index_by"
+                    && name != "on_overflow"
+                {
+                    return Err(unsupported_argument(name))
+                }
+                input.parse::<syn::Token![=]>()?;
+                args.push(ParamArg::IdentValue(name, input.parse::<syn::Ident>()?));
+            } else if input.peek(syn::Ident)
+                && input.peek2(syn::token::Paren)
+                && input.fork().parse::<syn::Ident>()? == "view"
+            {
+                args.push(ParamArg::View(input.parse::<ViewArg>()?));
+            } else if input.peek(syn::Ident)
+                && input.peek2(syn::token::Paren)
+                && input.fork().parse::<syn::Ident>()? == "float_view"
+            {
+                args.push(ParamArg::FloatView(input.parse::<FloatViewArg>()?));
+            } else {
+                args.push(ParamArg::Meta(input.parse::<syn::NestedMeta>()?));
+            }
+            if input.is_empty() {
+                break
+            }
+            input.parse::<syn::Token![,]>()?;
+        }
+        Ok(Self { args })
     }
 }
 
 impl IntoIterator for ParamArgs {
-    type Item = syn::NestedMeta;
-    type IntoIter = std::vec::IntoIter<syn::NestedMeta>;
+    type Item = ParamArg;
+    type IntoIter = std::vec::IntoIter<ParamArg>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.args.into_iter()
@@ -119,6 +289,412 @@ impl Config {
         Ok(())
     }
 
+    /// Feeds a `reorder: bool` parameter to the `#[bitfield]` configuration.
+    ///
+    /// Only `reorder = false` is supported since fields are always laid out in declaration order.
+    fn feed_reorder_param(&mut self, name_value: MetaNameValue) -> Result<()> {
+        match &name_value.lit {
+            syn::Lit::Bool(lit_bool) if !lit_bool.value => {
+                self.reorder(lit_bool.value, name_value.span())?;
+            }
+            syn::Lit::Bool(lit_bool) => {
+                return Err(format_err!(
+                    lit_bool,
+                    "encountered unsupported `reorder = true` parameter, #[bitfield] fields are always laid out in declaration order",
+                ))
+            }
+            invalid => {
+                return Err(format_err!(
+                invalid,
+                "encountered invalid value argument for #[bitfield] `reorder` parameter, expected `bool`",
+            ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Feeds a `transparent` flag parameter to the `#[bitfield]` configuration.
+    fn feed_transparent_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("transparent"));
+        self.transparent(path.span())
+    }
+
+    /// Feeds a `clone_copy` flag parameter to the `#[bitfield]` configuration.
+    fn feed_clone_copy_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("clone_copy"));
+        self.clone_copy(path.span())
+    }
+
+    /// Feeds a `track_caller` flag parameter to the `#[bitfield]` configuration.
+    fn feed_track_caller_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("track_caller"));
+        self.track_caller(path.span())
+    }
+
+    /// Feeds a `zero_const` flag parameter to the `#[bitfield]` configuration.
+    fn feed_zero_const_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("zero_const"));
+        self.zero_const(path.span())
+    }
+
+    /// Feeds a `max_const` flag parameter to the `#[bitfield]` configuration.
+    fn feed_max_const_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("max_const"));
+        self.max_const(path.span())
+    }
+
+    /// Feeds an `iter_flags` flag parameter to the `#[bitfield]` configuration.
+    fn feed_iter_flags_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("iter_flags"));
+        self.iter_flags(path.span())
+    }
+
+    /// Feeds an `allow_empty` flag parameter to the `#[bitfield]` configuration.
+    fn feed_allow_empty_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("allow_empty"));
+        self.allow_empty(path.span())
+    }
+
+    /// Feeds a `bit_view` flag parameter to the `#[bitfield]` configuration.
+    fn feed_bit_view_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("bit_view"));
+        self.bit_view(path.span())
+    }
+
+    /// Feeds an `octal` flag parameter to the `#[bitfield]` configuration.
+    fn feed_octal_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("octal"));
+        self.octal(path.span())
+    }
+
+    /// Feeds a `decoder` flag parameter to the `#[bitfield]` configuration.
+    fn feed_decoder_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("decoder"));
+        self.decoder(path.span())
+    }
+
+    /// Feeds a `deref` flag parameter to the `#[bitfield]` configuration.
+    fn feed_deref_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("deref"));
+        self.deref(path.span())
+    }
+
+    /// Feeds a `deref_mut` flag parameter to the `#[bitfield]` configuration.
+    fn feed_deref_mut_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("deref_mut"));
+        self.deref_mut(path.span())
+    }
+
+    /// Feeds a `from_field` flag parameter to the `#[bitfield]` configuration.
+    fn feed_from_field_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("from_field"));
+        self.from_field(path.span())
+    }
+
+    /// Feeds a `widening_from` flag parameter to the `#[bitfield]` configuration.
+    fn feed_widening_from_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("widening_from"));
+        self.widening_from(path.span())
+    }
+
+    /// Feeds an `accessors_in_trait` flag parameter to the `#[bitfield]` configuration.
+    fn feed_accessors_in_trait_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("accessors_in_trait"));
+        self.accessors_in_trait(path.span())
+    }
+
+    /// Feeds a `layout` flag parameter to the `#[bitfield]` configuration.
+    fn feed_layout_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("layout"));
+        self.layout(path.span())
+    }
+
+    /// Feeds a `max_align` flag parameter to the `#[bitfield]` configuration.
+    fn feed_max_align_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("max_align"));
+        self.max_align(path.span())
+    }
+
+    /// Feeds a `from_str` flag parameter to the `#[bitfield]` configuration.
+    fn feed_from_str_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("from_str"));
+        self.from_str(path.span())
+    }
+
+    /// Feeds a `chained_setters` flag parameter to the `#[bitfield]` configuration.
+    fn feed_chained_setters_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("chained_setters"));
+        self.chained_setters(path.span())
+    }
+
+    /// Feeds an `enumerate` flag parameter to the `#[bitfield]` configuration.
+    fn feed_enumerate_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("enumerate"));
+        self.enumerate(path.span())
+    }
+
+    /// Feeds a `plain` flag parameter to the `#[bitfield]` configuration.
+    fn feed_plain_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("plain"));
+        self.plain(path.span())
+    }
+
+    /// Feeds a `borrowed_view` flag parameter to the `#[bitfield]` configuration.
+    fn feed_borrowed_view_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("borrowed_view"));
+        self.borrowed_view(path.span())
+    }
+
+    /// Feeds a `rich_errors` parameter to the `#[bitfield]` configuration.
+    fn feed_rich_errors_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("rich_errors"));
+        self.rich_errors(path.span())
+    }
+
+    /// Feeds a `by_value_getters` parameter to the `#[bitfield]` configuration.
+    fn feed_by_value_getters_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("by_value_getters"));
+        self.by_value_getters(path.span())
+    }
+
+    /// Feeds a `bcd` parameter to the `#[bitfield]` configuration.
+    fn feed_bcd_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("bcd"));
+        self.bcd(path.span())
+    }
+
+    /// Feeds a `serde` parameter to the `#[bitfield]` configuration.
+    fn feed_serde_param(&mut self, path: syn::Path) -> Result<()> {
+        assert!(path.is_ident("serde"));
+        if !cfg!(feature = "serde") {
+            return Err(format_err!(
+                path,
+                "encountered #[bitfield] `serde` parameter without the `serde` crate feature"
+            ))
+        }
+        self.serde(path.span())
+    }
+
+    /// Feeds a `repr = [u8; N]` parameter to the `#[bitfield]` configuration.
+    fn feed_repr_array_param(&mut self, array: syn::TypeArray) -> Result<()> {
+        let span = array.span();
+        match &*array.elem {
+            syn::Type::Path(path) if path.qself.is_none() && path.path.is_ident("u8") => {}
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid element type for #[bitfield] `repr` parameter, expected `u8`",
+                ))
+            }
+        }
+        let len = match &array.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(lit_int),
+                ..
+            }) => {
+                lit_int.base10_parse::<usize>().map_err(|err| {
+                    format_err!(
+                        lit_int,
+                        "encountered malformatted integer value for `repr` parameter: {}",
+                        err
+                    )
+                })?
+            }
+            invalid => {
+                return Err(format_err!(
+                    invalid,
+                    "encountered invalid array length for #[bitfield] `repr` parameter, expected an integer literal",
+                ))
+            }
+        };
+        self.repr(ReprKind::ByteArray(len), span)
+    }
+
+    /// Feeds a `repr = bool` parameter to the `#[bitfield]` configuration.
+    fn feed_repr_ident_param(&mut self, name: syn::Ident, value: syn::Ident) -> Result<()> {
+        assert_eq!(name, "repr");
+        if value != "bool" {
+            return Err(format_err!(
+                value,
+                "encountered invalid value argument for #[bitfield] `repr` parameter, expected `bool` or `[u8; N]`",
+            ))
+        }
+        self.repr(ReprKind::Bool, value.span())
+    }
+
+    /// Feeds a `view(Name = start..end)` parameter to the `#[bitfield]` configuration.
+    fn feed_view_param(&mut self, arg: ViewArg) -> Result<()> {
+        let parse_index = |lit_int: &syn::LitInt| {
+            lit_int.base10_parse::<usize>().map_err(|err| {
+                format_err!(
+                    lit_int,
+                    "encountered malformatted integer value for `view` parameter: {}",
+                    err
+                )
+            })
+        };
+        let start = parse_index(&arg.start)?;
+        let end = parse_index(&arg.end)?;
+        if start >= end {
+            return Err(format_err!(
+                arg.end,
+                "encountered empty byte range {}..{} for #[bitfield] `view({})` parameter",
+                start,
+                end,
+                arg.name,
+            ))
+        }
+        let span = arg.view.span();
+        self.view(
+            ViewConfig {
+                name: arg.name,
+                start,
+                end,
+            },
+            span,
+        )
+    }
+
+    /// Feeds an `impl_trait = Trait(a, set_a)` parameter to the `#[bitfield]` configuration.
+    fn feed_impl_trait_param(&mut self, arg: ImplTraitArg) -> Result<()> {
+        if arg.methods.is_empty() {
+            return Err(format_err!(
+                arg.path,
+                "encountered no accessors for #[bitfield] `impl_trait` parameter"
+            ))
+        }
+        let span = arg.impl_trait.span();
+        self.impl_trait(
+            ImplTraitConfig {
+                path: arg.path,
+                methods: arg.methods.into_iter().collect(),
+            },
+            span,
+        )
+    }
+
+    /// Feeds a `float_view(mantissa = m, exponent = e)` parameter to the `#[bitfield]`
+    /// configuration.
+    fn feed_float_view_param(&mut self, arg: FloatViewArg) -> Result<()> {
+        let span = arg.float_view.span();
+        let mut mantissa = None;
+        let mut exponent = None;
+        let mut bias = None;
+        for arg_value in arg.args {
+            let name = arg_value.name.to_string();
+            let duplicate = match (name.as_str(), arg_value.value) {
+                ("mantissa", FloatViewValue::Field(field)) => mantissa.replace(field).is_some(),
+                ("exponent", FloatViewValue::Field(field)) => exponent.replace(field).is_some(),
+                ("bias", FloatViewValue::Int(lit_int)) => {
+                    let value = lit_int.base10_parse::<i32>().map_err(|err| {
+                        format_err!(
+                            lit_int,
+                            "encountered malformatted integer value for `float_view` bias: {}",
+                            err
+                        )
+                    })?;
+                    bias.replace(value).is_some()
+                }
+                ("mantissa" | "exponent" | "bias", _) => {
+                    return Err(format_err!(
+                        arg_value.name,
+                        "encountered invalid value for `{}` in #[bitfield] `float_view` parameter, expected a field name for `mantissa` and `exponent` or an integer for `bias`",
+                        name,
+                    ))
+                }
+                _ => {
+                    return Err(format_err!(
+                        arg_value.name,
+                        "encountered unknown argument `{}` in #[bitfield] `float_view` parameter, expected `mantissa`, `exponent` or `bias`",
+                        name,
+                    ))
+                }
+            };
+            if duplicate {
+                return Err(format_err!(
+                    arg_value.name,
+                    "encountered duplicate `{}` in #[bitfield] `float_view` parameter",
+                    name,
+                ))
+            }
+        }
+        let (mantissa, exponent) = match (mantissa, exponent) {
+            (Some(mantissa), Some(exponent)) => (mantissa, exponent),
+            _ => {
+                return Err(format_err!(
+                    arg.float_view,
+                    "encountered #[bitfield] `float_view` parameter without `mantissa = m` and `exponent = e`"
+                ))
+            }
+        };
+        self.float_view(
+            FloatViewConfig {
+                mantissa,
+                exponent,
+                bias: bias.unwrap_or(0),
+            },
+            span,
+        )
+    }
+
+    /// Feeds an `ord = kind` parameter to the `#[bitfield]` configuration.
+    fn feed_ord_param(&mut self, name: syn::Ident, kind: syn::Ident) -> Result<()> {
+        assert_eq!(name, "ord");
+        match kind.to_string().as_str() {
+            "fields" => self.ord(OrdKind::Fields, name.span()),
+            _ => {
+                Err(format_err!(
+                    kind,
+                    "encountered invalid value argument for #[bitfield] `ord` parameter, expected `fields`",
+                ))
+            }
+        }
+    }
+
+    /// Feeds an `endian = kind` parameter to the `#[bitfield]` configuration.
+    fn feed_endian_param(&mut self, name: syn::Ident, kind: syn::Ident) -> Result<()> {
+        assert_eq!(name, "endian");
+        match kind.to_string().as_str() {
+            "both" => self.endian(EndianKind::Both, name.span()),
+            _ => {
+                Err(format_err!(
+                    kind,
+                    "encountered invalid value argument for #[bitfield] `endian` parameter, expected `both`",
+                ))
+            }
+        }
+    }
+
+    /// Feeds an `on_overflow = kind` parameter to the `#[bitfield]` configuration.
+    fn feed_on_overflow_param(&mut self, name: syn::Ident, kind: syn::Ident) -> Result<()> {
+        assert_eq!(name, "on_overflow");
+        match kind.to_string().as_str() {
+            "saturate" => self.on_overflow(OverflowKind::Saturate, name.span()),
+            _ => {
+                Err(format_err!(
+                    kind,
+                    "encountered invalid value argument for #[bitfield] `on_overflow` parameter, expected `saturate`",
+                ))
+            }
+        }
+    }
+
+    /// Feeds a `debug = kind` parameter to the `#[bitfield]` configuration.
+    fn feed_debug_param(&mut self, name: syn::Ident, kind: syn::Ident) -> Result<()> {
+        assert_eq!(name, "debug");
+        match kind.to_string().as_str() {
+            "verbose" => self.debug(DebugKind::Verbose, name.span()),
+            "by_byte" => self.debug(DebugKind::ByByte, name.span()),
+            "compact" => self.debug(DebugKind::Compact, name.span()),
+            _ => {
+                Err(format_err!(
+                    kind,
+                    "encountered invalid value argument for #[bitfield] `debug` parameter, expected `verbose`, `by_byte` or `compact`",
+                ))
+            }
+        }
+    }
+
     /// Feeds the given parameters to the `#[bitfield]` configuration.
     ///
     /// # Errors
@@ -126,9 +702,56 @@ impl Config {
     /// If a parameter is malformatted, unexpected, duplicate or in conflict.
     pub fn feed_params<'a, P>(&mut self, params: P) -> Result<()>
     where
-        P: IntoIterator<Item = syn::NestedMeta> + 'a,
+        P: IntoIterator<Item = ParamArg> + 'a,
     {
-        for nested_meta in params {
+        for param in params {
+            let nested_meta = match param {
+                ParamArg::Meta(nested_meta) => nested_meta,
+                ParamArg::ReprArray(array) => {
+                    self.feed_repr_array_param(array)?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "repr" => {
+                    self.feed_repr_ident_param(name, value)?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "debug" => {
+                    self.feed_debug_param(name, value)?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "endian" => {
+                    self.feed_endian_param(name, value)?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "on_overflow" => {
+                    self.feed_on_overflow_param(name, value)?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "storage" => {
+                    self.storage(value, name.span())?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) if name == "index_by" => {
+                    self.index_by(value, name.span())?;
+                    continue
+                }
+                ParamArg::IdentValue(name, value) => {
+                    self.feed_ord_param(name, value)?;
+                    continue
+                }
+                ParamArg::View(arg) => {
+                    self.feed_view_param(arg)?;
+                    continue
+                }
+                ParamArg::ImplTrait(arg) => {
+                    self.feed_impl_trait_param(arg)?;
+                    continue
+                }
+                ParamArg::FloatView(arg) => {
+                    self.feed_float_view_param(arg)?;
+                    continue
+                }
+            };
             match nested_meta {
                 syn::NestedMeta::Meta(meta) => {
                     match meta {
@@ -139,10 +762,69 @@ impl Config {
                                 self.feed_filled_param(name_value)?;
                             } else if name_value.path.is_ident("packed"){
                                 self.feed_packed_param(name_value)?;
+                            } else if name_value.path.is_ident("reorder") {
+                                self.feed_reorder_param(name_value)?;
                             } else {
                                 return Err(unsupported_argument(name_value))
                             }
                         }
+                        syn::Meta::Path(path) => {
+                            if path.is_ident("transparent") {
+                                self.feed_transparent_param(path)?;
+                            } else if path.is_ident("clone_copy") {
+                                self.feed_clone_copy_param(path)?;
+                            } else if path.is_ident("track_caller") {
+                                self.feed_track_caller_param(path)?;
+                            } else if path.is_ident("zero_const") {
+                                self.feed_zero_const_param(path)?;
+                            } else if path.is_ident("max_const") {
+                                self.feed_max_const_param(path)?;
+                            } else if path.is_ident("iter_flags") {
+                                self.feed_iter_flags_param(path)?;
+                            } else if path.is_ident("allow_empty") {
+                                self.feed_allow_empty_param(path)?;
+                            } else if path.is_ident("bit_view") {
+                                self.feed_bit_view_param(path)?;
+                            } else if path.is_ident("octal") {
+                                self.feed_octal_param(path)?;
+                            } else if path.is_ident("decoder") {
+                                self.feed_decoder_param(path)?;
+                            } else if path.is_ident("deref") {
+                                self.feed_deref_param(path)?;
+                            } else if path.is_ident("deref_mut") {
+                                self.feed_deref_mut_param(path)?;
+                            } else if path.is_ident("from_field") {
+                                self.feed_from_field_param(path)?;
+                            } else if path.is_ident("widening_from") {
+                                self.feed_widening_from_param(path)?;
+                            } else if path.is_ident("accessors_in_trait") {
+                                self.feed_accessors_in_trait_param(path)?;
+                            } else if path.is_ident("layout") {
+                                self.feed_layout_param(path)?;
+                            } else if path.is_ident("max_align") {
+                                self.feed_max_align_param(path)?;
+                            } else if path.is_ident("from_str") {
+                                self.feed_from_str_param(path)?;
+                            } else if path.is_ident("chained_setters") {
+                                self.feed_chained_setters_param(path)?;
+                            } else if path.is_ident("enumerate") {
+                                self.feed_enumerate_param(path)?;
+                            } else if path.is_ident("plain") {
+                                self.feed_plain_param(path)?;
+                            } else if path.is_ident("borrowed_view") {
+                                self.feed_borrowed_view_param(path)?;
+                            } else if path.is_ident("rich_errors") {
+                                self.feed_rich_errors_param(path)?;
+                            } else if path.is_ident("by_value_getters") {
+                                self.feed_by_value_getters_param(path)?;
+                            } else if path.is_ident("bcd") {
+                                self.feed_bcd_param(path)?;
+                            } else if path.is_ident("serde") {
+                                self.feed_serde_param(path)?;
+                            } else {
+                                return Err(unsupported_argument(path))
+                            }
+                        }
                         unsupported => return Err(unsupported_argument(unsupported)),
                     }
                 }
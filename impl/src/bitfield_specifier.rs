@@ -45,10 +45,9 @@ fn parse_attrs(attrs: &[syn::Attribute]) -> syn::Result<Attributes> {
     let attributes = attrs
         .iter()
         .filter(|attr| attr.path.is_ident("bits"))
-        .fold(
-            Ok(Attributes { bits: None }),
-            |acc: syn::Result<Attributes>, attr| {
-                let mut acc = acc?;
+        .try_fold(
+            Attributes { bits: None },
+            |mut acc: Attributes, attr| {
                 if acc.bits.is_some() {
                     return Err(format_err_spanned!(
                         attr,
@@ -116,9 +115,41 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
 
     let check_discriminants = variants.iter().map(|ident| {
         let span = ident.span();
+        let error_msg = format!(
+            "discriminant of variant `{}::{}` does not fit into {} bits",
+            enum_ident, ident, bits,
+        );
         quote_spanned!(span =>
-            impl ::modular_bitfield::private::checks::CheckDiscriminantInRange<[(); Self::#ident as usize]> for #enum_ident {
-                type CheckType = [(); ((Self::#ident as usize) < (0x01_usize << #bits)) as usize ];
+            const _: () = {
+                let fits = match (#enum_ident::#ident as ::core::primitive::u128).checked_shr(#bits as ::core::primitive::u32) {
+                    ::core::option::Option::Some(rest) => rest == 0,
+                    ::core::option::Option::None => true,
+                };
+                if !fits {
+                    ::core::panic!(#error_msg)
+                }
+            };
+        )
+    });
+    let default_bits = input
+        .variants
+        .iter()
+        .find(|variant| {
+            matches!(variant.fields, syn::Fields::Unit)
+                && variant.attrs.iter().any(|attr| attr.path.is_ident("default"))
+        })
+        .map(|variant| {
+            let ident = &variant.ident;
+            let span = ident.span();
+            quote_spanned!(span=>
+                const DEFAULT_BITS: ::core::primitive::u128 = Self::#ident as ::core::primitive::u128;
+            )
+        });
+    let max_bits = variants.iter().map(|ident| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            if Self::#ident as ::core::primitive::u128 > __bf_max {
+                __bf_max = Self::#ident as ::core::primitive::u128;
             }
         )
     });
@@ -131,11 +162,43 @@ fn generate_enum(input: syn::ItemEnum) -> syn::Result<TokenStream2> {
         )
     });
 
+    let from_bytes_const_arms = variants.iter().map(|ident| {
+        let span = ident.span();
+        quote_spanned!(span=>
+            __bitfield_binding if __bitfield_binding == Self::#ident as <Self as ::modular_bitfield::Specifier>::Bytes => {
+                ::core::option::Option::Some(Self::#ident)
+            }
+        )
+    });
+
     Ok(quote_spanned!(span=>
         #( #check_discriminants )*
 
+        impl #enum_ident {
+            /// Converts the given bytes into the enum in `const` contexts.
+            ///
+            /// Used by the `const` getters of `#[const_enum]` fields.
+            #[doc(hidden)]
+            #[inline]
+            #[allow(dead_code)]
+            pub const fn __bf_from_bytes_const(
+                bytes: <Self as ::modular_bitfield::Specifier>::Bytes,
+            ) -> ::core::option::Option<Self> {
+                match bytes {
+                    #( #from_bytes_const_arms ),*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
         impl ::modular_bitfield::Specifier for #enum_ident {
             const BITS: usize = #bits;
+            #default_bits
+            const MAX_BITS: ::core::primitive::u128 = {
+                let mut __bf_max: ::core::primitive::u128 = 0;
+                #( #max_bits )*
+                __bf_max
+            };
             type Bytes = <[(); #bits] as ::modular_bitfield::private::SpecifierBytes>::Bytes;
             type InOut = Self;
 
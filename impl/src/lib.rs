@@ -39,6 +39,14 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///        if the value contains an invalid bit pattern.
 ///     2. `f_or_err()`: Returns the value of `f` or an error
 ///        if the value contains an invalid bit pattern.
+///     3. `get_f_raw()`: Returns the raw bits of `f` as its `Specifier::Bytes` type
+///        without validating them.
+///     4. `f_range()`: An associated `const fn` returning the range of bits occupied by `f`.
+///     5. `get_f_checked()`: Returns the value of `f` or `None` if the value contains
+///        an invalid bit pattern. Only generated for packed bitfields.
+///     6. `get_f_ref()`: Returns a reference to the stored value of `f`. Only generated for
+///        unpacked bitfields and for every field whose specifier is not a primitive such as
+///        `bool`, `B4` or `u32`, i.e. enums, nested bitfields, tuples and arrays.
 ///
 /// - **Setters:**
 ///
@@ -52,11 +60,104 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///        Primarily useful for method chaining.
 ///     4. `with_f_checked(new_value)`: Similar to `set_f_checked` but consumes and returns `Self`.
 ///        Primarily useful for method chaining.
+///     5. `set_f_if_changed(new_value)`: Sets the value of `f` only if it differs from the
+///        current value and returns whether `f` has been changed.
+///     6. `set_f_raw(bits)` and `with_f_raw(bits)`: Sets the raw bits of `f` as its
+///        `Specifier::Bytes` type without validating them, the latter consuming and returning
+///        `Self`. Only generated for packed bitfields.
+///     7. `modify_f(closure)`: Replaces the value of `f` with the result of applying `closure`
+///        to its current value and might panic like `f()` and `set_f(new_value)`.
+///     8. `set_f_chained(new_value)`: Sets the value of `f` like `set_f(new_value)` but returns
+///        `&mut Self` for chaining. Only generated with the `chained_setters` parameter.
 ///
 /// - **Conversions:**
 ///
 ///     - `from_bytes(bytes)`: Allows to constructor the bitfield type from a fixed array of bytes.
 ///     - `into_bytes()`: Allows to convert the bitfield into its underlying byte representation.
+///     - `load_le_bytes(bytes)`: Loads the given bytes into an existing bitfield in place.
+///     - `to_be_bytes()` and `from_be_bytes(bytes)`: Converts from and to the big endian byte
+///       representation. Only available for unpacked bitfields.
+///     - `write_le_into(out)`: Writes the underlying bytes into a `[MaybeUninit<u8>; N]` buffer
+///       without zeroing it first. Only available for packed bitfields.
+///     - `swap_bytes()`: Reverses the order of the underlying bytes, e.g. to flip endianness.
+///     - `from_bytes_args(b0, b1, ..)`: `const fn` constructing the bitfield from its bytes as
+///       separate arguments, least significant byte first. Only available for filled packed
+///       bitfields of at most 8 bytes whose width is given by `bits = N` or primitive fields.
+///     - `as_bytes_tuple()`: `const fn` returning the bytes as a tuple for destructuring, least
+///       significant byte first. Only available for packed bitfields of at most 8 bytes under the
+///       same conditions as `from_bytes_args`, except that they need not be filled.
+///     - `from_le_bytes_at(buf, bit_offset)`: Extracts the bitfield from a byte buffer starting at an
+///       arbitrary bit offset. Only available for packed bitfields.
+///     - `parse_le(buf)`: Decodes the bitfield from the start of a byte buffer and returns it
+///       together with the unconsumed rest of the buffer for chained parsing. The bitfield also
+///       implements `TryFrom<&[u8]>` for buffers of exactly `Self::BYTES` bytes. Only available
+///       for packed bitfields.
+///     - `from_bits_iter(bits)`: Builds the bitfield from an iterator of `bool` bits, least
+///       significant bit first, ignoring excess and zero-padding missing bits. Only available for
+///       packed bitfields.
+///     - `to_bits()` and `from_bits(bits)`: Converts from and to an array of all `bool` bits of
+///       the bitfield, least significant bit first. The array also implements `From<Self>` for
+///       conversions via `.into()`. Only available for packed bitfields.
+///     - `to_u32_words()` and `from_u32_words(words)`: Converts between the underlying bytes and
+///       little endian `u32` words with the last word padded. Only available for packed bitfields.
+///     - `TryFrom<u128>`: Constructs the bitfield from a `u128` that fits into its bit width.
+///     - `TryFrom<bool>` or `TryFrom<uN>`: Constructs a packed bitfield with a single primitive
+///       field such as `bool` or `B4` from the value of that field. Requires the `from_field` flag.
+///     - `PartialEq<uN>`: Compares the bitfield directly to an integer of its `#[repr(uN)]` type
+///       or, for unpacked bitfields, the integer type fitting its `bits = N`.
+///     - `diff(other)` and `changed_fields(other)`: Returns the bits in which two bitfields differ
+///       as the integer type of `PartialEq<uN>` and iterates over the names of the changed fields.
+///     - `masked(mask)`: Returns the bitfield with only the bits set in `mask` kept, where `mask`
+///       is of the integer type of `PartialEq<uN>`.
+///     - `swap_raw(new)`: Replaces the bitfield by the given value of the integer type of
+///       `PartialEq<uN>` and returns the previous value, failing if `new` exceeds the bit width.
+///     - `patch(mask, value)`: Overwrites the bits set in `mask` by those of `value`, both of the
+///       integer type of `PartialEq<uN>`, failing if the result exceeds the bit width.
+///     - `truncated_to_bits(n)`: Returns the value as the integer type of `PartialEq<uN>` with only
+///       the low `n` bits kept, e.g. for streaming encoders. Panics if `n` exceeds the bit width.
+///     - `contains(mask)`: Returns whether all bits set in `mask` are set, where `mask` is of the
+///       integer type of `PartialEq<uN>`, e.g. `Status::READY_MASK | Status::BUSY_MASK`.
+///       Only available for packed bitfields.
+///     - `F_MASK`: The mask of the bits occupied by field `f` as the integer type of
+///       `PartialEq<uN>`. The `field_mask!(Type, a, b)` macro combines the masks of several fields
+///       at compile time. Fields whose names only differ in case are rejected since their
+///       constants would collide. Only available for packed bitfields.
+///     - `is_zero()` and `is_all_ones()`: Returns whether all bits are zero or whether all bits
+///       occupied by fields are set. These are `const fn` for packed bitfields.
+///     - `hamming_distance(&other)`: Returns the number of bits occupied by fields in which two
+///       bitfields differ. Only available for packed bitfields.
+///     - `bit_offset_of(name)`: Returns the offset in bits of the field with the given name or
+///       `None` if there is no such field.
+///     - `BYTES` and `bytes_len()`: The number of bytes of the underlying byte representation,
+///       usable in constant contexts such as array lengths.
+///     - `from_field_map(map)`: Constructs the bitfield from a `HashMap` of field names
+///       and their raw values. Only available with the `std` crate feature.
+///     - `try_from_le_bytes_all(bytes)`: Converts the bytes into the bitfield and collects the
+///       errors of all invalid fields. Only available for packed bitfields with the `alloc`
+///       crate feature.
+///     - `extend_le_into(out)`: Appends the bytes of `to_le_bytes` to a fixed-capacity
+///       `heapless::Vec<u8, C>`. Only available for packed bitfields with the `heapless` crate
+///       feature.
+///     - `store_into(atomic, order)`, `load_from(atomic, order)` and
+///       `fetch_update_in(atomic, set_order, fetch_order, f)`: Converts between the bitfield and
+///       the atomic integer of its `uN` representation, e.g. `AtomicU32` for `#[repr(u32)]`.
+///       Only available with the `atomic` crate feature for representations of at most 64 bits.
+///     - `get_bits(lo, len)` and `bits_in(range)`: Returns an arbitrary range of at most 128 bits
+///       regardless of field boundaries, as `u128` or as the smallest unsigned integer covering
+///       the bitfield respectively, e.g. `bits_in(2..6)`. Only available with the `bit_view`
+///       parameter, see below.
+///     - `set_bits(lo, len, value)` and `set_bits_range(range, value)`: Overwrites an arbitrary
+///       range of bits regardless of field boundaries, e.g. `set_bits_range(2..6, 0b1010)`.
+///       Only available with the `bit_view` parameter, see below.
+///     - `rotate_left(n)` and `rotate_right(n)`: Rotates the bits within the bit width of the
+///       bitfield. Only available for packed bitfields.
+///     - `reverse_bits()`: Reverses the order of the bits within the bit width of the bitfield.
+///       Only available for packed bitfields.
+///     - `try_set_many(updates)`: Applies a batch of `set_f_checked` writes to a copy of the
+///       bitfield and commits them only if all succeed. Only available for packed bitfields.
+///     - `Borrow<[u8]>`: Borrows the underlying bytes, e.g. to look up bitfields in maps by bytes.
+///       Only available for packed bitfields without `ord = fields` or `#[eq(ignore)]` fields
+///       and not for unfilled bitfields deriving `Hash`.
 ///
 /// # Parameters
 ///
@@ -68,6 +169,10 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// therefore has a bitwidth that is divisible by 8. If `filled` is `false` ensures the
 /// exact opposite.
 ///
+/// Only the storage of an unfilled bitfield is padded to whole bytes. When it is used
+/// as a field of another bitfield via `#[derive(BitfieldSpecifier)]` it occupies exactly
+/// its bit width, so multiple unfilled bitfields can be chained without padding.
+///
 /// The default value is: `true`
 ///
 /// ### Example
@@ -92,6 +197,12 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// acts the same as if it was a true bitfield. This layout is preferable for performance when you have few instances of a
 /// particular struct, but a lot of accesses to the struct's fields. One can get the bitwise representation using the `into()` or `into_bytes()` methods.
 ///
+/// Since unpacked bitfields store their field values directly, fields of non-primitive specifier
+/// types additionally get a `get_f_ref()` getter that returns a reference to the value. This
+/// covers enums as well as nested bitfields, tuples and arrays.
+/// Fields whose `InOut` type is `Clone` but not `Copy` are supported with the `#[non_copy]`
+/// field attribute, so deriving `Clone` for an unpacked bitfield clones each of its fields.
+///
 /// The default value is: `true`
 ///
 /// ### Example
@@ -106,6 +217,31 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Parameter: `reorder: bool`
+///
+/// Fields are always laid out in declaration order: the first field occupies the least
+/// significant bits and every following field starts right after the previous one, without
+/// any reordering for packing efficiency. Features such as `bit_offset_of`, `layout` and the
+/// `#[repr(uN)]` conversions rely on this. `reorder = false` states this guarantee explicitly
+/// while `reorder = true` is rejected since reordering is not supported.
+///
+/// The default value is: `false`
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(reorder = false)]
+/// pub struct Packet {
+///     flag: bool,
+///     wide: u16,
+///     kind: B7,
+/// }
+///
+/// assert_eq!(Packet::bit_offset_of("wide"), Some(1));
+/// assert_eq!(Packet::bit_offset_of("kind"), Some(17));
+/// ```
+///
 /// ## Parameter: `bits = N`
 ///
 /// With the `bits: int` parameter it is possible to control the targeted bit width of
@@ -113,6 +249,9 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// struct will have a bit width of exactly `N`. A `From` and `Into` implementation is generated for the closest integer type that fits
 /// the bit width.
 ///
+/// If a `#[repr(uN)]` is given as well its integer type must be able to hold `N` bits.
+/// For packed or filled bitfields `N` must match its bit width exactly.
+///
 /// ### Example 1
 ///
 /// ```
@@ -141,11 +280,874 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Parameter: `max_align`
+///
+/// Packed bitfields are stored as byte arrays and therefore have an alignment of 1.
+/// With the `max_align` flag the bitfield instead gets the alignment of the smallest native
+/// integer type that is able to hold all of its bits, e.g. the alignment of `u32` for a 24-bit
+/// bitfield. This allows for aligned loads at the cost of size since the size of the bitfield
+/// is rounded up to its alignment, e.g. 4 bytes instead of 3 bytes for a 24-bit bitfield.
+/// The underlying byte representation remains unchanged.
+///
+/// This requires the `bits = N` parameter or a `#[repr(uN)]` annotation and is only supported
+/// for packed bitfields without the `transparent` parameter.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 24, max_align)]
+/// pub struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// assert_eq!(core::mem::align_of::<Rgb>(), core::mem::align_of::<u32>());
+/// assert_eq!(core::mem::size_of::<Rgb>(), 4);
+/// ```
+///
+/// ## Parameter: `transparent`
+///
+/// With the `transparent` flag the generated struct is annotated with `#[repr(transparent)]`
+/// so that it shares the layout of its underlying storage. This is only allowed for filled
+/// bitfield structs with exactly one field and reported as an error otherwise.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(transparent)]
+/// pub struct Address {
+///     value: u32, // 32 bits
+/// }
+///
+/// assert_eq!(core::mem::size_of::<Address>(), 4);
+/// ```
+///
+/// ## Parameter: `clone_copy`
+///
+/// With the `clone_copy` flag the generated struct derives both `Clone` and `Copy`.
+/// The `InOut` types of all fields are required to be `Copy` as well and an additional
+/// `#[derive(Clone)]` or `#[derive(Copy)]` on the struct is reported as an error.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(clone_copy)]
+/// pub struct Flags {
+///     is_ready: bool, // 1 bit
+///     counter: B7,    // 7 bits
+/// }
+///
+/// let flags = Flags::new().with_counter(5);
+/// let mut copy = flags;
+/// copy.set_counter(6);
+/// assert_eq!(flags.counter(), 5);
+/// ```
+///
+/// ## Parameter: `track_caller`
+///
+/// With the `track_caller` flag all accessors that might panic, e.g. `set_f` and `with_f`
+/// for out of bounds values, are annotated with `#[track_caller]` so that panics are reported
+/// at the call site in user code instead of within the generated code.
+///
+/// ### Example
+///
+/// ```should_panic
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(track_caller)]
+/// pub struct Flags {
+///     is_ready: bool, // 1 bit
+///     counter: B7,    // 7 bits
+/// }
+///
+/// // Panics pointing to this line.
+/// let flags = Flags::new().with_counter(128);
+/// ```
+///
+/// ## Parameter: `zero_const`
+///
+/// With the `zero_const` flag an associated `const ZERO: Self` is generated that holds an
+/// instance with all bits set to 0. This is useful as a default value in constant contexts.
+/// The flag is not supported in conjunction with `packed = false`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(zero_const)]
+/// pub struct Flags {
+///     is_ready: bool, // 1 bit
+///     counter: B7,    // 7 bits
+/// }
+///
+/// const DEFAULT_FLAGS: Flags = Flags::ZERO;
+/// assert_eq!(DEFAULT_FLAGS.counter(), 0);
+/// ```
+///
+/// ## Parameter: `max_const`
+///
+/// With the `max_const` flag an associated `const MAX: Self` is generated that holds an
+/// instance with every field set to its maximum valid value, e.g. as a saturation bound.
+/// Numeric fields have all their bits set while enum fields deriving `BitfieldSpecifier` hold
+/// their variant with the largest discriminant. `core::cmp::Ordering` fields hold `Greater`.
+/// `#[magic = N]` fields keep their magic value and the `#[checksum(..)]` field is computed
+/// from the fields it covers so that `MAX` passes `validate()`. `#[reserved]` fields remain
+/// zero. The flag is not supported in conjunction with `packed = false` or with
+/// `#[sentinel = N]` fields since their maximum value might be the sentinel.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 2]
+/// pub enum Speed {
+///     Low = 0,
+///     High = 2,
+/// }
+///
+/// #[bitfield(max_const)]
+/// pub struct Fan {
+///     speed: Speed,
+///     level: B6,
+/// }
+///
+/// assert_eq!(Fan::MAX.speed(), Speed::High);
+/// assert_eq!(Fan::MAX.level(), 63);
+/// ```
+///
+/// ## Parameter: `on_overflow = saturate`
+///
+/// With `on_overflow = saturate` out of bounds values are clamped instead of rejected.
+/// The `set_f`, `with_f` and `set_f_if_changed` setters store the maximum value of the field
+/// instead of panicking and the `TryFrom<u128>` impl sets all bits of the bitfield for values
+/// beyond its bit width instead of returning an error. The `set_f_checked` setters still
+/// report out of bounds values. The parameter is not supported in conjunction with
+/// `packed = false`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use core::convert::TryFrom;
+/// #[bitfield(on_overflow = saturate)]
+/// pub struct Volume {
+///     left: B4,
+///     right: B4,
+/// }
+///
+/// let volume = Volume::new().with_left(100).with_right(3);
+/// assert_eq!(volume.left(), 15);
+/// assert_eq!(volume.right(), 3);
+/// assert!(volume.with_left_checked(16).is_err());
+///
+/// let volume = Volume::try_from(0x1234).unwrap();
+/// assert_eq!((volume.left(), volume.right()), (15, 15));
+/// ```
+///
+/// ## Parameter: `allow_empty`
+///
+/// By default a `#[bitfield]` struct with a total of 0 bits, e.g. one without fields,
+/// is rejected at compile time since it is most likely a mistake.
+/// The `allow_empty` flag explicitly allows such empty bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(allow_empty)]
+/// pub struct Reserved {}
+///
+/// assert_eq!(Reserved::BYTES, 0);
+/// ```
+///
+/// ## Parameter: `bit_view`
+///
+/// With the `bit_view` flag the bitfield can additionally be accessed as a sequence of
+/// single bits through `bit(index)`, `set_bit(index, value)`, `iter_bits()` and indexing.
+/// Arbitrary ranges of bits are read and written through `get_bits(lo, len)`,
+/// `bits_in(range)`, `set_bits(lo, len, value)` and `set_bits_range(range, value)`.
+/// These methods are only generated with the flag so that they do not clash with the
+/// accessors of existing fields, e.g. the `set_bits` setter of a field named `bits`.
+/// The number of bits is available as `BIT_LEN`. The flag is not supported in conjunction
+/// with `packed = false`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bit_view)]
+/// pub struct Register {
+///     low: B4,
+///     high: B4,
+/// }
+///
+/// let mut register = Register::new().with_low(0b0010);
+/// assert!(register[1]);
+/// register.set_bit(7, true);
+/// assert_eq!(register.high(), 0b1000);
+/// assert_eq!(register.iter_bits().filter(|bit| *bit).count(), 2);
+/// register.set_bits_range(2..6, 0b1111);
+/// assert_eq!(register.bits_in(0..8), 0b1011_1110);
+/// ```
+///
+/// ## Parameter: `iter_flags`
+///
+/// With the `iter_flags` flag `IntoIterator` is implemented for references to the bitfield,
+/// yielding the name and value of every `bool` field in declaration order.
+/// Fields of any other type, fields without getters and fields with a `#[cfg(..)]`
+/// attribute are not yielded.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(iter_flags)]
+/// pub struct Permissions {
+///     read: bool,
+///     write: bool,
+///     mode: B6,
+/// }
+///
+/// let permissions = Permissions::new().with_read(true);
+/// let flags = (&permissions).into_iter().collect::<Vec<_>>();
+/// assert_eq!(flags, vec![("read", true), ("write", false)]);
+/// ```
+///
+/// ## Parameter: `index_by = Enum`
+///
+/// With `index_by = Enum` the bitfield implements `Index<Enum>` returning the value of a `bool`
+/// field, e.g. `control[Flag::Enable]`. Every named `bool` field with a getter maps to the
+/// variant of the user enum that is the `CamelCase` form of the field name. The enum must be in
+/// scope and have exactly these variants, so a missing or misspelled flag is a compile error.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// pub enum Flag {
+///     Enable,
+///     IrqPending,
+/// }
+///
+/// #[bitfield(index_by = Flag)]
+/// pub struct Control {
+///     enable: bool,
+///     irq_pending: bool,
+///     mode: B6,
+/// }
+///
+/// let control = Control::new().with_irq_pending(true);
+/// assert!(!control[Flag::Enable]);
+/// assert!(control[Flag::IrqPending]);
+/// ```
+///
+/// ## Parameter: `float_view(mantissa = m, exponent = e)`
+///
+/// With `float_view(mantissa = m, exponent = e)` the bitfield gets a `to_f64` method that
+/// interprets two of its unsigned fields as a custom float layout and returns
+/// `m * 2^(e - bias)`. The optional `bias = N` argument defaults to `0`. Both fields must
+/// be primitive integer fields with getters.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(float_view(mantissa = mantissa, exponent = exponent, bias = 7))]
+/// pub struct Minifloat {
+///     mantissa: B4,
+///     exponent: B4,
+/// }
+///
+/// let value = Minifloat::new().with_mantissa(3).with_exponent(8);
+/// assert_eq!(value.to_f64(), 6.0);
+/// ```
+///
+/// ## Parameter: `octal`
+///
+/// With the `octal` flag `core::fmt::Octal` is implemented by formatting the underlying
+/// bits of the bitfield as an unsigned integer. Formatter flags such as `#`, width and
+/// zero padding are honored. Packed bitfields must not exceed 128 bits and unpacked
+/// bitfields must implement `Clone`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(octal)]
+/// pub struct Permissions {
+///     other: B3,
+///     group: B3,
+///     owner: B3,
+///     #[skip] __: B7,
+/// }
+///
+/// let permissions = Permissions::new().with_owner(0o7).with_group(0o5).with_other(0o4);
+/// assert_eq!(format!("{:#o}", permissions), "0o754");
+/// ```
+///
+/// ## Parameter: `decoder`
+///
+/// With the `decoder` flag an additional `IdentDecoder` type is generated for the bitfield
+/// `Ident` that accumulates bytes across calls to `push_bytes`, e.g. for messages that are
+/// split across multiple reads. Once enough bytes have been accumulated the decoded bitfield
+/// is returned together with the remaining unconsumed bytes. For bitfields with
+/// `filled = false` the result of `from_le_bytes` is returned instead.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(decoder)]
+/// pub struct Header {
+///     kind: B4,
+///     len: B12,
+/// }
+///
+/// let mut decoder = HeaderDecoder::new();
+/// let (header, _) = decoder.push_bytes(&[0x21]);
+/// assert!(header.is_none());
+/// let (header, rest) = decoder.push_bytes(&[0x43, 0xFF]);
+/// assert_eq!(header.unwrap().len(), 0x432);
+/// assert_eq!(rest, &[0xFF]);
+/// ```
+///
+/// ## Parameter: `storage = name`
+///
+/// By default the underlying bytes of a packed bitfield are stored in a private field.
+/// With `storage = name` this field is renamed to `name` and gets the visibility of the
+/// bitfield struct itself. This allows to access the raw bytes directly, e.g. when the
+/// bitfield is embedded into a larger `#[repr(C)]` struct for FFI. The parameter is not
+/// supported in conjunction with `packed = false`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(storage = raw)]
+/// #[repr(C)]
+/// pub struct Flags {
+///     enabled: bool,
+///     priority: B7,
+/// }
+///
+/// let flags = Flags { raw: [0b0000_0101] };
+/// assert!(flags.enabled());
+/// assert_eq!(flags.priority(), 0b10);
+/// ```
+///
+/// ## Parameter: `deref`
+///
+/// With the `deref` flag a bitfield with a single field acting as a strong typedef
+/// implements `Deref` to the value of its field. This is only supported for bitfields
+/// with `packed = false` since packed bitfields do not store the value itself.
+///
+/// The additional `deref_mut` flag also implements `DerefMut`. Note that values written
+/// through `DerefMut` bypass the bounds checks of the setters.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bits = 14, packed = false, filled = false, deref)]
+/// #[derive(Clone)]
+/// pub struct UserId {
+///     value: B12,
+/// }
+///
+/// let id = UserId::new().with_value(42);
+/// assert_eq!(*id, 42);
+/// ```
+///
+/// ## Parameter: `from_field`
+///
+/// With the `from_field` flag a packed bitfield with a single primitive field such as
+/// `bool`, `u16` or `B4` implements `TryFrom` for the `InOut` type of that field which
+/// constructs the bitfield from the value of its field. Values that are out of bounds
+/// for the field are rejected with the same error as the `with_f_checked` setter or
+/// clamped with `on_overflow = saturate`.
+///
+/// The flag is not supported together with `#[repr(uN)]` or for fields wider than 64 bits
+/// since their `TryFrom` impl would overlap with the `From<uN>` and `TryFrom<u128>` impls.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use core::convert::TryFrom;
+/// #[bitfield(filled = false, from_field)]
+/// pub struct Nibble {
+///     value: B4,
+/// }
+///
+/// assert_eq!(Nibble::try_from(0xA_u8).unwrap().value(), 0xA);
+/// assert!(Nibble::try_from(0x1F_u8).is_err());
+/// ```
+///
+/// ## Parameter: `accessors_in_trait`
+///
+/// With the `accessors_in_trait` flag the getters and setters of all fields are not
+/// generated as inherent methods but in an extension trait named after the bitfield,
+/// e.g. `ControlAccessors` for a bitfield `Control`. Users have to import the trait in
+/// order to use the accessors which keeps the inherent API of large bitfields small.
+///
+/// The trait has the same visibility as the bitfield and all of its accessors are
+/// available wherever the trait is.
+///
+/// ### Example
+///
+/// ```
+/// mod regs {
+///     # use modular_bitfield::prelude::*;
+///     #[bitfield(accessors_in_trait)]
+///     pub struct Control {
+///         pub enable: bool,
+///         pub prescaler: B15,
+///     }
+/// }
+///
+/// use regs::{Control, ControlAccessors};
+///
+/// let control = Control::new().with_enable(true).with_prescaler(1000);
+/// assert!(control.enable());
+/// assert_eq!(control.prescaler(), 1000);
+/// ```
+///
+/// ## Parameter: `impl_trait = Trait(a, set_a, ..)`
+///
+/// Implements the given user provided trait for the bitfield by forwarding the listed trait
+/// methods to the getters and setters of the same name. This allows dynamic dispatch over
+/// different bitfields sharing a common set of fields, e.g. via `Box<dyn Trait>`. The compiler
+/// verifies that the method signatures of the trait match the generated accessors.
+/// The parameter may be given multiple times for different traits and conflicts with the
+/// `accessors_in_trait` parameter.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// pub trait Enable {
+///     fn enabled(&self) -> bool;
+///     fn set_enabled(&mut self, new_val: bool);
+/// }
+///
+/// #[bitfield(impl_trait = Enable(enabled, set_enabled))]
+/// pub struct Control {
+///     enabled: bool,
+///     prescaler: B15,
+/// }
+///
+/// #[bitfield(impl_trait = Enable(enabled, set_enabled))]
+/// pub struct Status {
+///     code: B7,
+///     enabled: bool,
+/// }
+///
+/// let mut registers: Vec<Box<dyn Enable>> = vec![Box::new(Control::new()), Box::new(Status::new())];
+/// registers.iter_mut().for_each(|register| register.set_enabled(true));
+/// assert!(registers.iter().all(|register| register.enabled()));
+/// ```
+///
+/// ## Parameter: `layout`
+///
+/// With the `layout` flag a unit struct named after the bitfield, e.g. `HeaderLayout` for
+/// a bitfield `Header`, is generated. It carries the offset and width in bits of every
+/// field as associated constants named `F_OFFSET` and `F_WIDTH` for a field `f`.
+/// Fields of tuple structs are named by their index, e.g. `_0_OFFSET`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(layout)]
+/// pub struct Header {
+///     kind: B4,
+///     length: B12,
+/// }
+///
+/// const LENGTH_MASK: u16 = ((1 << HeaderLayout::LENGTH_WIDTH) - 1) << HeaderLayout::LENGTH_OFFSET;
+/// assert_eq!(LENGTH_MASK, 0xFFF0);
+/// ```
+///
+/// ## Parameter: `enumerate`
+///
+/// With the `enumerate` flag an `all_combinations()` function is generated returning an iterator
+/// over every bitfield whose fields all hold valid bit patterns, e.g. for exhaustive tests or
+/// building lookup tables over bitfields made of small enums. Since the number of combinations
+/// grows exponentially only bitfields of at most 16 bits are supported.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier)]
+/// #[bits = 2]
+/// pub enum Mode {
+///     Off,
+///     Low,
+///     High,
+/// }
+///
+/// #[bitfield(enumerate, filled = false)]
+/// pub struct Config {
+///     mode: Mode,
+///     enabled: bool,
+/// }
+///
+/// assert_eq!(Config::all_combinations().count(), 6);
+/// ```
+///
+/// ## Parameter: `view(Name = start..end)`
+///
+/// With a `view(Name = start..end)` parameter a `#[repr(C)]` struct `Name` wrapping the bytes
+/// `start..end` of the bitfield is generated, e.g. for handing a sub-header to C code.
+/// The bitfield gets a `name` getter returning the view and a `set_name` setter writing it back,
+/// where `name` is the `snake_case` form of `Name`. Multiple views may be declared and may
+/// overlap. Views are only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(view(Header = 0..2))]
+/// pub struct Packet {
+///     kind: B4,
+///     version: B4,
+///     flags: u8,
+///     payload_len: u16,
+/// }
+///
+/// let packet = Packet::new().with_kind(3).with_version(1).with_flags(0x80);
+/// assert_eq!(packet.header().to_le_bytes(), [0x13, 0x80]);
+/// ```
+///
+/// ## Parameter: `chained_setters`
+///
+/// With the `chained_setters` flag an additional `set_f_chained` setter is generated for
+/// every field `f` which takes `&mut self` and returns `&mut Self`. This allows to chain
+/// setters like the `with_f` setters without moving the bitfield.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(chained_setters)]
+/// pub struct Control {
+///     mode: B3,
+///     enabled: bool,
+///     divider: B4,
+/// }
+///
+/// let mut control = Control::new();
+/// control.set_mode_chained(3).set_enabled_chained(true);
+/// assert_eq!(control.mode(), 3);
+/// assert!(control.enabled());
+/// ```
+///
+/// ## Parameter: `from_str`
+///
+/// With the `from_str` flag `core::str::FromStr` is implemented for the bitfield, parsing
+/// comma separated `field=value` pairs such as `"mode=3,enabled=true"`, e.g. for register
+/// pokes on a command line. Values are `true`, `false` or decimal, `0x` prefixed hexadecimal
+/// or `0b` prefixed binary integers. Fields that are not mentioned are zero-initialized.
+/// Unknown fields and invalid or out of bounds values are reported as `ParseFieldsError`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(from_str)]
+/// pub struct Control {
+///     mode: B3,
+///     enabled: bool,
+///     divider: B4,
+/// }
+///
+/// let control: Control = "mode=3, enabled=true, divider=0xA".parse().unwrap();
+/// assert_eq!(control.mode(), 3);
+/// assert!(control.enabled());
+/// assert_eq!(control.divider(), 0xA);
+/// assert!("mode=8".parse::<Control>().is_err());
+/// ```
+///
+/// ## Parameter: `ord = fields`
+///
+/// With `ord = fields` the `PartialOrd` and `Ord` traits are implemented by comparing the
+/// values of the fields lexicographically in declaration order. Note that this generally
+/// differs from comparing the underlying bytes as an integer. All fields must be of types
+/// implementing `Ord` and `PartialEq` as well as `Eq` have to be derived separately.
+/// Since every bit has to take part in the comparison, fields without getters such as
+/// `#[skip]` or `#[reserved]` fields are rejected.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(ord = fields)]
+/// #[derive(PartialEq, Eq)]
+/// pub struct Version {
+///     major: B4,
+///     minor: B4,
+/// }
+///
+/// let newer = Version::new().with_major(1);
+/// let older = Version::new().with_minor(2);
+/// assert!(newer > older);
+/// ```
+///
+/// ## Parameter: `debug = verbose`
+///
+/// With `debug = verbose` the `Debug` impl generated for `#[derive(Debug)]` appends the raw
+/// underlying bytes as a single hex number after the values of the fields. This is only
+/// supported for packed bitfields that derive `Debug`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(debug = verbose)]
+/// #[derive(Debug)]
+/// pub struct MyField {
+///     a: B4,
+///     b: B4,
+/// }
+///
+/// let field = MyField::new().with_a(1).with_b(2);
+/// assert_eq!(format!("{:?}", field), "MyField { a: 1, b: 2, raw: 0x21 }");
+/// ```
+///
+/// ## Parameter: `debug = by_byte`
+///
+/// With `debug = by_byte` the `Debug` impl generated for `#[derive(Debug)]` groups the fields
+/// under the index of the byte containing their first bit, which eases cross-referencing wide
+/// registers with datasheets. This is only supported for packed bitfields that derive `Debug`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(debug = by_byte)]
+/// #[derive(Debug)]
+/// pub struct Register {
+///     a: B4,
+///     b: B4,
+///     c: B8,
+/// }
+///
+/// let register = Register::new().with_a(1).with_b(2).with_c(3);
+/// assert_eq!(
+///     format!("{:?}", register),
+///     "Register {byte_0: {a: 1, b: 2}, byte_1: {c: 3}}",
+/// );
+/// ```
+///
+/// ## Parameter: `debug = compact`
+///
+/// With `debug = compact` the `Debug` impl generated for `#[derive(Debug)]` prints the fields
+/// as comma separated `name=value` pairs without any whitespace, e.g. `Status(a=1,b=2)`, which
+/// keeps log lines short. This is only supported for packed bitfields that derive `Debug`.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(debug = compact)]
+/// #[derive(Debug)]
+/// pub struct Status {
+///     a: B4,
+///     b: B4,
+/// }
+///
+/// let status = Status::new().with_a(1).with_b(2);
+/// assert_eq!(format!("{:?}", status), "Status(a=1,b=2)");
+/// ```
+///
+/// ## Parameter: `plain`
+///
+/// With `plain` a sibling struct named after the bitfield with a `Fields` suffix is generated.
+/// It has a public field for every bitfield field with getters and setters, stored without
+/// any bit packing. Conversions in both directions are provided via `From`, which makes it
+/// easy to read, pattern match and modify all fields at once. If the values of all fields
+/// implement `PartialEq` the bitfield and its sibling struct can also be compared field by field
+/// in both directions, e.g. `control == fields` in tests. Only structs with named fields
+/// are supported.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(plain)]
+/// pub struct Control {
+///     mode: B3,
+///     enabled: bool,
+///     divider: B4,
+/// }
+///
+/// let mut fields = ControlFields::from(Control::new().with_mode(2));
+/// fields.enabled = true;
+/// let control = Control::from(fields);
+/// assert_eq!(control.mode(), 2);
+/// assert!(control.enabled());
+/// assert!(control == ControlFields { mode: 2, enabled: true, divider: 0 });
+/// ```
+///
+/// ## Parameter: `borrowed_view`
+///
+/// With the `borrowed_view` flag a `IdentView<'a>` tuple struct wrapping a `&'a [u8; N]` is
+/// generated for a bitfield `Ident`. It has read-only `f()` and `f_or_err()` getters for every
+/// field `f` that read the field directly from the borrowed bytes. This allows to parse large
+/// arrays of packed records without copying every record into an owned bitfield first.
+/// Borrowed views are only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(borrowed_view)]
+/// pub struct Record {
+///     kind: B4,
+///     len: B12,
+/// }
+///
+/// let bytes = [0x21, 0x43, 0x65, 0x87];
+/// let lens = bytes
+///     .chunks_exact(Record::BYTES)
+///     .map(|chunk| RecordView(core::convert::TryInto::try_into(chunk).unwrap()).len())
+///     .collect::<Vec<_>>();
+/// assert_eq!(lens, vec![0x432, 0x876]);
+/// ```
+///
+/// ## Parameter: `rich_errors`
+///
+/// With the `rich_errors` flag the checked setters `set_f_checked` and `with_f_checked` return a
+/// `FieldOutOfBounds` error carrying the names of the bitfield and the field that failed instead
+/// of a plain `OutOfBounds`. This makes errors easier to trace when many fallible conversions are
+/// chained. `FieldOutOfBounds` converts into `OutOfBounds` for compatibility. Only supported for
+/// packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(rich_errors)]
+/// pub struct Config {
+///     mode: B3,
+///     level: B5,
+/// }
+///
+/// let error = Config::new().with_mode_checked(8).err().unwrap();
+/// assert_eq!(error.bitfield, "Config");
+/// assert_eq!(error.field, "mode");
+/// ```
+///
+/// ## Parameter: `by_value_getters`
+///
+/// With the `by_value_getters` flag the getters `f()`, `f_or_err()`, `get_f_checked()` and
+/// `get_f_raw()` take `self` by value instead of `&self`. This can be clearer for small bitfields
+/// and sometimes optimizes better. The bitfield must be `Copy`, e.g. via `#[derive(Clone, Copy)]`
+/// or the `clone_copy` parameter. Only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(by_value_getters)]
+/// #[derive(Clone, Copy)]
+/// pub struct Status {
+///     ready: bool,
+///     level: B7,
+/// }
+///
+/// let statuses = [Status::new().with_level(3), Status::new().with_ready(true)];
+/// assert_eq!(statuses.iter().filter(|status| status.ready()).count(), 1);
+/// ```
+///
+/// ## Parameter: `bcd`
+///
+/// With the `bcd` flag the bits of the fields are treated as binary coded decimal digits, one
+/// per nibble with the least significant digit in the lowest nibble, as is common for the
+/// registers of real-time clocks. The bitfield gets `add_bcd(other)` returning the sum with
+/// decimal carry between the digits together with whether the most significant digit carried
+/// over, as well as an `Add` impl discarding that carry. Every field must be a multiple of
+/// 4 bits wide. Only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(bcd)]
+/// pub struct Minutes {
+///     ones: B4,
+///     tens: B4,
+/// }
+///
+/// let (sum, carry) = Minutes::from_le_bytes([0x37]).add_bcd(Minutes::from_le_bytes([0x25]));
+/// assert_eq!((sum.tens(), sum.ones(), carry), (6, 2, false));
+/// let (sum, carry) = Minutes::from_le_bytes([0x99]).add_bcd(Minutes::from_le_bytes([0x01]));
+/// assert_eq!((sum.tens(), sum.ones(), carry), (0, 0, true));
+/// ```
+///
+/// ## Parameter: `endian = both`
+///
+/// With `endian = both` the big endian byte conversions `from_be_bytes`, `to_be_bytes`,
+/// `load_be_bytes` and `parse_be` are generated next to their little endian counterparts,
+/// together with `try_update_byte_le` to complete the `update_byte_*` family. The big endian
+/// bytes are the reverse of the little endian bytes. Unpacked bitfields already provide
+/// `from_be_bytes` and `to_be_bytes` and only gain the remaining methods.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(endian = both)]
+/// #[derive(Clone, Copy)]
+/// pub struct Word {
+///     low: B8,
+///     high: B8,
+/// }
+///
+/// let word = Word::new().with_low(0x34).with_high(0x12);
+/// assert_eq!(word.to_le_bytes(), [0x34, 0x12]);
+/// assert_eq!(word.to_be_bytes(), [0x12, 0x34]);
+/// assert_eq!(Word::from_be_bytes([0x12, 0x34]).low(), 0x34);
+/// ```
+///
+/// ## Parameter: `serde`
+///
+/// With the `serde` flag compact `Serialize` and `Deserialize` impls are generated which encode
+/// the bitfield as a tuple of its little endian bytes. Formats such as `postcard` encode this
+/// without a length prefix, so a bitfield takes exactly `BYTES` bytes on the wire. Deserializing
+/// fails for bytes with undefined bits set if the bitfield is not filled. Requires the `serde`
+/// crate feature and works in `no_std` environments.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(serde)]
+/// pub struct Header {
+///     kind: B4,
+///     len: B12,
+/// }
+///
+/// let mut buffer = [0u8; 2];
+/// let header = Header::new().with_kind(1).with_len(2);
+/// let encoded = postcard::to_slice(&header, &mut buffer).unwrap();
+/// assert_eq!(encoded, &[0x21, 0x00]);
+/// ```
+///
 /// ## Field Parameter: `#[bits = N]`
 ///
-/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of exactly
+/// To ensure at compile time that a field of a `#[bitfield]` struct has a bit width of
 /// `N` a user may add `#[bits = N]` to the field in question.
 ///
+/// If `N` is larger than the bit width of the field's type the field still occupies `N` bits.
+/// Only the lower bits are used by its getters and setters while the surplus upper bits are
+/// reserved and stay zero. A value of `N` smaller than the bit width of the type is an error.
+///
 /// ### Example
 ///
 /// ```
@@ -208,6 +1210,418 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Field Parameter: `#[reserved]`
+///
+/// Reserves the bits of a field without generating any accessors for it, just like `#[skip]`.
+/// Packed bitfields with reserved fields additionally get a `validate()` method which returns
+/// an error naming the first reserved field with a nonzero bit.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Register {
+///     enabled: bool,
+///     #[reserved]
+///     reserved: B7,
+/// }
+///
+/// assert!(Register::from_le_bytes([0x01]).validate().is_ok());
+/// assert!(Register::from_le_bytes([0x03]).validate().is_err());
+/// ```
+///
+/// ## Field Parameter: `#[units = "..."]`
+///
+/// Annotates a field with its physical units which are appended to its value in the
+/// `Debug` impl generated for `#[derive(Debug)]`, e.g. `freq: 48 Hz`. Only supported for
+/// packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(Debug)]
+/// pub struct Clock {
+///     #[units = "Hz"]
+///     freq: B12,
+///     enabled: bool,
+///     divider: B3,
+/// }
+///
+/// let clock = Clock::new().with_freq(48).with_enabled(true);
+/// assert_eq!(format!("{:?}", clock), "Clock { freq: 48 Hz, enabled: true, divider: 0 }");
+/// ```
+///
+/// ## Field Parameter: `#[checksum(over = a..c, algo = xor)]`
+///
+/// Declares a field holding the checksum of the fields from `a` up to, but excluding, `c`.
+/// The range may also be written as `a..=c` to include `c` or as `a..` to cover all fields
+/// from `a` up to and including the last field. The covered bits are split into chunks as
+/// wide as the checksum field and XOR-ed together. The bitfield gets a `set_checksum()` method
+/// recomputing the checksum field, and `validate()` reports a mismatching checksum as
+/// `ValidateError::ChecksumMismatch`. Only a single checksum field is supported and only for
+/// packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Header {
+///     kind: u8,
+///     length: u8,
+///     #[checksum(over = kind..crc, algo = xor)]
+///     crc: u8,
+/// }
+///
+/// let mut header = Header::new().with_kind(0x12).with_length(0x40);
+/// assert!(header.validate().is_err());
+/// header.set_checksum();
+/// assert_eq!(header.crc(), 0x52);
+/// assert!(header.validate().is_ok());
+/// ```
+///
+/// ## Field Parameter: `#[payload_len]`
+///
+/// Declares a field holding the length in bytes of a variable payload following the bitfield,
+/// as is common for protocol headers. The bitfield gets a `payload_len()` method returning the
+/// value of the field as `usize` and a `split_at(buffer)` function splitting a buffer into the
+/// bitfield, its payload and the remaining bytes. Only a single `#[payload_len]` field is
+/// supported and only for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Header {
+///     kind: B4,
+///     #[payload_len]
+///     len: B12,
+/// }
+///
+/// let buffer = [0x21, 0x00, 0xAA, 0xBB, 0xCC];
+/// let (header, payload, rest) = Header::split_at(&buffer).unwrap();
+/// assert_eq!(header.kind(), 1);
+/// assert_eq!(header.payload_len(), 2);
+/// assert_eq!(payload, &[0xAA, 0xBB]);
+/// assert_eq!(rest, &[0xCC]);
+/// ```
+///
+/// ## Field Parameter: `#[extract_as(Name)]`
+///
+/// Generates a standalone type `Name` for the field so that a frequently reused sub-field can
+/// be passed around on its own. The type is created via `Name::new(value)` or
+/// `Name::from_bits(bits)`, returns its value via `get()` and implements `Specifier` so that
+/// it can itself be used as the type of a field. The bitfield gets `extract_{field}()`
+/// returning the field as `Name` as well as `set_{field}_extracted(..)` and
+/// `with_{field}_extracted(..)` setting it from one. Only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Header {
+///     #[extract_as(Priority)]
+///     priority: B4,
+///     kind: B4,
+/// }
+///
+/// let header = Header::new().with_priority(7);
+/// let priority: Priority = header.extract_priority();
+/// assert_eq!(priority.get(), 7);
+/// let header = header.with_priority_extracted(Priority::new(3));
+/// assert_eq!(header.priority(), 3);
+/// ```
+///
+/// ## Field Parameter: `#[const_enum]`
+///
+/// Generates a `const fn get_{field}_const()` getter for a field whose type is an enum deriving
+/// `BitfieldSpecifier`, returning `None` for invalid bit patterns. This allows reading enum
+/// fields of bitfields in constant contexts such as lookup tables. Only supported for packed
+/// bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 2]
+/// pub enum Mode {
+///     A = 0,
+///     B = 1,
+///     C = 2,
+/// }
+///
+/// #[bitfield]
+/// pub struct Entry {
+///     #[const_enum]
+///     mode: Mode,
+///     value: B6,
+/// }
+///
+/// const MODE: Option<Mode> = Entry::from_le_bytes([0b0000_0010]).get_mode_const();
+/// assert_eq!(MODE, Some(Mode::C));
+/// ```
+///
+/// ## Field Parameter: `#[non_copy]`
+///
+/// Marks a field of an unpacked bitfield whose specifier has an `InOut` type that is `Clone`
+/// but not `Copy`. The getter of the field returns a clone of the stored value and is therefore
+/// not a `const fn` unlike the getters of all other fields. Only supported for unpacked
+/// bitfields since packed bitfields decode their field values on every access.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use modular_bitfield::error::{InvalidBitPattern, OutOfBounds};
+/// #[derive(Debug, Clone, PartialEq)]
+/// pub struct Level(u8);
+///
+/// pub enum LevelSpecifier {}
+///
+/// impl Specifier for LevelSpecifier {
+///     const BITS: usize = 4;
+///     type Bytes = u8;
+///     type InOut = Level;
+///
+///     fn into_bytes(input: Level) -> Result<u8, OutOfBounds> {
+///         Ok(input.0)
+///     }
+///
+///     fn from_bytes(bytes: u8) -> Result<Level, InvalidBitPattern<u8>> {
+///         Ok(Level(bytes))
+///     }
+/// }
+///
+/// #[bitfield(bits = 8, packed = false)]
+/// #[derive(Clone)]
+/// pub struct Settings {
+///     #[non_copy]
+///     level: LevelSpecifier,
+///     extra: B4,
+/// }
+///
+/// let settings = Settings::new().with_level(Level(9));
+/// assert_eq!(settings.clone().level(), Level(9));
+/// ```
+///
+/// ## Field Parameter: `#[magic = N]`
+///
+/// Marks a field as holding a magic constant. `new()` initializes the field to the magic value
+/// and the generated `validate()` method returns `ValidateError::MagicMismatch` if the field
+/// holds anything else. The magic value must fit into the bits of the field. Only supported
+/// for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Header {
+///     #[magic = 0xAB]
+///     magic: B8,
+///     version: B8,
+/// }
+///
+/// assert_eq!(Header::new().magic(), 0xAB);
+/// assert!(Header::new().validate().is_ok());
+/// assert!(Header::from_le_bytes([0xAC, 0x01]).validate().is_err());
+/// ```
+///
+/// ## Field Parameter: `#[sentinel = N]`
+///
+/// Marks the raw value `N` of a field as "unset". In addition to the usual accessors the field
+/// `f` gets a `get_f()` getter returning `None` if the field holds the sentinel value as well as
+/// `set_f_opt(value)` and `with_f_opt(value)` setters taking an `Option` which write the sentinel
+/// value for `None`. These setters panic for `Some` of the sentinel value while their
+/// `set_f_opt_checked(value)` and `with_f_opt_checked(value)` variants return an error instead.
+/// The sentinel value must fit into the bits of the field. Only supported for named fields of
+/// packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Slot {
+///     #[sentinel = 0xF]
+///     channel: B4,
+///     volume: B4,
+/// }
+///
+/// let slot = Slot::new().with_channel_opt(None);
+/// assert_eq!(slot.get_channel(), None);
+/// assert_eq!(slot.channel(), 0xF);
+/// assert_eq!(slot.with_channel_opt(Some(3)).get_channel(), Some(3));
+/// assert!(Slot::new().with_channel_opt_checked(Some(0xF)).is_err());
+/// ```
+///
+/// ## Field Parameter: `#[tag]`
+///
+/// Marks a field whose enum value discriminates the interpretation of the remaining fields.
+/// The bitfield gets a `tag()` getter for the field, `check_tag()` returning the bitfield only
+/// if the tag holds a valid bit pattern, e.g. right after constructing it from untrusted bytes,
+/// and `match_tag(f)` calling `f` with the tag for an exhaustive `match` without panicking.
+/// At most one field can be marked as `#[tag]`. Only supported for named fields of packed
+/// bitfields.
+///
+/// Validating the tag is opt-in: the constructors such as `new()` and `from_le_bytes(bytes)`
+/// accept any bit pattern of the tag field, including ones that are not a variant of its
+/// enum, so `tag()` panics for such bitfields. Chain `check_tag()` onto the construction of
+/// bitfields from untrusted bytes or use `match_tag(f)` which reports invalid tags instead.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier, Debug, PartialEq)]
+/// #[bits = 2]
+/// pub enum Kind {
+///     Data = 0,
+///     Ack = 1,
+///     Nack = 2,
+/// }
+///
+/// #[bitfield]
+/// pub struct Frame {
+///     #[tag]
+///     kind: Kind,
+///     payload: B6,
+/// }
+///
+/// let frame = Frame::from_le_bytes([0b0000_0101]).check_tag().unwrap();
+/// assert_eq!(frame.tag(), Kind::Ack);
+/// let ack = frame.match_tag(|kind| match kind {
+///     Kind::Data => false,
+///     Kind::Ack => true,
+///     Kind::Nack => false,
+/// });
+/// assert_eq!(ack, Ok(true));
+/// assert!(Frame::from_le_bytes([0b0000_0011]).check_tag().is_err());
+/// ```
+///
+/// ## Field Parameter: `#[variants(A, B)]`
+///
+/// Generates an `is_a()` predicate for every listed variant `A` of a field whose type is an
+/// enum deriving `BitfieldSpecifier`, where `a` is the `snake_case` form of `A`. The predicates
+/// return `false` for invalid bit patterns instead of panicking. Since the predicates are named
+/// after the variants only, the variants listed for different fields must not overlap. Only
+/// supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[derive(BitfieldSpecifier)]
+/// #[bits = 2]
+/// pub enum Mode {
+///     Disabled = 0,
+///     Enabled = 1,
+///     LowPower = 2,
+/// }
+///
+/// #[bitfield]
+/// pub struct Control {
+///     #[variants(Disabled, Enabled, LowPower)]
+///     mode: Mode,
+///     level: B6,
+/// }
+///
+/// let control = Control::new().with_mode(Mode::LowPower);
+/// assert!(control.is_low_power());
+/// assert!(!control.is_enabled());
+/// ```
+///
+/// ## Field Parameter: `#[hidden]`
+///
+/// Marks all generated getters and setters of the field with `#[doc(hidden)]`.
+/// This is useful for internal or reserved fields that still need to be accessible
+/// but should not show up in the documentation.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Status {
+///     ready: bool,
+///     #[hidden]
+///     reserved: B7,
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[deprecated]`
+///
+/// Marks all generated getters and setters of the field as `#[deprecated]`, e.g. to migrate
+/// users of a register definition to a renamed field. Only uses of the accessors by the user
+/// trigger the deprecation warning, not the code generated by the `#[bitfield]` macro.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Control {
+///     #[deprecated = "use `mode` instead"]
+///     old_mode: B4,
+///     mode: B4,
+/// }
+/// ```
+///
+/// ## Field Parameter: `#[group(name)]`
+///
+/// Adjacent fields annotated with the same `#[group(name)]` attribute get an additional
+/// getter `name()` returning the values of all fields of the group as a tuple in
+/// declaration order. This is convenient for logically related fields such as coordinates.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Cursor {
+///     #[group(position)]
+///     x: B12,
+///     #[group(position)]
+///     y: B12,
+///     visible: bool,
+///     blink: B7,
+/// }
+///
+/// let cursor = Cursor::new().with_x(640).with_y(480);
+/// assert_eq!(cursor.position(), (640, 480));
+/// ```
+///
+/// ## Field Parameter: `#[eq(ignore)]`
+///
+/// If any field is annotated with `#[eq(ignore)]` a `PartialEq` impl is generated that compares
+/// the bits of the bitfields while ignoring the bits of all annotated fields. This is useful to
+/// compare register snapshots that contain volatile fields such as free-running counters.
+/// The bitfield must not also derive `PartialEq` in this case. A derived `Hash` of a packed
+/// bitfield ignores the bits of the annotated fields as well.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// pub struct Snapshot {
+///     enabled: bool,
+///     mode: B3,
+///     #[eq(ignore)]
+///     counter: B12,
+/// }
+///
+/// let first = Snapshot::new().with_mode(2).with_counter(10);
+/// let second = Snapshot::new().with_mode(2).with_counter(20);
+/// assert!(first == second);
+/// ```
+///
 /// # Features
 ///
 /// ## Support: `#[derive(BitfieldSpecifier)]`
@@ -280,6 +1694,54 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// );
 /// ```
 ///
+/// ## Support: `#[derive(defmt::Format)]`
+///
+/// With the `defmt` crate feature enabled a `#[derive(defmt::Format)]` or `#[derive(Format)]`
+/// found by the `#[bitfield]` generates a `defmt::Format` implementation for packed bitfields
+/// that formats all the fields by name just like the `Debug` implementation above.
+/// The user crate needs to depend on `defmt` itself since the implementation is based on
+/// the `defmt::write!` macro.
+///
+/// ### Example
+///
+/// ```ignore
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield]
+/// #[derive(defmt::Format)]
+/// pub struct Package {
+///     is_received: bool, // 1 bit
+///     is_alive: bool,    // 1 bit
+///     status: B6,        // 6 bits
+/// }
+///
+/// defmt::info!("{}", Package::new().with_status(3));
+/// ```
+///
+/// ## Support: `#[derive(Hash)]`
+///
+/// A `#[derive(Hash)]` found by the `#[bitfield]` generates a `Hash` implementation for packed
+/// bitfields that hashes the underlying bytes directly. Bits beyond the bit width of the
+/// bitfield and bits of `#[eq(ignore)]` fields are masked out so that the hash agrees with
+/// `PartialEq`. Only if no bits are masked out does it also agree with the `Hash` impl of `[u8]`.
+/// Together with a derived `PartialEq` and `Eq` this allows using bitfields as `HashMap` keys.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// # use std::collections::HashMap;
+/// #[bitfield]
+/// #[derive(Hash, PartialEq, Eq)]
+/// pub struct Key {
+///     kind: B4,
+///     id: B12,
+/// }
+///
+/// let mut names = HashMap::new();
+/// names.insert(Key::new().with_id(42), "answer");
+/// assert_eq!(names.get(&Key::new().with_id(42)), Some(&"answer"));
+/// ```
+///
 /// ## Support: `#[repr(uN)]`
 ///
 /// It is possible to additionally annotate a `#[bitfield]` annotated struct with `#[repr(uN)]`
@@ -288,7 +1750,18 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 ///
 /// As an effect to the user this implements `From` implementations between the chosen primitive
 /// and the bitfield as well as ensuring at compile time that the bit width of the bitfield struct
-/// matches the bit width of the primitive.
+/// matches the bit width of the primitive. With the `widening_from` flag the bitfield
+/// additionally converts into all wider unsigned integers, e.g. a `#[repr(u16)]` bitfield
+/// into `u32`, `u64` and `u128`. The flag is opt-in so that users are free to implement
+/// these conversions themselves.
+///
+/// Furthermore the `f()` getters of fields with primitive specifiers such as `bool`, `u8`
+/// or `B1` up to `B128` are `const fn`s reading directly from the underlying integer.
+///
+/// Unfilled `packed = false` bitfields may use a `#[repr(uN)]` wider than their `bits = N`.
+/// Their `from_le_bytes` and `to_le_bytes` arrays always have `size_of::<uN>()` bytes and
+/// `from_le_bytes` rejects bytes with bits set beyond `bits = N`. Without a `#[repr(uN)]` the
+/// arrays only cover the bytes of `bits = N`, e.g. 3 bytes for `bits = 24`.
 ///
 /// ### Example
 ///
@@ -306,6 +1779,51 @@ pub fn define_specifiers(input: TokenStream) -> TokenStream {
 /// assert_eq!(sint.abs_value(), 0b0011_1000);
 /// assert_eq!(u16::from(sint), 0b0111_0001_u16);
 /// ```
+///
+/// ## Parameter: `repr = [u8; N]`
+///
+/// Bitfields that are wider than any of the unsigned integer types, or that simply
+/// are not meant to be interpreted as an integer, may use `#[bitfield(repr = [u8; N])]`
+/// instead. This implements `From` between `[u8; N]` and the bitfield and checks at
+/// compile time that the bitfield spans exactly `N` bytes.
+///
+/// This is only supported for packed bitfields.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(repr = [u8; 12])]
+/// pub struct Timestamp {
+///     seconds: B64,
+///     nanos: B32,
+/// }
+///
+/// let timestamp = Timestamp::from([1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0]);
+/// assert_eq!(timestamp.seconds(), 1);
+/// assert_eq!(timestamp.nanos(), 2);
+/// assert_eq!(<[u8; 12]>::from(timestamp)[8], 2);
+/// ```
+///
+/// ## Parameter: `repr = bool`
+///
+/// Single-bit bitfields may use `#[bitfield(repr = bool)]` to implement `From` between
+/// `bool` and the bitfield. It is checked at compile time that the bitfield spans exactly
+/// one bit. Such bitfields are implicitly `filled = false` and only supported when packed.
+///
+/// ### Example
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #[bitfield(repr = bool)]
+/// pub struct Enabled {
+///     enabled: bool,
+/// }
+///
+/// let enabled = Enabled::from(true);
+/// assert!(enabled.enabled());
+/// assert!(bool::from(enabled));
+/// ```
 #[proc_macro_attribute]
 pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
     bitfield::analyse_and_expand(args.into(), input.into()).into()
@@ -373,6 +1891,45 @@ pub fn bitfield(args: TokenStream, input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// Every discriminant must fit into the declared number of bits, otherwise
+/// compilation fails with an error naming the offending variant:
+///
+/// ```compile_fail
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier)]
+/// #[bits = 2]
+/// pub enum Priority {
+///     Low = 0,
+///     High = 4, // error: does not fit into 2 bits
+/// }
+/// ```
+///
+/// ## Example: Default Variant
+///
+/// If the enum also derives `Default` the `new()` constructor of a `#[bitfield]`
+/// initializes fields of the enum to its `#[default]` variant instead of the
+/// variant with the zero discriminant:
+///
+/// ```
+/// # use modular_bitfield::prelude::*;
+/// #
+/// #[derive(BitfieldSpecifier, Default, Debug, PartialEq)]
+/// pub enum Power {
+///     Off = 0,
+///     #[default]
+///     On = 1,
+/// }
+///
+/// #[bitfield]
+/// pub struct Device {
+///     power: Power,
+///     level: B7,
+/// }
+///
+/// assert_eq!(Device::new().power(), Power::On);
+/// ```
+///
 /// ## Example: Use in `#[bitfield]`
 ///
 /// Given the above `Weekday` enum that starts at `Sunday` and uses 3 bits in total
@@ -1,6 +1,11 @@
 //! Errors that can occure while operating on modular bitfields.
+//!
+//! All error types only depend on `core` and implement `core::fmt::Display`.
+//! With the `std` crate feature they additionally implement `std::error::Error`.
 
 use core::fmt::Debug;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 /// The given value was out of range for the bitfield.
 #[derive(Debug, PartialEq, Eq)]
@@ -12,6 +17,57 @@ impl core::fmt::Display for OutOfBounds {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBounds {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for OutOfBounds {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "encountered an out of bounds value")
+    }
+}
+
+/// The given value was out of range for a field of a bitfield with the `rich_errors` parameter.
+///
+/// Carries the names of the bitfield and the field that failed and converts into
+/// [`OutOfBounds`] for compatibility.
+#[derive(Debug, PartialEq, Eq)]
+pub struct FieldOutOfBounds {
+    pub bitfield: &'static str,
+    pub field: &'static str,
+}
+
+impl From<FieldOutOfBounds> for OutOfBounds {
+    fn from(_: FieldOutOfBounds) -> Self {
+        Self
+    }
+}
+
+impl core::fmt::Display for FieldOutOfBounds {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "encountered an out of bounds value for field `{}.{}`",
+            self.bitfield, self.field
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldOutOfBounds {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for FieldOutOfBounds {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "encountered an out of bounds value for field `{}.{}`",
+            self.bitfield,
+            self.field
+        )
+    }
+}
+
 /// The bitfield contained an invalid bit pattern.
 #[derive(Debug, PartialEq, Eq)]
 pub struct InvalidBitPattern<Bytes> {
@@ -31,6 +87,27 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl<Bytes> std::error::Error for InvalidBitPattern<Bytes>
+where
+    Bytes: Debug,
+{
+}
+
+#[cfg(feature = "defmt")]
+impl<Bytes> defmt::Format for InvalidBitPattern<Bytes>
+where
+    Bytes: defmt::Format,
+{
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "encountered an invalid bit pattern: {:X}",
+            self.invalid_bytes
+        )
+    }
+}
+
 impl<Bytes> InvalidBitPattern<Bytes> {
     /// Creates a new invalid bit pattern error.
     #[inline]
@@ -44,3 +121,197 @@ impl<Bytes> InvalidBitPattern<Bytes> {
         self.invalid_bytes
     }
 }
+
+/// A field marked `#[reserved]` contained bits other than zero.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NonZeroReserved {
+    pub field: &'static str,
+}
+
+impl core::fmt::Display for NonZeroReserved {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "encountered nonzero bits in reserved field `{}`", self.field)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonZeroReserved {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for NonZeroReserved {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "encountered nonzero bits in reserved field `{}`", self.field)
+    }
+}
+
+/// A bitfield with a `#[checksum(..)]` or `#[magic = N]` field failed its validation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ValidateError {
+    /// A field marked `#[reserved]` contained bits other than zero.
+    NonZeroReserved(NonZeroReserved),
+    /// The named `#[checksum(..)]` field did not match the checksum of the bits it covers.
+    ChecksumMismatch {
+        field: &'static str,
+        expected: u128,
+        found: u128,
+    },
+    /// The named `#[magic = N]` field did not contain its magic value.
+    MagicMismatch {
+        field: &'static str,
+        expected: u128,
+        found: u128,
+    },
+}
+
+impl From<NonZeroReserved> for ValidateError {
+    fn from(error: NonZeroReserved) -> Self {
+        Self::NonZeroReserved(error)
+    }
+}
+
+impl core::fmt::Display for ValidateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NonZeroReserved(error) => core::fmt::Display::fmt(error, f),
+            Self::ChecksumMismatch { field, expected, found } => {
+                write!(
+                    f,
+                    "encountered checksum {:#x} in field `{}` but expected {:#x}",
+                    found, field, expected
+                )
+            }
+            Self::MagicMismatch { field, expected, found } => {
+                write!(
+                    f,
+                    "encountered {:#x} in magic field `{}` but expected {:#x}",
+                    found, field, expected
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::NonZeroReserved(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ValidateError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Self::NonZeroReserved(error) => defmt::Format::format(error, f),
+            Self::ChecksumMismatch { field, expected, found } => {
+                defmt::write!(
+                    f,
+                    "encountered checksum {=u128:#x} in field `{}` but expected {=u128:#x}",
+                    found,
+                    field,
+                    expected
+                )
+            }
+            Self::MagicMismatch { field, expected, found } => {
+                defmt::write!(
+                    f,
+                    "encountered {=u128:#x} in magic field `{}` but expected {=u128:#x}",
+                    found,
+                    field,
+                    expected
+                )
+            }
+        }
+    }
+}
+
+/// A bitfield could not be parsed from a string of `field=value` pairs.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseFieldsError {
+    /// A pair was not of the form `field=value`.
+    InvalidSyntax,
+    /// The bitfield has no settable field with the given name.
+    UnknownField,
+    /// The value given for the named field was neither an integer nor a `bool`.
+    InvalidValue(&'static str),
+    /// The value given for the named field was out of bounds.
+    OutOfBounds(&'static str),
+    /// The value given for the named field was an invalid bit pattern.
+    InvalidBitPattern(&'static str),
+}
+
+impl core::fmt::Display for ParseFieldsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::InvalidSyntax => write!(f, "encountered a pair not of the form `field=value`"),
+            Self::UnknownField => write!(f, "encountered an unknown field"),
+            Self::InvalidValue(name) => write!(f, "encountered an invalid value for field `{}`", name),
+            Self::OutOfBounds(name) => {
+                write!(f, "encountered an out of bounds value for field `{}`", name)
+            }
+            Self::InvalidBitPattern(name) => {
+                write!(f, "encountered an invalid bit pattern for field `{}`", name)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFieldsError {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ParseFieldsError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Self::InvalidSyntax => defmt::write!(f, "encountered a pair not of the form `field=value`"),
+            Self::UnknownField => defmt::write!(f, "encountered an unknown field"),
+            Self::InvalidValue(name) => {
+                defmt::write!(f, "encountered an invalid value for field `{}`", name)
+            }
+            Self::OutOfBounds(name) => {
+                defmt::write!(f, "encountered an out of bounds value for field `{}`", name)
+            }
+            Self::InvalidBitPattern(name) => {
+                defmt::write!(f, "encountered an invalid bit pattern for field `{}`", name)
+            }
+        }
+    }
+}
+
+/// A field could not be set from a map of field names and values or bytes.
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq, Eq)]
+pub enum FieldError {
+    /// The bitfield has no settable field with the given name.
+    UnknownField(String),
+    /// The value given for the named field was out of bounds.
+    OutOfBounds(&'static str),
+    /// The value given for the named field was an invalid bit pattern.
+    InvalidBitPattern(&'static str),
+    /// The given bytes set bits beyond the bit width of the bitfield.
+    UndefinedBits,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnknownField(name) => write!(f, "encountered an unknown field `{}`", name),
+            Self::OutOfBounds(name) => {
+                write!(f, "encountered an out of bounds value for field `{}`", name)
+            }
+            Self::InvalidBitPattern(name) => {
+                write!(f, "encountered an invalid bit pattern for field `{}`", name)
+            }
+            Self::UndefinedBits => {
+                write!(f, "encountered bits beyond the bit width of the bitfield")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
@@ -5,6 +5,7 @@
 //! within bitfield structs.
 //!
 //! There are preset bitfield specifiers such as `B1`, `B2`,..,`B64`
+//! as well as `U24` and `U48` for common non power of two byte widths
 //! that allow for easy bitfield usage in structs very similar to how
 //! they work in C or C++.
 //!
@@ -355,6 +356,14 @@
 //! | `fn new() -> Self` | Creates a new instance of the bitfield with all bits initialized to 0. |
 //! | `fn from_bytes([u8; 1]) -> Self` | Creates a new instance of the bitfield from the given raw bytes. |
 //! | `fn into_bytes(self) -> [u8; 1]` | Returns the underlying bytes of the bitfield. |
+//! | `const BYTES: usize` | The number of underlying bytes of the bitfield, also returned by `const fn bytes_len()`. |
+//! | `fn swap_bytes(self) -> Self` | Returns the bitfield with the order of its underlying bytes reversed. |
+//! | `fn from_le_bytes_at(&[u8], usize) -> Result<Self, OutOfBounds>` | Creates a new instance of the bitfield from a buffer starting at the given bit offset. |
+//! | `fn rotate_left(self, n: u32) -> Self` | Rotates the bits left within the bit width, also available as `rotate_right`. |
+//! | `fn reverse_bits(self) -> Self` | Reverses the order of the bits within the bit width. |
+//! | `fn to_u32_words(self) -> [u32; 1]` | Returns the underlying bytes as little endian `u32` words, inverted by `from_u32_words`. |
+//! | `fn from_field_map(&HashMap<&str, u128>) -> Result<Self, FieldError>` | Creates a new instance of the bitfield from the named raw field values. Requires the `std` feature. |
+//! | `fn try_from_le_bytes_all([u8; 1]) -> Result<Self, Vec<FieldError>>` | Creates a new instance of the bitfield from the given raw bytes, collecting the errors of all invalid fields. Requires the `alloc` feature. |
 //!
 //! And below the generated signatures for field `a`:
 //!
@@ -362,10 +371,15 @@
 //! |:--|:--|
 //! | `fn a() -> bool` | Returns the value of `a` or panics if invalid. |
 //! | `fn a_or_err() -> Result<bool, InvalidBitPattern<u8>>` | Returns the value of `a` of an error providing information about the invalid bits. |
+//! | `fn get_a_raw() -> u8` | Returns the raw bits of `a` without validating them. |
+//! | `fn get_a_checked() -> Option<bool>` | Returns the value of `a` or `None` if invalid. |
 //! | `fn set_a(&mut self, new_value: bool)` | Sets `a` to the new value or panics if `new_value` contains invalid bits. |
 //! | `fn set_a_checked(&mut self, new_value: bool) -> Result<(), OutOfBounds>` | Sets `a` to the new value of returns an out of bounds error. |
 //! | `fn with_a(self, new_value: bool) -> Self` | Similar to `set_a` but useful for method chaining. |
 //! | `fn with_a_checked(self, new_value: bool) -> Result<Self, OutOfBounds>` | Similar to `set_a_checked` but useful for method chaining. |
+//! | `fn set_a_if_changed(&mut self, new_value: bool) -> bool` | Sets `a` to the new value if it differs and returns whether `a` changed. |
+//! | `fn set_a_raw(&mut self, bits: u8)` | Sets the raw bits of `a` masked to its bit width without validating them. |
+//! | `fn modify_a(&mut self, f: impl FnOnce(bool) -> bool)` | Replaces `a` with the result of applying `f` to its current value. |
 //!
 //! ## Generated Structure
 //!
@@ -416,6 +430,10 @@
 #![forbid(unsafe_code)]
 
 extern crate static_assertions;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod error;
 #[doc(hidden)]
@@ -448,12 +466,36 @@ pub mod prelude {
 /// # Note
 ///
 /// These can be all unsigned fixed-size primitives,
-/// represented by `B1, B2, ... B64` and enums that
-/// derive from `BitfieldSpecifier`.
+/// represented by `B1, B2, ... B64`, enums that
+/// derive from `BitfieldSpecifier`, tuples `(A, B)`
+/// of two specifiers with at most 128 bits in total,
+/// arrays `[T; N]` of specifiers with at most 128 bits in total,
+/// `core::net::Ipv4Addr` with 32 bits and `core::cmp::Ordering` with 2 bits.
+///
+/// Raw byte arrays `[u8; N]` such as MAC addresses are arrays of specifiers
+/// as well and occupy `N * 8` bits with the first byte in the lowest bits.
 pub trait Specifier {
     /// The amount of bits used by the specifier.
     const BITS: usize;
 
+    /// The raw bits of the value `new()` initializes fields of the specifier to.
+    ///
+    /// This is zero unless the specifier is an enum deriving `BitfieldSpecifier` together
+    /// with `Default` whose `#[default]` variant has a non-zero discriminant.
+    const DEFAULT_BITS: u128 = 0;
+
+    /// The raw bits of the maximum valid value of the specifier.
+    ///
+    /// This has all bits set unless the specifier is an enum deriving `BitfieldSpecifier`
+    /// for which it is the largest discriminant. Specifiers with invalid bit patterns such
+    /// as `core::cmp::Ordering` override it and tuples and arrays combine the values of their
+    /// elements. Used by the `max_const` parameter.
+    const MAX_BITS: u128 = if Self::BITS >= 128 {
+        u128::MAX
+    } else {
+        (1 << Self::BITS) - 1
+    };
+
     /// The base type of the specifier.
     ///
     /// # Note
@@ -490,7 +532,216 @@ pub trait Specifier {
     ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>>;
 }
 
+/// Packs the given value into the raw bits of the specifier `T`.
+///
+/// This is the packing routine used by the generated setters, usable standalone
+/// outside of a `#[bitfield]` struct.
+///
+/// # Errors
+///
+/// If `value` does not fit into the `T::BITS` bits of the specifier.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::{prelude::*, error::OutOfBounds};
+///
+/// assert_eq!(modular_bitfield::pack::<B5>(0b1_0110), Ok(0b1_0110));
+/// assert_eq!(modular_bitfield::pack::<B5>(0b10_0000), Err(OutOfBounds));
+/// ```
+#[inline]
+pub fn pack<T>(value: T::InOut) -> Result<T::Bytes, OutOfBounds>
+where
+    T: Specifier,
+{
+    <T as Specifier>::into_bytes(value)
+}
+
+/// Unpacks the given raw bits of the specifier `T` into its in-out type.
+///
+/// This is the unpacking routine used by the generated getters, usable standalone
+/// outside of a `#[bitfield]` struct.
+///
+/// # Errors
+///
+/// If `bytes` is an invalid bit pattern for the specifier.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// assert_eq!(modular_bitfield::unpack::<B5>(0b1_0110), Ok(0b1_0110));
+/// assert!(modular_bitfield::unpack::<B5>(0b10_0000).is_err());
+/// ```
+#[inline]
+pub fn unpack<T>(bytes: T::Bytes) -> Result<T::InOut, InvalidBitPattern<T::Bytes>>
+where
+    T: Specifier,
+{
+    <T as Specifier>::from_bytes(bytes)
+}
+
+/// Asserts at compile time that the fields of a `#[bitfield]` struct occupy the given bit ranges.
+///
+/// Bit ranges are given as `start..end` relative to the least significant bit of the
+/// bitfield. Fields of tuple structs are referred to by their index. This helps to lock
+/// down a layout against accidental reordering or resizing of its fields.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// #[bitfield]
+/// pub struct Header {
+///     kind: B4,
+///     flags: B4,
+///     length: B8,
+/// }
+///
+/// modular_bitfield::assert_bitfield_layout!(Header, kind: 0..4, flags: 4..8, length: 8..16);
+/// ```
+#[macro_export]
+macro_rules! assert_bitfield_layout {
+    ( $ty:ty, $( $field:tt : $start:literal .. $end:literal ),+ $(,)? ) => {
+        const _: () = {
+            $(
+                ::core::assert!(
+                    $crate::private::layout::field_has_range(
+                        <$ty>::__BF_LAYOUT,
+                        ::core::stringify!($field),
+                        $start,
+                        $end,
+                    ),
+                    ::core::concat!(
+                        "field `", ::core::stringify!($field), "` of `", ::core::stringify!($ty),
+                        "` does not occupy bits ", ::core::stringify!($start), "..", ::core::stringify!($end),
+                    ),
+                );
+            )+
+        };
+    };
+}
+
+/// Asserts at compile time that two `#[bitfield]` structs have identical layouts.
+///
+/// Both bitfields must consist of fields with the same names occupying the same bit ranges
+/// in the same order. This helps to keep a new definition of a register or packet
+/// compatible with the one it replaces.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// #[bitfield]
+/// pub struct OldHeader {
+///     kind: B4,
+///     length: B12,
+/// }
+///
+/// #[bitfield]
+/// #[repr(u16)]
+/// pub struct NewHeader {
+///     kind: B4,
+///     length: B12,
+/// }
+///
+/// modular_bitfield::assert_same_layout!(OldHeader, NewHeader);
+/// ```
+#[macro_export]
+macro_rules! assert_same_layout {
+    ( $a:ty, $b:ty $(,)? ) => {
+        const _: () = ::core::assert!(
+            $crate::private::layout::same_layout(<$a>::__BF_LAYOUT, <$b>::__BF_LAYOUT),
+            ::core::concat!(
+                "bitfields `", ::core::stringify!($a), "` and `", ::core::stringify!($b),
+                "` have different layouts",
+            ),
+        );
+    };
+}
+
+/// Evaluates to the number of bits occupied by a field of a `#[bitfield]` struct.
+///
+/// The field is given as `Type::field`, or as `<path::to::Type>::field` for qualified
+/// types, and fields of tuple structs are referred to by their index. The result is a
+/// `usize` constant that can be used in constant contexts, e.g. to parameterize generic
+/// code on the width of a field.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// #[bitfield]
+/// pub struct Color {
+///     r: B8,
+///     g: B8,
+///     b: B8,
+///     a: B8,
+/// }
+///
+/// const R_BITS: usize = modular_bitfield::field_bits!(Color::r);
+/// assert_eq!(R_BITS, 8);
+/// ```
+#[macro_export]
+macro_rules! field_bits {
+    ( $ty:ident :: $field:tt ) => {
+        $crate::field_bits!(<$ty>::$field)
+    };
+    ( < $ty:ty > :: $field:tt ) => {{
+        const BITS: ::core::primitive::usize = $crate::private::layout::field_bits(
+            <$ty>::__BF_LAYOUT,
+            ::core::stringify!($field),
+        );
+        BITS
+    }};
+}
+
+/// Evaluates to the union of the masks of the given fields of a `#[bitfield]` struct.
+///
+/// The bitfield must have a `#[repr(uN)]` and the result is a constant of that integer type,
+/// e.g. to be used with the generated `contains` or `masked` methods. Fields of tuple structs
+/// are referred to by their index and unknown fields are rejected at compile time.
+///
+/// # Example
+///
+/// ```
+/// use modular_bitfield::prelude::*;
+///
+/// #[bitfield]
+/// #[repr(u16)]
+/// pub struct Status {
+///     ready: bool,
+///     error: bool,
+///     code: B6,
+///     count: B8,
+/// }
+///
+/// const READY_OR_CODE: u16 = modular_bitfield::field_mask!(Status, ready, code);
+/// assert_eq!(READY_OR_CODE, 0b1111_1101);
+/// assert_eq!(Status::CODE_MASK, 0b1111_1100);
+/// ```
+#[macro_export]
+macro_rules! field_mask {
+    ( $ty:ty, $( $field:tt ),+ $(,)? ) => {
+        const { 0 $( | <$ty>::__bf_field_mask(::core::stringify!($field)) )+ }
+    };
+}
+
 /// The default set of predefined specifiers.
 pub mod specifiers {
     ::modular_bitfield_impl::define_specifiers!();
+
+    /// Specifier for 24-bit values, e.g. audio samples, with `u32` as in-out type.
+    ///
+    /// Values exceeding 24 bits are rejected just like for [`B24`].
+    pub type U24 = B24;
+
+    /// Specifier for 48-bit values, e.g. MAC addresses or timestamps, with `u64` as in-out type.
+    ///
+    /// Values exceeding 48 bits are rejected just like for [`B48`].
+    pub type U48 = B48;
 }
@@ -80,10 +80,6 @@ where
     type Size: RenameSizeType;
 }
 
-/// Helper trait to check if an enum discriminant of a bitfield specifier
-/// is within valid bounds.
-pub trait DiscriminantInRange: private::Sealed {}
-
 /// Helper trait to check if a `#[derive(BitfieldSpecifier)]` flagged bitfield
 /// requires
 /// at most 128 bits.
@@ -104,7 +100,6 @@ pub enum True {}
 pub enum False {}
 
 impl private::Sealed for True {}
-impl DiscriminantInRange for True {}
 impl SpecifierHasAtMost128Bits for True {}
 impl FillsUnalignedBits for True {}
 impl DoesNotFillUnalignedBits for True {}
@@ -124,16 +119,6 @@ impl DispatchTrueFalse for [(); 1] {
     type Out = True;
 }
 
-/// Public facing trait that is implemented by bitfield specifiers to
-/// let the compiler check if all its variant discriminants are within
-/// valid bounds.
-pub trait CheckDiscriminantInRange<A>
-where
-    <Self::CheckType as DispatchTrueFalse>::Out: DiscriminantInRange,
-{
-    type CheckType: DispatchTrueFalse;
-}
-
 /// Traits to check at compile-time if a `#[derive(BitfieldSpecifier)]` type requires
 /// no more than 128 bits.
 pub trait CheckSpecifierHasAtMost128Bits
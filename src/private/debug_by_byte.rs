@@ -0,0 +1,59 @@
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result,
+};
+
+/// Formats the fields of a bitfield grouped under the index of the byte containing their
+/// first bit, e.g. `Reg {byte_0: {a: 1, b: 2}, byte_1: {c: 3}}`.
+///
+/// The fields are given in order as `(byte, name, value)`.
+///
+/// Used by the generated `Debug` impl for `#[bitfield(debug = by_byte)]`.
+#[doc(hidden)]
+pub struct DebugByByte<'a>(pub &'static str, pub &'a [(usize, &'static str, &'a dyn Debug)]);
+
+impl Debug for DebugByByte<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(self.0)?;
+        f.write_str(" ")?;
+        let mut map = f.debug_map();
+        let mut rest = self.1;
+        while let Some(&(byte, _, _)) = rest.first() {
+            let len = rest.iter().take_while(|(other, _, _)| *other == byte).count();
+            let (group, tail) = rest.split_at(len);
+            map.entry(&ByteKey(byte), &FieldGroup(group));
+            rest = tail;
+        }
+        map.finish()
+    }
+}
+
+/// Formats the index of a byte as `byte_N`.
+struct ByteKey(usize);
+
+impl Debug for ByteKey {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "byte_{}", self.0)
+    }
+}
+
+/// Formats the fields starting in the same byte as `{a: 1, b: 2}`.
+struct FieldGroup<'a>(&'a [(usize, &'static str, &'a dyn Debug)]);
+
+impl Debug for FieldGroup<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.debug_map()
+            .entries(self.0.iter().map(|(_, name, value)| (FieldName(name), value)))
+            .finish()
+    }
+}
+
+/// Formats the name of a field without quotes.
+struct FieldName<'a>(&'a str);
+
+impl Debug for FieldName<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(self.0)
+    }
+}
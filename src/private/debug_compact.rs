@@ -0,0 +1,30 @@
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result,
+};
+
+/// Formats the fields of a bitfield as compact comma separated `name=value` pairs,
+/// e.g. `Reg(a=1,b=2)`.
+///
+/// The fields are given in order as `(name, value)`.
+///
+/// Used by the generated `Debug` impl for `#[bitfield(debug = compact)]`.
+#[doc(hidden)]
+pub struct DebugCompact<'a>(pub &'static str, pub &'a [(&'static str, &'a dyn Debug)]);
+
+impl Debug for DebugCompact<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str(self.0)?;
+        f.write_str("(")?;
+        for (index, (name, value)) in self.1.iter().enumerate() {
+            if index != 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(name)?;
+            f.write_str("=")?;
+            value.fmt(f)?;
+        }
+        f.write_str(")")
+    }
+}
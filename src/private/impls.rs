@@ -1,3 +1,4 @@
+use core::convert::TryFrom;
 use crate::{
     error::{
         InvalidBitPattern,
@@ -56,3 +57,160 @@ impl_specifier_for_primitive!(
     (u64: 64),
     (u128: 128),
 );
+
+impl<A, B> Specifier for (A, B)
+where
+    A: Specifier,
+    B: Specifier,
+    A::Bytes: Into<u128> + TryFrom<u128>,
+    B::Bytes: Into<u128> + TryFrom<u128>,
+{
+    const BITS: usize = {
+        let bits = A::BITS + B::BITS;
+        assert!(bits <= 128, "tuple specifiers must not exceed 128 bits");
+        bits
+    };
+    const MAX_BITS: u128 = match A::BITS {
+        128 => A::MAX_BITS,
+        shift => A::MAX_BITS | (B::MAX_BITS << shift),
+    };
+    type Bytes = u128;
+    type InOut = (A::InOut, B::InOut);
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let (a, b) = input;
+        let a: u128 = A::into_bytes(a)?.into();
+        let b: u128 = B::into_bytes(b)?.into();
+        if a > mask_of(A::BITS) || b > mask_of(B::BITS) {
+            return Err(OutOfBounds)
+        }
+        Ok(a | (b << A::BITS))
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let invalid = || InvalidBitPattern { invalid_bytes: bytes };
+        if bytes > mask_of(Self::BITS) {
+            return Err(invalid())
+        }
+        let a = A::Bytes::try_from(bytes & mask_of(A::BITS)).map_err(|_| invalid())?;
+        let b = B::Bytes::try_from(bytes >> A::BITS).map_err(|_| invalid())?;
+        Ok((
+            A::from_bytes(a).map_err(|_| invalid())?,
+            B::from_bytes(b).map_err(|_| invalid())?,
+        ))
+    }
+}
+
+impl<T, const N: usize> Specifier for [T; N]
+where
+    T: Specifier,
+    T::Bytes: Into<u128> + TryFrom<u128>,
+{
+    const BITS: usize = {
+        let bits = T::BITS * N;
+        assert!(bits <= 128, "array specifiers must not exceed 128 bits");
+        bits
+    };
+    const MAX_BITS: u128 = {
+        let mut bits = 0_u128;
+        let mut index = 0;
+        while index < N && index * T::BITS < 128 {
+            bits |= T::MAX_BITS << (index * T::BITS);
+            index += 1;
+        }
+        bits
+    };
+    type Bytes = u128;
+    type InOut = [T::InOut; N];
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        let mut bytes = 0_u128;
+        for (index, element) in IntoIterator::into_iter(input).enumerate() {
+            let element: u128 = T::into_bytes(element)?.into();
+            if element > mask_of(T::BITS) {
+                return Err(OutOfBounds)
+            }
+            bytes |= element << (index * T::BITS);
+        }
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        let invalid = || InvalidBitPattern { invalid_bytes: bytes };
+        if bytes > mask_of(Self::BITS) {
+            return Err(invalid())
+        }
+        let elements = core::array::from_fn::<_, N, _>(|index| {
+            let element = (bytes >> (index * T::BITS)) & mask_of(T::BITS);
+            T::Bytes::try_from(element)
+                .ok()
+                .and_then(|element| T::from_bytes(element).ok())
+        });
+        if elements.iter().any(Option::is_none) {
+            return Err(invalid())
+        }
+        Ok(elements.map(|element| element.unwrap_or_else(|| unreachable!())))
+    }
+}
+
+/// Returns a mask with the lowest `bits` bits set.
+#[inline]
+const fn mask_of(bits: usize) -> u128 {
+    u128::MAX >> (128 - bits)
+}
+
+/// IPv4 addresses are stored as their `u32` value in network byte order.
+///
+/// This means that the first octet of the address occupies the most significant bits.
+impl Specifier for core::net::Ipv4Addr {
+    const BITS: usize = 32;
+    type Bytes = u32;
+    type InOut = core::net::Ipv4Addr;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        Ok(u32::from_be_bytes(input.octets()))
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        Ok(core::net::Ipv4Addr::from(bytes.to_be_bytes()))
+    }
+}
+
+/// Orderings are stored in 2 bits mapping `Less`, `Equal` and `Greater` to `0`, `1` and `2`.
+///
+/// The remaining bit pattern `3` is invalid.
+impl Specifier for core::cmp::Ordering {
+    const BITS: usize = 2;
+    const MAX_BITS: u128 = 2;
+    type Bytes = u8;
+    type InOut = core::cmp::Ordering;
+
+    #[inline]
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        Ok((input as i8 + 1) as u8)
+    }
+
+    #[inline]
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        match bytes {
+            0 => Ok(core::cmp::Ordering::Less),
+            1 => Ok(core::cmp::Ordering::Equal),
+            2 => Ok(core::cmp::Ordering::Greater),
+            invalid_bytes => Err(InvalidBitPattern { invalid_bytes }),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+/// The bit range `start..end` occupied by the field with the given name.
+pub type FieldLayout = (&'static str, usize, usize);
+
+/// Returns `true` if `a` and `b` are equal.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    if a.len() != b.len() {
+        return false
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns `true` if the field with the given name occupies exactly the bits `start..end`.
+///
+/// Used by the `assert_bitfield_layout!` macro in constant contexts.
+#[doc(hidden)]
+pub const fn field_has_range(
+    layout: &[FieldLayout],
+    name: &str,
+    start: usize,
+    end: usize,
+) -> bool {
+    let mut i = 0;
+    while i < layout.len() {
+        let (field, field_start, field_end) = layout[i];
+        if str_eq(field, name) {
+            return field_start == start && field_end == end
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Returns `true` if both layouts consist of the same fields occupying the same bit ranges
+/// in the same order.
+///
+/// Used by the `assert_same_layout!` macro in constant contexts.
+#[doc(hidden)]
+pub const fn same_layout(a: &[FieldLayout], b: &[FieldLayout]) -> bool {
+    if a.len() != b.len() {
+        return false
+    }
+    let mut i = 0;
+    while i < a.len() {
+        let (a_field, a_start, a_end) = a[i];
+        let (b_field, b_start, b_end) = b[i];
+        if !str_eq(a_field, b_field) || a_start != b_start || a_end != b_end {
+            return false
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the number of bits occupied by the field with the given name.
+///
+/// Used by the `field_bits!` macro in constant contexts.
+///
+/// # Panics
+///
+/// If there is no field with the given name.
+#[doc(hidden)]
+pub const fn field_bits(layout: &[FieldLayout], name: &str) -> usize {
+    let mut i = 0;
+    while i < layout.len() {
+        let (field, start, end) = layout[i];
+        if str_eq(field, name) {
+            return end - start
+        }
+        i += 1;
+    }
+    panic!("encountered unknown bitfield field name")
+}
+
+/// Returns the mask with the bits `start..end` set.
+///
+/// Used by the generated per-field mask constants.
+#[doc(hidden)]
+pub const fn range_mask(start: usize, end: usize) -> u128 {
+    let bits = end - start;
+    let ones = if bits >= 128 { !0 } else { (1_u128 << bits) - 1 };
+    if start >= 128 { 0 } else { ones << start }
+}
+
+/// Returns the mask of the bits occupied by the field with the given name.
+///
+/// Used by the `field_mask!` macro in constant contexts.
+///
+/// # Panics
+///
+/// If there is no field with the given name.
+#[doc(hidden)]
+pub const fn field_mask(layout: &[FieldLayout], name: &str) -> u128 {
+    let mut i = 0;
+    while i < layout.len() {
+        let (field, start, end) = layout[i];
+        if str_eq(field, name) {
+            return range_mask(start, end)
+        }
+        i += 1;
+    }
+    panic!("encountered unknown bitfield field name")
+}
+
+/// Returns the offset in bits of the field with the given name if any.
+///
+/// Used by the generated `bit_offset_of` functions.
+#[doc(hidden)]
+pub const fn field_offset(layout: &[FieldLayout], name: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < layout.len() {
+        let (field, start, _) = layout[i];
+        if str_eq(field, name) {
+            return Some(start)
+        }
+        i += 1;
+    }
+    None
+}
@@ -1,18 +1,43 @@
 mod array_bytes_conv;
 pub mod checks;
+mod debug_by_byte;
+mod debug_compact;
 mod impls;
+pub mod layout;
+mod parse;
 mod proc;
 mod push_pop;
+mod raw_hex;
 mod traits;
+mod units;
+
+#[cfg(feature = "alloc")]
+pub use alloc::vec::Vec;
+#[cfg(feature = "std")]
+pub use std::collections::HashMap;
+#[cfg(feature = "heapless")]
+pub use heapless::Vec as HeaplessVec;
+#[cfg(feature = "serde")]
+pub use serde;
 
 pub mod static_assertions {
     pub use static_assertions::*;
 }
 pub use self::{
     array_bytes_conv::ArrayBytesConversion,
+    debug_by_byte::DebugByByte,
+    debug_compact::DebugCompact,
+    parse::parse_field_value,
     proc::{
+        bits_are_zero,
+        bits_eq_ignoring,
+        hash_bits_ignoring,
+        read_bits,
         read_specifier,
+        scale_by_pow2,
+        with_bits,
         write_specifier,
+        xor_checksum,
     },
     push_pop::{
         PopBuffer,
@@ -28,4 +53,6 @@ pub use self::{
         PushBits,
         SpecifierBytes,
     },
+    raw_hex::RawHex,
+    units::WithUnits,
 };
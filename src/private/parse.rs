@@ -0,0 +1,28 @@
+/// Parses the value of a `field=value` pair for the generated `FromStr` impls.
+///
+/// Accepts `true` and `false` as well as decimal, `0x` prefixed hexadecimal
+/// and `0b` prefixed binary integers, optionally separated by `_`.
+#[doc(hidden)]
+pub fn parse_field_value(value: &str) -> Option<u128> {
+    match value {
+        "true" => return Some(1),
+        "false" => return Some(0),
+        _ => {}
+    }
+    let (digits, radix) = if let Some(hex) = value.strip_prefix("0x") {
+        (hex, 16)
+    } else if let Some(bin) = value.strip_prefix("0b") {
+        (bin, 2)
+    } else {
+        (value, 10)
+    };
+    if digits.is_empty() || digits.starts_with('_') || digits.starts_with('+') {
+        return None
+    }
+    let mut result: u128 = 0;
+    for c in digits.chars().filter(|&c| c != '_') {
+        let digit = c.to_digit(radix)?;
+        result = result.checked_mul(u128::from(radix))?.checked_add(u128::from(digit))?;
+    }
+    Some(result)
+}
@@ -18,6 +18,118 @@ where
     <PushBuffer<<T as Specifier>::Bytes> as Default>::default()
 }
 
+/// Returns `true` if all bits within `start..end` of the given bytes are zero.
+#[doc(hidden)]
+#[inline]
+pub fn bits_are_zero(bytes: &[u8], start: usize, end: usize) -> bool {
+    (start..end).all(|index| bytes[index / 8] & (0x01 << (index % 8)) == 0)
+}
+
+/// Returns the XOR of the bits within `start..end` of the given bytes split into
+/// chunks of `width` bits, the last chunk padded with zeros.
+///
+/// Usable in constant contexts, e.g. for the checksum of the `MAX` constant of `max_const`.
+#[doc(hidden)]
+#[inline]
+pub const fn xor_checksum(bytes: &[u8], start: usize, end: usize, width: usize) -> u128 {
+    let mut checksum = 0;
+    let mut index = start;
+    while index < end {
+        if bytes[index / 8] & (0x01 << (index % 8)) != 0 {
+            checksum ^= 0x01 << ((index - start) % width);
+        }
+        index += 1;
+    }
+    checksum
+}
+
+/// Returns the bits within `start..end` of the given bytes as an integer.
+#[doc(hidden)]
+#[inline]
+pub fn read_bits(bytes: &[u8], start: usize, end: usize) -> u128 {
+    (start..end)
+        .filter(|index| bytes[index / 8] & (0x01 << (index % 8)) != 0)
+        .fold(0, |value, index| value | (0x01 << (index - start)))
+}
+
+/// Returns the given bytes with the bits within `start..end` set to the given value.
+///
+/// Usable in constant contexts, e.g. for initializing `#[magic = N]` fields in `new()`.
+/// Bits beyond the 128 bits of the value are cleared.
+#[doc(hidden)]
+#[inline]
+pub const fn with_bits<const N: usize>(
+    mut bytes: [u8; N],
+    start: usize,
+    end: usize,
+    value: u128,
+) -> [u8; N] {
+    let mut index = start;
+    while index < end {
+        if index - start < 128 && (value >> (index - start)) & 0x01 != 0 {
+            bytes[index / 8] |= 0x01 << (index % 8);
+        } else {
+            bytes[index / 8] &= !(0x01 << (index % 8));
+        }
+        index += 1;
+    }
+    bytes
+}
+
+/// Returns `value * 2^exp` without relying on `std` floating point functions.
+///
+/// Used by the `to_f64` method of the `float_view` parameter.
+#[doc(hidden)]
+#[inline]
+pub fn scale_by_pow2(mut value: f64, mut exp: i64) -> f64 {
+    // Powers of two with exponents in `-1000..=1000` are exactly representable as normal `f64`.
+    let pow2 = |exp: i64| f64::from_bits(((exp + 1023) as u64) << 52);
+    while exp > 1000 && value.is_finite() && value != 0.0 {
+        value *= pow2(1000);
+        exp -= 1000;
+    }
+    while exp < -1000 && value.is_finite() && value != 0.0 {
+        value *= pow2(-1000);
+        exp += 1000;
+    }
+    value * pow2(exp.clamp(-1000, 1000))
+}
+
+/// Returns `true` if the given bytes are equal in all bits outside of the `ignored` bit ranges.
+#[doc(hidden)]
+#[inline]
+pub fn bits_eq_ignoring(lhs: &[u8], rhs: &[u8], ignored: &[(usize, usize)]) -> bool {
+    (0..lhs.len() * 8)
+        .filter(|index| !ignored.iter().any(|&(start, end)| (start..end).contains(index)))
+        .all(|index| (lhs[index / 8] ^ rhs[index / 8]) & (0x01 << (index % 8)) == 0)
+}
+
+/// Hashes the given bytes as a byte slice with all bits at or beyond `bits` and all bits
+/// within the `ignored` bit ranges masked out.
+///
+/// Without any masked bits this agrees with the `Hash` impl of `[u8]`.
+#[doc(hidden)]
+#[inline]
+pub fn hash_bits_ignoring<const N: usize, H>(
+    bytes: &[u8; N],
+    bits: usize,
+    ignored: &[(usize, usize)],
+    state: &mut H,
+) where
+    H: core::hash::Hasher,
+{
+    if ignored.is_empty() && bits == N * 8 {
+        return core::hash::Hash::hash(&bytes[..], state)
+    }
+    let mut masked = *bytes;
+    (0..N * 8)
+        .filter(|index| {
+            *index >= bits || ignored.iter().any(|&(start, end)| (start..end).contains(index))
+        })
+        .for_each(|index| masked[index / 8] &= !(0x01 << (index % 8)));
+    core::hash::Hash::hash(&masked[..], state)
+}
+
 #[doc(hidden)]
 #[inline]
 pub fn read_specifier<T>(bytes: &[u8], offset: usize) -> <T as Specifier>::Bytes
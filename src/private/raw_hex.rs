@@ -0,0 +1,21 @@
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result,
+};
+
+/// Formats the wrapped little endian bytes as a single hex number, e.g. `0x0321`.
+///
+/// Used by the generated `Debug` impl for `#[bitfield(debug = verbose)]`.
+#[doc(hidden)]
+pub struct RawHex<'a>(pub &'a [u8]);
+
+impl Debug for RawHex<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("0x")?;
+        for byte in self.0.iter().rev() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
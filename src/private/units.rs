@@ -0,0 +1,18 @@
+use core::fmt::{
+    Debug,
+    Formatter,
+    Result,
+};
+
+/// Formats the wrapped field value followed by its physical units, e.g. `48 Hz`.
+///
+/// Used by the generated `Debug` impl for fields annotated with `#[units = "..."]`.
+#[doc(hidden)]
+pub struct WithUnits<'a>(pub &'a dyn Debug, pub &'static str);
+
+impl Debug for WithUnits<'_> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        self.0.fmt(f)?;
+        write!(f, " {}", self.1)
+    }
+}
@@ -2,13 +2,14 @@
 // error happens if the user has written an incorrect #[bits = N] attribute.
 //
 // Ensure that the error message points to the incorrect attribute and contains
-// the correct number of bits in some form.
+// the correct number of bits in some form. A field may occupy more bits than its
+// specifier requires but never fewer.
 
 use modular_bitfield::prelude::*;
 
 #[bitfield]
 pub struct RedirectionTableEntry {
-    #[bits = 9]
+    #[bits = 1]
     trigger_mode: TriggerMode,
     reserved: B7,
 }
@@ -17,6 +18,8 @@ pub struct RedirectionTableEntry {
 pub enum TriggerMode {
     Edge = 0,
     Level = 1,
+    Pulse = 2,
+    Hold = 3,
 }
 
 fn main() {}
@@ -0,0 +1,50 @@
+// Setters that only write if the new value differs from the current one
+// and report back whether the bitfield has been changed.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    mode: Mode,
+    counter: B5,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct UnpackedStatus {
+    mode: Mode,
+    counter: B5,
+}
+
+fn main() {
+    let mut status = Status::new();
+    assert!(!status.set_ready_if_changed(false));
+    assert!(status.set_ready_if_changed(true));
+    assert!(!status.set_ready_if_changed(true));
+    assert!(status.ready());
+
+    assert!(status.set_mode_if_changed(Mode::Active));
+    assert!(!status.set_mode_if_changed(Mode::Active));
+    assert!(matches!(status.mode(), Mode::Active));
+
+    assert!(status.set_counter_if_changed(17));
+    assert!(!status.set_counter_if_changed(17));
+    assert_eq!(status.counter(), 17);
+    assert!(status.ready());
+
+    let mut unpacked = UnpackedStatus::new();
+    assert!(!unpacked.set_mode_if_changed(Mode::Sleep));
+    assert!(unpacked.set_mode_if_changed(Mode::Idle));
+    assert!(!unpacked.set_counter_if_changed(0));
+    assert!(unpacked.set_counter_if_changed(3));
+    assert_eq!(unpacked.counter(), 3);
+}
@@ -0,0 +1,38 @@
+// The byte length of a bitfield is available in constant contexts
+// so that external buffers can be sized accordingly.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    flags: B4,
+    length: B16,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Small {
+    kind: B2,
+    level: B6,
+}
+
+struct Buffer<const N: usize> {
+    data: [u8; N],
+}
+
+fn main() {
+    let mut buffer = [0u8; Header::BYTES];
+    assert_eq!(buffer.len(), 3);
+    let header = Header::new().with_kind(1).with_length(0xABCD);
+    buffer.copy_from_slice(&header.to_le_bytes());
+    assert_eq!(buffer, [0x01, 0xCD, 0xAB]);
+
+    const LEN: usize = Header::bytes_len();
+    assert_eq!(LEN, 3);
+    let generic = Buffer::<{ Header::BYTES }> { data: [0xFF; Header::BYTES] };
+    assert_eq!(generic.data.len(), 3);
+
+    assert_eq!(Small::BYTES, 2);
+    assert_eq!([0u8; Small::bytes_len()].len(), 2);
+}
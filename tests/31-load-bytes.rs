@@ -0,0 +1,35 @@
+// Loading bytes into an existing bitfield instance in place.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sample {
+    channel: B4,
+    is_valid: bool,
+    value: B11,
+}
+
+#[bitfield(filled = false)]
+pub struct Partial {
+    low: B3,
+    high: B4,
+}
+
+fn main() {
+    let mut sample = Sample::new();
+    for (bytes, channel, value) in [([0x13, 0x00], 3, 0), ([0x1F, 0xFF], 15, 0x7F8)] {
+        sample.load_le_bytes(bytes);
+        assert_eq!(sample.channel(), channel);
+        assert!(sample.is_valid());
+        assert_eq!(sample.value(), value);
+    }
+
+    let mut partial = Partial::new().with_low(0b101);
+    assert_eq!(partial.load_le_bytes([0b0111_1010]), Ok(()));
+    assert_eq!(partial.low(), 0b010);
+    assert_eq!(partial.high(), 0b1111);
+    // Bits outside of the defined range are rejected and leave `self` unchanged.
+    assert!(partial.load_le_bytes([0b1000_0000]).is_err());
+    assert_eq!(partial.low(), 0b010);
+    assert_eq!(partial.high(), 0b1111);
+}
@@ -0,0 +1,36 @@
+// Tuples of two specifiers can be used as a single bitfield field.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Packet {
+    pair: (B3, B5),
+    tagged: (Mode, B6),
+}
+
+fn main() {
+    assert_eq!(<(B3, B5) as Specifier>::BITS, 8);
+
+    let mut packet = Packet::new().with_pair((0b101, 0b1_0011));
+    assert_eq!(packet.pair(), (0b101, 0b1_0011));
+
+    packet.set_tagged((Mode::Active, 42));
+    assert_eq!(packet.tagged(), (Mode::Active, 42));
+    assert_eq!(packet.pair(), (0b101, 0b1_0011));
+
+    // Each half is checked against its own bit width.
+    assert!(packet.set_pair_checked((0b1000, 0)).is_err());
+    assert_eq!(packet.to_le_bytes(), [0b1001_1101, 0b1010_1010]);
+
+    // Invalid bit patterns of either half are reported.
+    let invalid = Packet::from_le_bytes([0x00, 0b0000_0011]);
+    assert!(invalid.tagged_or_err().is_err());
+}
@@ -0,0 +1,46 @@
+// Every bitfield can be constructed from a `u128` if the value fits its bit width.
+
+use core::convert::TryFrom;
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Word {
+    value: B12,
+    tag: B4,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug)]
+pub struct Partial {
+    low: B3,
+    high: B4,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone, Debug)]
+pub struct Unpacked {
+    low: B2,
+    high: B6,
+}
+
+fn main() {
+    let word = Word::try_from(0xA123_u128).unwrap();
+    assert_eq!(word.value(), 0x123);
+    assert_eq!(word.tag(), 0xA);
+    assert_eq!(Word::try_from(0x1_0000_u128).unwrap_err(), OutOfBounds);
+    assert_eq!(Word::try_from(u128::MAX).unwrap_err(), OutOfBounds);
+
+    let partial = Partial::try_from(0b111_1010_u128).unwrap();
+    assert_eq!(partial.low(), 0b010);
+    assert_eq!(partial.high(), 0b1111);
+    assert!(Partial::try_from(0b1000_0000_u128).is_err());
+
+    let unpacked = Unpacked::try_from(0b1010_1001_u128).unwrap();
+    assert_eq!(unpacked.low(), 0b01);
+    assert_eq!(unpacked.high(), 0b10_1010);
+    assert!(Unpacked::try_from(1_u128 << 12).is_err());
+}
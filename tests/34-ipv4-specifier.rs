@@ -0,0 +1,27 @@
+// `core::net::Ipv4Addr` can be used as a 32 bit specifier.
+
+use core::net::Ipv4Addr;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Route {
+    destination: Ipv4Addr,
+    prefix_len: B6,
+    is_active: bool,
+    is_static: bool,
+}
+
+fn main() {
+    assert_eq!(<Ipv4Addr as Specifier>::BITS, 32);
+
+    let route = Route::new()
+        .with_destination(Ipv4Addr::new(192, 168, 1, 10))
+        .with_prefix_len(24)
+        .with_is_active(true);
+    assert_eq!(route.destination(), Ipv4Addr::new(192, 168, 1, 10));
+    assert_eq!(route.prefix_len(), 24);
+    assert!(route.is_active());
+    assert!(!route.is_static());
+    // The address is stored in network byte order, i.e. the first octet is the most significant.
+    assert_eq!(route.get_destination_raw(), 0xC0A8_010A);
+}
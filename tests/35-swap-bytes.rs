@@ -0,0 +1,43 @@
+// Tests the generated `swap_bytes` method against a manual byte reversal.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Filled {
+    a: B4,
+    b: B12,
+    c: u8,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Unfilled {
+    a: u8,
+    b: B4,
+}
+
+#[bitfield(bits = 14, filled = false, packed = false)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unpacked {
+    a: u8,
+    b: B4,
+}
+
+const SWAPPED: Filled = Filled::from_le_bytes([0x01, 0x02, 0x03]).swap_bytes();
+
+fn main() {
+    let filled = Filled::new().with_a(0x1).with_b(0x234).with_c(0x56);
+    let mut manual = filled.to_le_bytes();
+    manual.reverse();
+    let filled = Filled::new().with_a(0x1).with_b(0x234).with_c(0x56);
+    assert_eq!(filled.swap_bytes().to_le_bytes(), manual);
+    assert_eq!(SWAPPED.to_le_bytes(), [0x03, 0x02, 0x01]);
+
+    // The low byte ends up in the partially defined high byte and gets masked.
+    let unfilled = Unfilled::new().with_a(0xAB).with_b(0xC);
+    assert_eq!(unfilled.swap_bytes().to_le_bytes(), [0x0C, 0x0B]);
+
+    let unpacked = Unpacked::new().with_a(0x0B).with_b(0xC);
+    assert_eq!(unpacked.swap_bytes().to_le_bytes(), [0x0C, 0x0B]);
+}
@@ -0,0 +1,22 @@
+// A field annotated with `#[bits = N]` where `N` exceeds the bits of its
+// specifier occupies `N` bits with the surplus upper bits reserved as zero.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    #[bits = 16]
+    low: B8,
+    high: u8,
+}
+
+fn main() {
+    assert_eq!(Register::low_range(), 0..16);
+    assert_eq!(Register::high_range(), 16..24);
+
+    let register = Register::new().with_low(0xFF).with_high(0xAB);
+    assert_eq!(register.low(), 0xFF);
+    assert_eq!(register.high(), 0xAB);
+    // The upper 8 bits of `low` always read zero.
+    assert_eq!(register.to_le_bytes(), [0xFF, 0x00, 0xAB]);
+}
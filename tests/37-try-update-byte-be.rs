@@ -0,0 +1,23 @@
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+fn main() {
+    let mut header = Header::new();
+    assert_eq!(header.try_update_byte_be(0, 0xAB), Ok(()));
+    assert_eq!(header.try_update_byte_be(2, 0xCD), Ok(()));
+    assert_eq!(header.c(), 0xAB);
+    assert_eq!(header.a(), 0xCD);
+
+    assert_eq!(header.try_update_byte_be(3, 0xFF), Err(OutOfBounds));
+    assert_eq!(header.try_update_byte_be(usize::MAX, 0xFF), Err(OutOfBounds));
+    assert_eq!(header.to_le_bytes(), [0xCD, 0x00, 0xAB]);
+}
@@ -0,0 +1,39 @@
+// Arrays of specifiers can be used as a single bitfield field.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Channels {
+    modes: [Mode; 4],
+    levels: [B4; 2],
+}
+
+fn main() {
+    assert_eq!(<[Mode; 4] as Specifier>::BITS, 8);
+
+    let modes = [Mode::Active, Mode::Sleep, Mode::Idle, Mode::Active];
+    let mut channels = Channels::new().with_modes(modes);
+    assert_eq!(channels.modes(), modes);
+    assert_eq!(channels.to_le_bytes(), [0b10_01_00_10, 0x00]);
+
+    channels.set_levels([0x3, 0xC]);
+    assert_eq!(channels.levels(), [0x3, 0xC]);
+    assert_eq!(channels.modes(), modes);
+
+    // Each element is checked against its own bit width.
+    assert!(channels.set_levels_checked([0x10, 0]).is_err());
+
+    // A corrupt element is reported as an invalid bit pattern.
+    let corrupt = Channels::from_le_bytes([0b00_11_00_00, 0x00]);
+    assert!(corrupt.modes_or_err().is_err());
+    assert_eq!(corrupt.get_modes_raw(), 0b00_11_00_00);
+}
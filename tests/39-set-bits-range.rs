@@ -0,0 +1,44 @@
+// Arbitrary ranges of bits can be written regardless of field boundaries.
+//
+// `set_bits` and `set_bits_range` are only generated with the `bit_view` parameter so that
+// they do not clash with the accessors of existing fields such as `bits`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_view)]
+#[derive(Clone, Copy)]
+pub struct Register {
+    low: B4,
+    mid: B8,
+    high: B4,
+}
+
+#[bitfield(filled = false, bit_view)]
+#[derive(Clone, Copy)]
+pub struct Unfilled {
+    a: B3,
+    b: B7,
+}
+
+fn main() {
+    let mut register = Register::new();
+    register.set_bits_range(2..10, 0b1111_1111);
+    assert_eq!(register.low(), 0b1100);
+    assert_eq!(register.mid(), 0b0011_1111);
+    assert_eq!(register.high(), 0);
+
+    // The range form delegates to the `(lo, len)` form.
+    register.set_bits(2, 8, 0b1010_0101);
+    assert_eq!(register.low(), 0b0100);
+    assert_eq!(register.mid(), 0b0010_1001);
+
+    register.set_bits_range(12..16, 0xF);
+    assert_eq!(register.high(), 0xF);
+    register.set_bits_range(4..4, 0);
+    assert_eq!(register.to_le_bytes(), [0b1001_0100, 0b1111_0010]);
+
+    let mut unfilled = Unfilled::new();
+    unfilled.set_bits_range(0..10, 0b11_1111_1111);
+    assert_eq!(unfilled.a(), 0b111);
+    assert_eq!(unfilled.b(), 0b111_1111);
+}
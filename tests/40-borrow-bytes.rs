@@ -0,0 +1,28 @@
+// Packed bitfields can be looked up by their underlying bytes in maps.
+
+use modular_bitfield::prelude::*;
+use std::borrow::Borrow;
+use std::collections::{BTreeSet, HashMap};
+
+#[bitfield]
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone, Copy)]
+pub struct Key {
+    kind: B4,
+    index: B12,
+}
+
+fn main() {
+    let key = Key::new().with_kind(3).with_index(0x123);
+    let bytes: &[u8] = key.borrow();
+    assert_eq!(bytes, &key.to_le_bytes()[..]);
+
+    let mut names = HashMap::new();
+    names.insert(key, "first");
+    names.insert(Key::new().with_kind(1), "second");
+    assert_eq!(names.get(&key.to_le_bytes()[..]), Some(&"first"));
+    assert_eq!(names.get(&[0x01, 0x00][..]), Some(&"second"));
+    assert_eq!(names.get(&[0x02, 0x00][..]), None);
+
+    let set = [key, Key::new()].iter().copied().collect::<BTreeSet<_>>();
+    assert!(set.contains(&[0x00, 0x00][..]));
+}
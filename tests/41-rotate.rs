@@ -0,0 +1,48 @@
+// Bitfields can be rotated within their bit width.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(Clone, Copy)]
+pub struct Ring {
+    low: B4,
+    high: B6,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Register {
+    value: B16,
+}
+
+fn main() {
+    // The most significant bit wraps around at bit 10, not at bit 16.
+    let ring = Ring::new().with_high(0b10_0000).with_low(0b0001);
+    let rotated = ring.rotate_left(1);
+    assert_eq!(rotated.low(), 0b0011);
+    assert_eq!(rotated.high(), 0b00_0000);
+    assert_eq!(rotated.to_le_bytes(), [0b0000_0011, 0b00]);
+
+    let rotated = ring.rotate_right(1);
+    assert_eq!(rotated.low(), 0b0000);
+    assert_eq!(rotated.high(), 0b11_0000);
+
+    // Rotating by the bit width or multiples of it is the identity.
+    assert_eq!(ring.rotate_left(10).to_le_bytes(), ring.to_le_bytes());
+    assert_eq!(ring.rotate_right(25).to_le_bytes(), ring.rotate_right(5).to_le_bytes());
+    assert_eq!(
+        ring.rotate_left(3).rotate_right(3).to_le_bytes(),
+        ring.to_le_bytes()
+    );
+
+    let register = Register::new().with_value(0x8001);
+    assert_eq!(register.rotate_left(4).value(), 0x0018);
+    assert_eq!(register.rotate_right(4).value(), 0x1800);
+    assert_eq!(
+        register.rotate_left(7).value(),
+        0x8001_u16.rotate_left(7)
+    );
+
+    const ROTATED: Register = Register::from_le_bytes([0x01, 0x00]).rotate_right(1);
+    assert_eq!(ROTATED.value(), 0x8000);
+}
@@ -0,0 +1,42 @@
+// Tests the generated `to_u32_words` and `from_u32_words` methods.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Regs {
+    control: u32,
+    status: B24,
+    flags: B8,
+    data: u32,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Unfilled {
+    a: u8,
+    b: B12,
+}
+
+const WORDS: [u32; 3] = Regs::from_le_bytes([0xEF, 0xBE, 0xAD, 0xDE, 0, 0, 0, 0, 0, 0, 0, 0]).to_u32_words();
+
+fn main() {
+    let regs = Regs::new()
+        .with_control(0x1234_5678)
+        .with_status(0xAB_CDEF)
+        .with_flags(0x99)
+        .with_data(0xCAFE_BABE);
+    let words = regs.to_u32_words();
+    assert_eq!(words, [0x1234_5678, 0x99AB_CDEF, 0xCAFE_BABE]);
+    assert_eq!(Regs::from_u32_words(words), regs);
+    assert_eq!(WORDS, [0xDEAD_BEEF, 0, 0]);
+
+    let unfilled = Unfilled::new().with_a(0x12).with_b(0xABC);
+    let words = unfilled.to_u32_words();
+    assert_eq!(words, [0x000A_BC12]);
+    assert_eq!(Unfilled::from_u32_words(words), Ok(unfilled));
+    // The padding byte of the last word is ignored.
+    assert_eq!(Unfilled::from_u32_words([0xFF0A_BC12]), Ok(unfilled));
+    // Undefined bits within the bit width are rejected.
+    assert!(Unfilled::from_u32_words([0x001A_BC12]).is_err());
+}
@@ -0,0 +1,29 @@
+// Tests the generated `PartialEq` impls comparing bitfields to their integer representation.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+pub struct Regs {
+    low: u8,
+    mode: B4,
+    flags: B4,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    a: B4,
+    b: B8,
+}
+
+fn main() {
+    let regs = Regs::new().with_low(0x34).with_mode(0x2).with_flags(0x1);
+    assert!(regs == 0x1234);
+    assert!(regs != 0x1235);
+    assert!(Regs::new() == 0);
+
+    let unpacked = Unpacked::new().with_a(0x5).with_b(0xAB);
+    assert!(unpacked == 0x0AB5);
+    assert!(unpacked != 0x0AB4);
+}
@@ -0,0 +1,47 @@
+// Tests the generated `from_le_bytes_at` method extracting unaligned bitfields from a buffer.
+
+use modular_bitfield::error::OutOfBounds;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Header {
+    kind: B4,
+    length: B12,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Small {
+    a: B3,
+    b: B2,
+}
+
+fn main() {
+    let header = Header::new().with_kind(0xA).with_length(0x5C3);
+    let value = u32::from(u16::from_le_bytes(header.to_le_bytes())) << 3 | 0b101;
+    let buf = value.to_le_bytes();
+    assert_eq!(Header::from_le_bytes_at(&buf, 3), Ok(header));
+    assert_eq!(Header::from_le_bytes_at(&buf[..3], 3), Ok(Header::new().with_kind(0xA).with_length(0x5C3)));
+    assert_eq!(Header::from_le_bytes_at(&buf[..2], 3), Err(OutOfBounds));
+    assert_eq!(Header::from_le_bytes_at(&buf, usize::MAX), Err(OutOfBounds));
+
+    // Byte aligned offsets behave like `from_le_bytes` on a sub slice.
+    let buf = [0xFF, 0x34, 0x12];
+    assert_eq!(
+        Header::from_le_bytes_at(&buf, 8),
+        Ok(Header::from_le_bytes([0x34, 0x12])),
+    );
+
+    // Bits beyond the width of unfilled bitfields are not extracted.
+    let buf = [0b1111_0110, 0b1111_1111];
+    assert_eq!(
+        Small::from_le_bytes_at(&buf, 1),
+        Ok(Small::new().with_a(0b011).with_b(0b11)),
+    );
+    assert_eq!(
+        Small::from_le_bytes_at(&buf, 11),
+        Ok(Small::new().with_a(0b111).with_b(0b11)),
+    );
+    assert_eq!(Small::from_le_bytes_at(&buf, 12), Err(OutOfBounds));
+}
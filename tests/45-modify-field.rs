@@ -0,0 +1,42 @@
+// Tests the generated `modify_*` methods applying a closure to a field in place.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Counter {
+    count: B4,
+    enabled: bool,
+    #[skip(getters)]
+    write_only: B3,
+}
+
+#[bitfield]
+pub struct Pair(B3, B5);
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    count: B4,
+    value: u8,
+}
+
+fn main() {
+    let mut counter = Counter::new();
+    for _ in 0..3 {
+        counter.modify_count(|count| count + 1);
+    }
+    assert_eq!(counter.count(), 3);
+    counter.modify_enabled(|enabled| !enabled);
+    assert!(counter.enabled());
+    counter.set_write_only(1);
+
+    let mut pair = Pair::new().with_1(7);
+    pair.modify_1(|value| value * 2);
+    assert_eq!(pair.get_1(), 14);
+
+    let mut unpacked = Unpacked::new().with_value(10);
+    unpacked.modify_count(|count| count + 2);
+    unpacked.modify_value(|value| value - 1);
+    assert_eq!(unpacked.count(), 2);
+    assert_eq!(unpacked.value(), 9);
+}
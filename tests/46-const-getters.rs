@@ -0,0 +1,42 @@
+// Tests that getters of primitive fields of integer backed bitfields are `const`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+}
+
+#[bitfield]
+#[repr(u32)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+    mode: Mode,
+    value: B14,
+    low: u8,
+}
+
+const STATUS: Status = Status::from_le_bytes([0b1010_1011, 0b1111_1110, 0xFF, 0x5A]);
+const READY: bool = STATUS.ready();
+const CODE: u8 = STATUS.code();
+const VALUE: u16 = STATUS.value();
+const LOW: u8 = STATUS.low();
+
+fn main() {
+    assert!(READY);
+    assert_eq!(CODE, 0b101_0101);
+    assert_eq!(VALUE, 0b11_1111_1111_1111);
+    assert_eq!(LOW, 0x5A);
+    assert_eq!(STATUS.mode(), Mode::C);
+
+    let status = Status::new().with_code(42).with_value(1000).with_low(7);
+    assert!(!status.ready());
+    assert_eq!(status.code(), 42);
+    assert_eq!(status.value(), 1000);
+    assert_eq!(status.low(), 7);
+}
@@ -0,0 +1,42 @@
+// Tests the generated `diff` and `changed_fields` methods.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub struct Regs {
+    low: u8,
+    mode: B4,
+    #[skip]
+    __: B3,
+    enabled: bool,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    a: B4,
+    b: B8,
+}
+
+fn main() {
+    let before = Regs::new().with_low(0x34).with_mode(0x2);
+    let after = before.with_mode(0x3).with_enabled(true);
+    assert_eq!(before.diff(&after), 0x8100);
+    assert_eq!(before.diff(&before), 0);
+    let mut changed = after.changed_fields(&before);
+    assert_eq!(changed.next(), Some("mode"));
+    assert_eq!(changed.next(), Some("enabled"));
+    assert_eq!(changed.next(), None);
+
+    // Bits of skipped fields show up in the diff but not as changed fields.
+    let skipped = Regs::from(0x7000);
+    assert_eq!(Regs::new().diff(&skipped), 0x7000);
+    assert_eq!(Regs::new().changed_fields(&skipped).count(), 0);
+
+    let before = Unpacked::new().with_a(0x5).with_b(0xAB);
+    let after = before.clone().with_b(0xAC);
+    assert_eq!(before.diff(&after), 0x0070);
+    assert!(before.changed_fields(&after).eq(["b"]));
+}
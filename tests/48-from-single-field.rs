@@ -0,0 +1,68 @@
+// Tests the generated `TryFrom` impl constructing single-field bitfields from their field value.
+
+use core::convert::{
+    TryFrom,
+    TryInto,
+};
+use modular_bitfield::{
+    error::{
+        FieldOutOfBounds,
+        OutOfBounds,
+    },
+    prelude::*,
+};
+
+#[bitfield(filled = false, from_field)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flag {
+    enabled: bool,
+}
+
+#[bitfield(from_field)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Counter {
+    value: u16,
+}
+
+#[bitfield(filled = false, from_field)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nibble(B4);
+
+#[bitfield(filled = false, from_field, rich_errors)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    level: B3,
+}
+
+// Without the `from_field` flag users are free to implement the conversions themselves.
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Port {
+    number: u16,
+}
+
+impl From<u16> for Port {
+    fn from(number: u16) -> Self {
+        Self::new().with_number(number)
+    }
+}
+
+fn main() {
+    assert_eq!(Counter::try_from(0xBEEF_u16).unwrap().value(), 0xBEEF);
+    assert_eq!(Counter::try_from(0xBEEF_u16), Ok(Counter::new().with_value(0xBEEF)));
+    assert_eq!(Nibble::try_from(0xA_u8).unwrap().get_0(), 0xA);
+    assert_eq!(Nibble::try_from(0x10_u8), Err(OutOfBounds));
+    let counter: Counter = 42_u16.try_into().unwrap();
+    assert_eq!(counter.value(), 42);
+    assert!(Flag::try_from(true).unwrap().enabled());
+    assert_eq!(Flag::try_from(false), Ok(Flag::new()));
+    assert_eq!(Level::try_from(5_u8).unwrap().level(), 5);
+    assert_eq!(
+        Level::try_from(8_u8),
+        Err(FieldOutOfBounds {
+            bitfield: "Level",
+            field: "level",
+        })
+    );
+    assert_eq!(Port::from(8080).number(), 8080);
+}
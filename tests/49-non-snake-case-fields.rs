@@ -0,0 +1,37 @@
+// Tests that fields named after datasheet registers such as `CR1` compile without
+// non snake case warnings for their generated accessors.
+
+#![deny(non_snake_case)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(layout)]
+#[derive(Debug)]
+pub struct Register {
+    CR1: B4,
+    OVR: bool,
+    #[skip]
+    __: B3,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Debug, Clone)]
+pub struct Unpacked {
+    CR1: B4,
+    OVR: B4,
+}
+
+#[bitfield(accessors_in_trait)]
+pub struct InTrait {
+    CR1: B4,
+    OVR: B4,
+}
+
+fn main() {
+    let register = Register::new().with_CR1(3).with_OVR(true);
+    assert_eq!(register.CR1(), 3);
+    let unpacked = Unpacked::new().with_CR1(3);
+    assert_eq!(unpacked.CR1(), 3);
+    let in_trait = InTrait::new().with_CR1(3);
+    assert_eq!(in_trait.CR1(), 3);
+}
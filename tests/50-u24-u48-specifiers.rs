@@ -0,0 +1,43 @@
+// Tests the `U24` and `U48` specifiers for non power of two byte widths.
+
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Frame {
+    sample: U24,
+    timestamp: U48,
+    channel: B8,
+    gain: u8,
+}
+
+fn main() {
+    assert_eq!(<U24 as Specifier>::BITS, 24);
+    assert_eq!(<U48 as Specifier>::BITS, 48);
+
+    let mut frame = Frame::new()
+        .with_sample(0x00AB_CDEF)
+        .with_timestamp(0x0000_1234_5678_9ABC)
+        .with_channel(3)
+        .with_gain(0xFF);
+    let sample: u32 = frame.sample();
+    let timestamp: u64 = frame.timestamp();
+    assert_eq!(sample, 0x00AB_CDEF);
+    assert_eq!(timestamp, 0x0000_1234_5678_9ABC);
+    assert_eq!(frame.channel(), 3);
+    assert_eq!(frame.gain(), 0xFF);
+
+    frame.set_sample(0x00FF_FFFF);
+    frame.set_timestamp(0x0000_FFFF_FFFF_FFFF);
+    assert_eq!(frame.sample(), 0x00FF_FFFF);
+    assert_eq!(frame.timestamp(), 0x0000_FFFF_FFFF_FFFF);
+    assert_eq!(frame.channel(), 3);
+
+    assert_eq!(frame.set_sample_checked(0x0100_0000), Err(OutOfBounds));
+    assert_eq!(frame.set_timestamp_checked(0x0001_0000_0000_0000), Err(OutOfBounds));
+    assert_eq!(frame.sample(), 0x00FF_FFFF);
+    assert_eq!(frame.timestamp(), 0x0000_FFFF_FFFF_FFFF);
+}
@@ -0,0 +1,23 @@
+// Tests building a bitfield from an iterator of bits via `from_bits_iter`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    a: bool,
+    b: B2,
+    c: B7,
+}
+
+fn main() {
+    let symbol = Symbol::from_bits_iter(vec![true, false, true]);
+    assert_eq!(symbol, Symbol::new().with_a(true).with_b(0b10));
+
+    // Bits beyond the width of the bitfield are ignored.
+    let symbol = Symbol::from_bits_iter(core::iter::repeat(true).take(16));
+    assert_eq!(symbol, Symbol::new().with_a(true).with_b(0b11).with_c(0x7F));
+    assert_eq!(symbol.to_le_bytes(), [0xFF, 0x03]);
+
+    assert_eq!(Symbol::from_bits_iter(core::iter::empty()), Symbol::new());
+}
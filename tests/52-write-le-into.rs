@@ -0,0 +1,20 @@
+// Tests writing the bytes of a bitfield into an uninitialized buffer via `write_le_into`.
+
+use core::mem::MaybeUninit;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Sample {
+    left: B12,
+    right: B12,
+}
+
+fn main() {
+    let sample = Sample::new().with_left(0xABC).with_right(0x123);
+    let mut out = [MaybeUninit::<u8>::uninit(); 3];
+    sample.write_le_into(&mut out);
+    // SAFETY: `write_le_into` initializes every byte of `out`.
+    let bytes = out.map(|byte| unsafe { byte.assume_init() });
+    assert_eq!(bytes, [0xBC, 0x3A, 0x12]);
+    assert_eq!(bytes, sample.to_le_bytes());
+}
@@ -0,0 +1,43 @@
+// Tests enumerating all valid field combinations via `#[bitfield(enumerate)]`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    Low = 1,
+    High = 2,
+}
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Polarity {
+    Positive = 0,
+    Negative = 1,
+}
+
+#[bitfield(enumerate, filled = false)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    mode: Mode,
+    polarity: Polarity,
+    enabled: bool,
+}
+
+fn main() {
+    let all = Config::all_combinations().collect::<Vec<_>>();
+    // 3 valid modes of the 4 bit patterns times 2 polarities times 2 flags.
+    assert_eq!(all.len(), 12);
+    for mode in [Mode::Off, Mode::Low, Mode::High] {
+        for polarity in [Polarity::Positive, Polarity::Negative] {
+            for enabled in [false, true] {
+                let config = Config::new()
+                    .with_mode(mode)
+                    .with_polarity(polarity)
+                    .with_enabled(enabled);
+                assert!(all.contains(&config));
+            }
+        }
+    }
+    assert_eq!(all[0], Config::new());
+}
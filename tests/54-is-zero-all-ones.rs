@@ -0,0 +1,50 @@
+// Tests the `is_zero` and `is_all_ones` checks over the bits of a bitfield.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    count: B7,
+}
+
+#[bitfield(bits = 60, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    a: B12,
+    b: u32,
+}
+
+const ZERO: bool = Status::new().is_zero();
+
+#[bitfield(bits = 15, filled = false)]
+pub struct Padded {
+    value: B12,
+}
+
+fn main() {
+    assert!(ZERO);
+    let zero = Status::new();
+    assert!(zero.is_zero());
+    assert!(!zero.is_all_ones());
+
+    let full = Status::new().with_ready(true).with_error(true).with_count(0x7F);
+    assert!(full.is_all_ones());
+    assert!(!full.is_zero());
+
+    let partial = Status::new().with_count(0x7F);
+    assert!(!partial.is_zero());
+    assert!(!partial.is_all_ones());
+
+    // Bits beyond the fields are not expected to be set.
+    assert!(Padded::new().with_value(0xFFF).is_all_ones());
+
+    let zero = Unpacked::new();
+    assert!(zero.is_zero());
+    assert!(!zero.is_all_ones());
+    let full = Unpacked::new().with_a(0xFFF).with_b(u32::MAX);
+    assert!(full.is_all_ones());
+    assert!(!full.is_zero());
+    assert!(!Unpacked::new().with_a(1).is_all_ones());
+}
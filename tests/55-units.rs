@@ -0,0 +1,34 @@
+// Tests that `#[units = "..."]` field attributes show up in the generated `Debug` output.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Clock {
+    #[units = "Hz"]
+    freq: B12,
+    #[units = "mV"]
+    level: B8,
+    mode: Mode,
+    #[units = "ms"]
+    timeout: Mode,
+}
+
+fn main() {
+    let clock = Clock::new().with_freq(48).with_level(200).with_mode(Mode::On);
+    assert_eq!(
+        format!("{:?}", clock),
+        "Clock { freq: 48 Hz, level: 200 mV, mode: On, timeout: Off ms }",
+    );
+    assert_eq!(
+        format!("{:#?}", Clock::from_le_bytes([0x30, 0x00, 0xC0])),
+        "Clock {\n    freq: 48 Hz,\n    level: 0 mV,\n    mode: Off,\n    timeout: InvalidBitPattern {\n        invalid_bytes: 3,\n    },\n}",
+    );
+}
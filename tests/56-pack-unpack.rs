@@ -0,0 +1,34 @@
+// Tests the standalone `pack` and `unpack` functions.
+
+use modular_bitfield::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    pack,
+    prelude::*,
+    unpack,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+fn main() {
+    assert_eq!(pack::<B5>(0), Ok(0));
+    assert_eq!(pack::<B5>(0b1_1111), Ok(0b1_1111));
+    assert_eq!(pack::<B5>(0b10_0000), Err(OutOfBounds));
+    assert_eq!(unpack::<B5>(0b1_0101), Ok(0b1_0101));
+    assert_eq!(unpack::<B5>(0b10_0000), Err(InvalidBitPattern::new(0b10_0000)));
+    for value in 0..32 {
+        assert_eq!(unpack::<B5>(pack::<B5>(value).unwrap()), Ok(value));
+    }
+
+    assert_eq!(pack::<bool>(true), Ok(1));
+    assert_eq!(pack::<Mode>(Mode::On), Ok(1));
+    assert_eq!(unpack::<Mode>(0), Ok(Mode::Off));
+    assert!(unpack::<Mode>(3).is_err());
+}
@@ -0,0 +1,54 @@
+// Tests conversions between a bitfield and its generated plain fields struct.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+    D = 3,
+}
+
+#[bitfield(plain)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Header {
+    version: B4,
+    mode: Mode,
+    ack: bool,
+    #[skip]
+    __: bool,
+    length: B8,
+}
+
+fn main() {
+    let header = Header::new()
+        .with_version(3)
+        .with_mode(Mode::C)
+        .with_ack(true)
+        .with_length(200);
+
+    let mut fields = HeaderFields::from(&header);
+    assert_eq!(fields.version, 3);
+    assert_eq!(fields.mode, Mode::C);
+    assert!(fields.ack);
+    assert_eq!(fields.length, 200);
+
+    match fields {
+        HeaderFields { mode: Mode::C, ack: true, .. } => {}
+        _ => panic!("unexpected fields"),
+    }
+
+    fields.version = 15;
+    fields.mode = Mode::D;
+    fields.ack = false;
+    let header: Header = fields.into();
+    assert_eq!(header.version(), 15);
+    assert_eq!(header.mode(), Mode::D);
+    assert!(!header.ack());
+    assert_eq!(header.length(), 200);
+
+    let fields: HeaderFields = header.into();
+    assert_eq!(Header::from(fields), header);
+}
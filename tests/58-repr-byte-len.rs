@@ -0,0 +1,53 @@
+// Tests that the byte arrays of an unfilled bitfield with an explicit
+// `#[repr(uN)]` always match the width of the primitive representation
+// while those of bitfields without one only cover the bytes of their bits.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12, packed = false, filled = false)]
+#[repr(u16)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Narrow {
+    value: B10,
+}
+
+#[bitfield(bits = 12, packed = false, filled = false)]
+#[repr(u32)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wide {
+    value: B10,
+}
+
+#[bitfield(bits = 24, packed = false)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triple {
+    low: B12,
+    high: B12,
+}
+
+fn main() {
+    let narrow = Narrow::new().with_value(0x2A5);
+    let bytes: [u8; 2] = narrow.clone().to_le_bytes();
+    assert_eq!(bytes.len(), core::mem::size_of::<u16>());
+    assert_eq!(Narrow::BYTES, 2);
+    assert_eq!(bytes, [0xA5, 0x02]);
+    assert_eq!(Narrow::from_le_bytes(bytes), Ok(narrow));
+    assert!(Narrow::from_le_bytes([0x00, 0x10]).is_err());
+
+    let wide = Wide::new().with_value(0x2A5);
+    let bytes: [u8; 4] = wide.clone().to_le_bytes();
+    assert_eq!(bytes.len(), core::mem::size_of::<u32>());
+    assert_eq!(Wide::BYTES, 4);
+    assert_eq!(bytes, [0xA5, 0x02, 0x00, 0x00]);
+    assert_eq!(Wide::from_le_bytes(bytes), Ok(wide));
+    assert!(Wide::from_le_bytes([0x00, 0x00, 0x01, 0x00]).is_err());
+
+    let triple = Triple::new().with_low(0xABC).with_high(0x123);
+    let bytes: [u8; 3] = triple.to_le_bytes();
+    assert_eq!(Triple::BYTES, 3);
+    assert_eq!(bytes, [0xBC, 0x3A, 0x12]);
+    assert_eq!(Triple::from_le_bytes(bytes), triple);
+    let bytes: [u8; 3] = triple.to_be_bytes();
+    assert_eq!(bytes, [0x12, 0x3A, 0xBC]);
+    assert_eq!(Triple::from_be_bytes(bytes), triple);
+}
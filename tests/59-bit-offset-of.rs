@@ -0,0 +1,32 @@
+// Tests the generated `bit_offset_of` function for known and unknown field names.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    version: B4,
+    flag: bool,
+    #[skip]
+    __: B3,
+    length: u8,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    low: B4,
+    high: B6,
+}
+
+const LENGTH_OFFSET: Option<usize> = Header::bit_offset_of("length");
+
+fn main() {
+    assert_eq!(Header::bit_offset_of("version"), Some(0));
+    assert_eq!(Header::bit_offset_of("flag"), Some(4));
+    assert_eq!(LENGTH_OFFSET, Some(8));
+    assert_eq!(Header::bit_offset_of("unknown"), None);
+    assert_eq!(Header::bit_offset_of(""), None);
+
+    assert_eq!(Unpacked::bit_offset_of("high"), Some(4));
+    assert_eq!(Unpacked::bit_offset_of("middle"), None);
+}
@@ -0,0 +1,37 @@
+// Tests the generated `masked` method keeping only the bits of a repr mask.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    count: B6,
+    code: u8,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unpacked {
+    a: B4,
+    b: B8,
+}
+
+fn main() {
+    let status = Status::new().with_ready(true).with_error(true).with_count(0x2A).with_code(0xC3);
+    let masked = status.masked(!0x00FC);
+    assert!(masked.ready());
+    assert!(masked.error());
+    assert_eq!(masked.count(), 0);
+    assert_eq!(masked.code(), 0xC3);
+    assert_eq!(status.masked(0xFFFF), status);
+    assert_eq!(status.masked(0), Status::new());
+
+    let unpacked = Unpacked::new().with_a(0x5).with_b(0xAB);
+    let masked = unpacked.clone().masked(0x0FF0);
+    assert_eq!(masked.a(), 0);
+    assert_eq!(masked.b(), 0xAB);
+    assert_eq!(unpacked.clone().masked(0x000F), Unpacked::new().with_a(0x5));
+}
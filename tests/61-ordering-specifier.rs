@@ -0,0 +1,27 @@
+// `core::cmp::Ordering` can be used as a 2 bit specifier.
+
+use core::cmp::Ordering;
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Comparison {
+    result: Ordering,
+    index: B6,
+}
+
+fn main() {
+    assert_eq!(<Ordering as Specifier>::BITS, 2);
+
+    for (ordering, raw) in [(Ordering::Less, 0), (Ordering::Equal, 1), (Ordering::Greater, 2)] {
+        let comparison = Comparison::new().with_result(ordering).with_index(42);
+        assert_eq!(comparison.result(), ordering);
+        assert_eq!(comparison.index(), 42);
+        assert_eq!(comparison.to_le_bytes()[0] & 0b11, raw);
+        assert_eq!(<Ordering as Specifier>::from_bytes(raw), Ok(ordering));
+    }
+
+    // The fourth bit pattern does not map to an ordering.
+    let comparison = Comparison::from_le_bytes([0b0000_0011]);
+    assert!(comparison.result_or_err().is_err());
+    assert!(<Ordering as Specifier>::from_bytes(3).is_err());
+}
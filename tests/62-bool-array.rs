@@ -0,0 +1,24 @@
+// Tests converting a bitfield from and to an array of its bits via `to_bits` and `from_bits`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Symbol {
+    a: bool,
+    b: B2,
+    c: B7,
+}
+
+fn main() {
+    let symbol = Symbol::new().with_a(true).with_b(0b10).with_c(0b100_0001);
+    let bits: [bool; 10] = symbol.to_bits();
+    assert_eq!(
+        bits,
+        [true, false, true, true, false, false, false, false, false, true],
+    );
+    assert_eq!(Symbol::from_bits(bits), symbol);
+
+    assert_eq!(Symbol::new().to_bits(), [false; 10]);
+    assert_eq!(Symbol::from_bits([true; 10]).to_le_bytes(), [0xFF, 0x03]);
+}
@@ -0,0 +1,33 @@
+// Arbitrary ranges of bits can be read regardless of field boundaries.
+//
+// `get_bits` and `bits_in` are only generated with the `bit_view` parameter.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_view)]
+#[derive(Clone, Copy)]
+pub struct Register {
+    low: B4,
+    mid: B8,
+    high: B4,
+}
+
+fn main() {
+    let register = Register::new()
+        .with_low(0b1100)
+        .with_mid(0b1010_0111)
+        .with_high(0b0101);
+    let slice: u16 = register.bits_in(2..6);
+    assert_eq!(slice, 0b1111);
+    assert_eq!(register.bits_in(0..4), 0b1100);
+    assert_eq!(register.bits_in(4..12), 0b1010_0111);
+    assert_eq!(register.bits_in(0..16), 0b0101_1010_0111_1100);
+    assert_eq!(register.bits_in(7..7), 0);
+
+    // The range form delegates to the `(lo, len)` form.
+    assert_eq!(register.get_bits(2, 4), u128::from(register.bits_in(2..6)));
+
+    // Ranges outside of the bitfield or decreasing ranges are rejected.
+    assert!(std::panic::catch_unwind(|| register.bits_in(10..17)).is_err());
+    assert!(std::panic::catch_unwind(|| register.bits_in(6..2)).is_err());
+}
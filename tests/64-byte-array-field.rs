@@ -0,0 +1,28 @@
+// Raw byte arrays can be used as fields, e.g. for MAC addresses.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Frame {
+    kind: B4,
+    mac: [u8; 6],
+    flags: B4,
+}
+
+fn main() {
+    assert_eq!(<[u8; 6] as Specifier>::BITS, 48);
+
+    let mac = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0xFF];
+    let frame = Frame::new().with_kind(0x3).with_mac(mac).with_flags(0xA);
+    assert_eq!(frame.mac(), mac);
+    assert_eq!(frame.kind(), 0x3);
+    assert_eq!(frame.flags(), 0xA);
+
+    // The bytes are packed in order starting at the lowest bits of the field.
+    assert_eq!(
+        frame.to_le_bytes(),
+        [0x03, 0xA0, 0xB1, 0xC2, 0xD3, 0xF4, 0xAF],
+    );
+    assert_eq!(Frame::from_le_bytes(frame.to_le_bytes()).mac(), mac);
+}
@@ -0,0 +1,48 @@
+// Bitfields with an integer repr and the `widening_from` flag convert into all wider
+// unsigned integers.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(widening_from)]
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub struct Packed {
+    low: B4,
+    high: B12,
+}
+
+#[bitfield(packed = false, widening_from)]
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    low: B4,
+    high: B12,
+}
+
+// Without the flag users are free to implement the conversions themselves.
+#[bitfield]
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub struct Custom {
+    value: u8,
+}
+
+impl From<Custom> for u32 {
+    fn from(custom: Custom) -> Self {
+        u32::from(custom.value()) << 8
+    }
+}
+
+fn main() {
+    let packed = Packed::new().with_low(0xA).with_high(0xBCD);
+    assert_eq!(u64::from(packed), 0xBCDA);
+    assert_eq!(u32::from(packed), 0xBCDA);
+    let wide: u128 = packed.into();
+    assert_eq!(wide, 0xBCDA);
+
+    let unpacked = Unpacked::new().with_low(0x1).with_high(0x234);
+    assert_eq!(u64::from(unpacked), 0x2341);
+    assert_eq!(u128::from(unpacked), 0x2341);
+
+    assert_eq!(u32::from(Custom::new().with_value(0x12)), 0x1200);
+}
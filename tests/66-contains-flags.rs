@@ -0,0 +1,38 @@
+// Bitfields with an integer repr provide `contains` checking the masks of their fields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    mode: B3,
+    error: bool,
+    r#busy: bool,
+    #[skip]
+    __: bool,
+    level: B9,
+}
+
+#[bitfield]
+#[repr(u8)]
+pub struct Pair(bool, B6, bool);
+
+const STATUS: Status = Status::from_le_bytes([0b0011_0001, 0x00]);
+const READY_AND_BUSY: bool = STATUS.contains(Status::READY_MASK | Status::BUSY_MASK);
+
+fn main() {
+    assert!(READY_AND_BUSY);
+
+    let status = Status::new().with_ready(true).with_error(true).with_mode(0b111);
+    assert!(status.contains(Status::READY_MASK));
+    assert!(status.contains(Status::READY_MASK | Status::ERROR_MASK));
+    assert!(status.contains(Status::MODE_MASK));
+    assert!(!status.contains(Status::READY_MASK | Status::BUSY_MASK));
+    assert!(status.contains(0));
+
+    let pair = Pair::new().with_2(true);
+    assert!(pair.contains(Pair::_2_MASK));
+    assert!(!pair.contains(Pair::_0_MASK | Pair::_2_MASK));
+}
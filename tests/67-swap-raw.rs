@@ -0,0 +1,33 @@
+// The whole raw value of a bitfield can be swapped, returning the previous raw value.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+pub struct Register {
+    low: B4,
+    high: B12,
+}
+
+#[bitfield(bits = 12, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Unfilled {
+    low: B4,
+    high: B8,
+}
+
+fn main() {
+    let mut register = Register::new().with_low(0x1).with_high(0x234);
+    assert_eq!(register.swap_raw(0xABCD), Ok(0x2341));
+    assert_eq!(register.low(), 0xD);
+    assert_eq!(register.high(), 0xABC);
+
+    let mut unfilled = Unfilled::new().with_low(0x5).with_high(0x67);
+    assert_eq!(unfilled.swap_raw(0x0FED), Ok(0x675));
+    assert_eq!(unfilled.low(), 0xD);
+    assert_eq!(unfilled.high(), 0xFE);
+
+    // Bits beyond the 12 bits of the bitfield are rejected and leave it unchanged.
+    assert!(unfilled.swap_raw(0x1000).is_err());
+    assert_eq!(unfilled.swap_raw(0), Ok(0xFED));
+}
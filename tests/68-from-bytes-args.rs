@@ -0,0 +1,28 @@
+// Small filled bitfields can be constructed from their bytes as separate arguments.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(bits = 24)]
+pub struct Sized {
+    #[bits = 16]
+    low: B12,
+    high: u8,
+}
+
+const HEADER: Header = Header::from_bytes_args(0x21, 0x43);
+
+fn main() {
+    assert_eq!(HEADER.kind(), 0x1);
+    assert_eq!(HEADER.len(), 0x432);
+    assert_eq!(Header::from_bytes_args(0x21, 0x43).to_le_bytes(), [0x21, 0x43]);
+
+    let sized = Sized::from_bytes_args(0xCD, 0x0A, 0xEF);
+    assert_eq!(sized.low(), 0xACD);
+    assert_eq!(sized.high(), 0xEF);
+}
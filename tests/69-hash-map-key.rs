@@ -0,0 +1,50 @@
+// Packed bitfields deriving `Hash` hash their bytes directly and can be used as map keys.
+
+use modular_bitfield::prelude::*;
+use std::collections::{
+    hash_map::DefaultHasher,
+    HashMap,
+};
+use std::hash::{
+    Hash,
+    Hasher,
+};
+
+#[bitfield]
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Key {
+    kind: B4,
+    id: B12,
+}
+
+#[bitfield]
+#[derive(Hash, Eq)]
+pub struct Tagged {
+    value: B12,
+    #[eq(ignore)]
+    generation: B4,
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {
+    let mut map = HashMap::new();
+    map.insert(Key::new().with_kind(1).with_id(42), "first");
+    map.insert(Key::new().with_kind(2).with_id(42), "second");
+    assert_eq!(map.get(&Key::new().with_kind(1).with_id(42)), Some(&"first"));
+    assert_eq!(map.get(&Key::new().with_kind(2).with_id(42)), Some(&"second"));
+    assert_eq!(map.get(&Key::new().with_kind(3).with_id(42)), None);
+
+    // Fields ignored by `PartialEq` are ignored by `Hash` as well.
+    let a = Tagged::new().with_value(7).with_generation(1);
+    let b = Tagged::new().with_value(7).with_generation(2);
+    assert!(a == b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+    let mut map = HashMap::new();
+    map.insert(a, "tagged");
+    assert_eq!(map.get(&b), Some(&"tagged"));
+}
@@ -0,0 +1,27 @@
+// Small bitfields can be destructured into their bytes.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(filled = false)]
+pub struct Unfilled {
+    low: B4,
+    high: B8,
+}
+
+const BYTES: (u8, u8) = Header::from_bytes_args(0x21, 0x43).as_bytes_tuple();
+
+fn main() {
+    let (lo, hi) = Header::new().with_kind(0x1).with_len(0x432).as_bytes_tuple();
+    assert_eq!((lo, hi), (0x21, 0x43));
+    assert_eq!(BYTES, (0x21, 0x43));
+
+    let (lo, hi) = Unfilled::new().with_low(0xA).with_high(0xBC).as_bytes_tuple();
+    assert_eq!(lo, 0xCA);
+    assert_eq!(hi, 0x0B);
+}
@@ -0,0 +1,33 @@
+// The raw value of a bitfield can be truncated to its low bits.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+pub struct Register {
+    low: B4,
+    high: B12,
+}
+
+#[bitfield(bits = 12, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Unfilled {
+    low: B4,
+    high: B8,
+}
+
+fn main() {
+    let register = Register::new().with_low(0xD).with_high(0xABC);
+    assert_eq!(register.truncated_to_bits(0), 0);
+    assert_eq!(register.truncated_to_bits(4), 0xD);
+    assert_eq!(register.truncated_to_bits(10), 0x3CD);
+    assert_eq!(register.truncated_to_bits(16), 0xABCD);
+
+    let unfilled = Unfilled::new().with_low(0x5).with_high(0x67);
+    assert_eq!(unfilled.truncated_to_bits(8), 0x75);
+    assert_eq!(unfilled.truncated_to_bits(12), 0x675);
+
+    // Truncating to more bits than the bitfield has panics.
+    assert!(std::panic::catch_unwind(|| unfilled.truncated_to_bits(13)).is_err());
+    assert!(std::panic::catch_unwind(|| register.truncated_to_bits(17)).is_err());
+}
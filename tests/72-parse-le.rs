@@ -0,0 +1,43 @@
+// Bitfields can be parsed one after another from the start of a buffer.
+
+use core::convert::TryFrom;
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(filled = false)]
+pub struct Flags {
+    ready: bool,
+    busy: bool,
+    code: B5,
+}
+
+fn main() {
+    let buf = [0x21, 0x43, 0b0100_0101, 0xFF];
+    let (header, rest) = Header::parse_le(&buf).unwrap();
+    assert_eq!(header.kind(), 0x1);
+    assert_eq!(header.len(), 0x432);
+    let (flags, rest) = Flags::parse_le(rest).unwrap();
+    assert!(flags.ready());
+    assert!(!flags.busy());
+    assert_eq!(flags.code(), 0b10001);
+    assert_eq!(rest, &[0xFF]);
+
+    // Too short buffers and undefined bits are rejected.
+    assert!(matches!(Header::parse_le(&buf[..1]), Err(OutOfBounds)));
+    assert!(matches!(Flags::parse_le(&[0x80]), Err(OutOfBounds)));
+
+    // `TryFrom<&[u8]>` requires the buffer to hold exactly the bytes of the bitfield.
+    let header = Header::try_from(&buf[..2]).unwrap();
+    assert_eq!(header.len(), 0x432);
+    assert!(Header::try_from(&buf[..]).is_err());
+    assert!(Header::try_from(&[][..]).is_err());
+    assert!(Flags::try_from(&[0x80][..]).is_err());
+}
@@ -0,0 +1,55 @@
+// Tests that `new()` initializes enum fields to the `#[default]` variant of the enum.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[bits = 2]
+pub enum Speed {
+    Low = 0,
+    Medium = 1,
+    #[default]
+    High = 2,
+}
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield(zero_const)]
+#[derive(Debug)]
+pub struct Fan {
+    enabled: bool,
+    speed: Speed,
+    mode: Mode,
+    level: B4,
+}
+
+const FAN: Fan = Fan::new();
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Debug, Clone, Copy)]
+pub struct UnpackedFan {
+    speed: Speed,
+    mode: Mode,
+    level: B5,
+}
+
+fn main() {
+    assert_eq!(Speed::default(), Speed::High);
+
+    let fan = Fan::new();
+    assert_eq!(fan.speed(), Speed::High);
+    assert_eq!(fan.mode(), Mode::Off);
+    assert!(!fan.enabled());
+    assert_eq!(fan.level(), 0);
+    assert_eq!(fan.to_le_bytes(), [0b0000_0100]);
+    assert_eq!(FAN.speed(), Speed::High);
+    assert_eq!(Fan::ZERO.speed(), Speed::Low);
+
+    let unpacked = UnpackedFan::new();
+    assert_eq!(unpacked.speed(), Speed::High);
+    assert_eq!(unpacked.mode(), Mode::Off);
+    assert_eq!(unpacked.level(), 0);
+}
@@ -0,0 +1,34 @@
+// Tests that `try_set_many` commits a batch of writes only if all of them succeed.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Config {
+    mode: B3,
+    level: B5,
+    limit: u8,
+}
+
+fn main() {
+    let mut config = Config::new().with_mode(1).with_level(2).with_limit(3);
+    let original = config;
+
+    // The second write is out of bounds, so neither write is committed.
+    let result = config.try_set_many(|config| {
+        config.set_mode_checked(5)?;
+        config.set_level_checked(32)?;
+        config.set_limit_checked(9)
+    });
+    assert!(result.is_err());
+    assert_eq!(config, original);
+
+    assert!(config
+        .try_set_many(|config| {
+            config.set_mode_checked(5)?;
+            config.set_level_checked(31)?;
+            config.set_limit_checked(9)
+        })
+        .is_ok());
+    assert_eq!(config, Config::new().with_mode(5).with_level(31).with_limit(9));
+}
@@ -0,0 +1,77 @@
+// Tests comparing a bitfield to its generated plain fields struct.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield(plain)]
+#[derive(Clone, Copy)]
+pub struct Header {
+    version: B4,
+    mode: Mode,
+    ack: bool,
+    #[skip]
+    __: bool,
+    length: B8,
+}
+
+#[bitfield(bits = 16, packed = false, plain)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    low: B7,
+    flag: bool,
+    high: B8,
+}
+
+#[derive(BitfieldSpecifier, Clone, Copy)]
+pub enum NoEq {
+    X = 0,
+    Y = 1,
+}
+
+// Bitfields with fields that do not implement `PartialEq` still compile.
+#[bitfield(plain)]
+pub struct WithoutEq {
+    no_eq: NoEq,
+    rest: B7,
+}
+
+fn main() {
+    let header = Header::new()
+        .with_version(3)
+        .with_mode(Mode::C)
+        .with_ack(true)
+        .with_length(200);
+    let fields = HeaderFields {
+        version: 3,
+        mode: Mode::C,
+        ack: true,
+        length: 200,
+    };
+    assert!(header == fields);
+    assert!(fields == header);
+    assert!(header.with_length(201) != fields);
+    assert!(HeaderFields { mode: Mode::B, ..fields } != header);
+
+    // Skipped fields do not take part in the comparison.
+    let mut bytes = header.to_le_bytes();
+    bytes[0] |= 0x80;
+    assert!(Header::from_le_bytes(bytes) == fields);
+
+    // Fields containing an invalid bit pattern are never equal.
+    let invalid = Header::from_le_bytes([0b0011_0011, 200]);
+    assert!(invalid != HeaderFields { mode: Mode::A, ..fields });
+
+    let unpacked = Unpacked::new().with_low(100).with_flag(true).with_high(7);
+    assert!(unpacked == UnpackedFields { low: 100, flag: true, high: 7 });
+    assert!(UnpackedFields { low: 100, flag: false, high: 7 } != unpacked);
+
+    let without_eq = WithoutEqFields::from(WithoutEq::new().with_no_eq(NoEq::Y).with_rest(5));
+    assert!(matches!(without_eq.no_eq, NoEq::Y));
+}
@@ -0,0 +1,38 @@
+// Bitfields can have their bits reversed within their bit width.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(Clone, Copy)]
+pub struct Ring {
+    low: B4,
+    high: B6,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Register {
+    value: B16,
+}
+
+fn main() {
+    // The bits are mirrored around bit 5, not around bit 8 or 16.
+    let ring = Ring::new().with_low(0b0011).with_high(0b10_0000);
+    let reversed = ring.reverse_bits();
+    assert_eq!(reversed.low(), 0b0001);
+    assert_eq!(reversed.high(), 0b11_0000);
+    assert_eq!(reversed.to_le_bytes(), [0b0000_0001, 0b11]);
+
+    // Padding bits stay zero.
+    let all = Ring::new().with_low(0b1111).with_high(0b11_1111);
+    assert_eq!(all.reverse_bits().to_le_bytes(), [0xFF, 0b11]);
+
+    // Reversing twice is the identity.
+    assert_eq!(ring.reverse_bits().reverse_bits().to_le_bytes(), ring.to_le_bytes());
+
+    let register = Register::new().with_value(0x1234);
+    assert_eq!(register.reverse_bits().value(), 0x1234_u16.reverse_bits());
+
+    const REVERSED: Register = Register::from_le_bytes([0x01, 0x00]).reverse_bits();
+    assert_eq!(REVERSED.value(), 0x8000);
+}
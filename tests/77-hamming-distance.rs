@@ -0,0 +1,34 @@
+// Bitfields can compute the number of bits in which they differ.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    low: B4,
+    high: B12,
+}
+
+#[bitfield(filled = false)]
+pub struct Partial {
+    value: B10,
+}
+
+fn main() {
+    let a = Register::new().with_low(0b1010).with_high(0x0F0);
+    let b = Register::new().with_low(0b0110).with_high(0x0FF);
+    assert_eq!(a.hamming_distance(&b), 2 + 4);
+    assert_eq!(b.hamming_distance(&a), 6);
+    assert_eq!(a.hamming_distance(&a), 0);
+    assert_eq!(
+        Register::new().hamming_distance(&Register::from_le_bytes([0xFF, 0xFF])),
+        16
+    );
+
+    let zero = Partial::new();
+    let ones = Partial::new().with_value(0x3FF);
+    assert_eq!(zero.hamming_distance(&ones), 10);
+
+    const DISTANCE: u32 =
+        Register::from_le_bytes([0x01, 0x80]).hamming_distance(&Register::from_le_bytes([0x00, 0x00]));
+    assert_eq!(DISTANCE, 2);
+}
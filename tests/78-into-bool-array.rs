@@ -0,0 +1,24 @@
+// Tests converting a bitfield into an array of its bits via `Into`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Frame {
+    start: bool,
+    data: B6,
+    stop: bool,
+}
+
+fn send(bits: impl Into<[bool; 8]>) -> [bool; 8] {
+    bits.into()
+}
+
+fn main() {
+    // Bits are ordered least significant bit first.
+    let frame = Frame::new().with_start(true).with_data(0b00_0011);
+    let bits: [bool; 8] = frame.into();
+    assert_eq!(bits, [true, true, true, false, false, false, false, false]);
+
+    let frame = Frame::from_le_bytes([0b1000_0000]);
+    assert_eq!(send(frame), [false, false, false, false, false, false, false, true]);
+}
@@ -0,0 +1,50 @@
+// The bits selected by a mask can be patched in a single operation.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+pub struct Register {
+    enable: bool,
+    mode: B3,
+    divider: B8,
+    reserved: B4,
+}
+
+#[bitfield(bits = 12, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Unfilled {
+    low: B4,
+    high: B8,
+}
+
+fn main() {
+    let mut register = Register::new()
+        .with_enable(true)
+        .with_mode(0b101)
+        .with_divider(0x12)
+        .with_reserved(0xA);
+    // Patch only the divider, all other bits are preserved.
+    assert_eq!(register.patch(0x0FF0, 0xFFFF), Ok(()));
+    assert!(register.enable());
+    assert_eq!(register.mode(), 0b101);
+    assert_eq!(register.divider(), 0xFF);
+    assert_eq!(register.reserved(), 0xA);
+    assert!(register == 0xAFFB_u16);
+
+    // Bits set in `value` but not in `mask` are ignored.
+    assert_eq!(register.patch(0x000E, 0x0002), Ok(()));
+    assert_eq!(register.mode(), 0b001);
+    assert!(register.enable());
+    assert!(register == 0xAFF3_u16);
+
+    let mut unfilled = Unfilled::new().with_low(0x5).with_high(0x67);
+    assert_eq!(unfilled.patch(0x00F0, 0x0090), Ok(()));
+    assert_eq!(unfilled.low(), 0x5);
+    assert_eq!(unfilled.high(), 0x69);
+
+    // Results beyond the 12 bits of the bitfield are rejected and leave it unchanged.
+    assert!(unfilled.patch(0xF000, 0x1000).is_err());
+    assert_eq!(unfilled.low(), 0x5);
+    assert_eq!(unfilled.high(), 0x69);
+}
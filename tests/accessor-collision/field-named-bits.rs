@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_view)]
+pub struct Sample {
+    bits: B4,
+    rate: B4,
+}
+
+fn main() {}
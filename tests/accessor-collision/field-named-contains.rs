@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u8)]
+pub struct Flags {
+    contains: bool,
+    rest: B7,
+}
+
+fn main() {}
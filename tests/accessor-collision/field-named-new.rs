@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Command {
+    new: bool,
+    clear: B7,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u16)]
+pub struct Version {
+    major: B4,
+    minor: B4,
+    patch: B8,
+}
+
+fn main() {}
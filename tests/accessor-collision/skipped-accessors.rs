@@ -0,0 +1,20 @@
+// Tests that fields whose accessors would collide with generated methods are accepted
+// as long as the colliding accessors are skipped.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_view)]
+pub struct Command {
+    #[skip(getters)]
+    new: bool,
+    clear: B3,
+    #[skip(setters)]
+    bits: B4,
+}
+
+fn main() {
+    let command = Command::new().with_new(true).with_clear(5);
+    assert_eq!(command.clear(), 5);
+    assert_eq!(command.bits(), 0);
+    assert_eq!(command.to_le_bytes(), [0b0000_1011]);
+}
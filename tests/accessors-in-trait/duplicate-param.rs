@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(accessors_in_trait, accessors_in_trait)]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+fn main() {}
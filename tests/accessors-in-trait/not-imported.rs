@@ -0,0 +1,14 @@
+mod regs {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(accessors_in_trait)]
+    pub struct Control {
+        pub enable: bool,
+        pub mode: B7,
+    }
+}
+
+fn main() {
+    let control = regs::Control::new();
+    let _ = control.enable();
+}
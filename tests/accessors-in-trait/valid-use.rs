@@ -0,0 +1,39 @@
+mod regs {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(accessors_in_trait)]
+    #[derive(Debug)]
+    pub struct Control {
+        pub enable: bool,
+        pub mode: B3,
+        pub prescaler: B12,
+    }
+
+    #[bitfield(bits = 14, packed = false, filled = false, accessors_in_trait)]
+    #[derive(Clone)]
+    pub struct Status {
+        pub ready: bool,
+        pub code: B11,
+    }
+}
+
+use regs::{Control, ControlAccessors, Status, StatusAccessors};
+
+fn main() {
+    let mut control = Control::new().with_mode(0b101);
+    control.set_enable(true);
+    control.set_prescaler(1000);
+    assert!(control.enable());
+    assert_eq!(control.mode(), 0b101);
+    assert_eq!(control.prescaler(), 1000);
+    assert_eq!(control.prescaler_or_err(), Ok(1000));
+    assert_eq!(
+        format!("{:?}", control),
+        "Control { enable: true, mode: 5, prescaler: 1000 }",
+    );
+
+    let mut status = Status::new().with_ready(true);
+    status.set_code(42);
+    assert!(status.ready());
+    assert_eq!(status.code(), 42);
+}
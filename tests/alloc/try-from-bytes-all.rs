@@ -0,0 +1,56 @@
+use modular_bitfield::error::FieldError;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    primary: Mode,
+    level: B4,
+    secondary: Mode,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, PartialEq)]
+pub struct Unfilled {
+    mode: Mode,
+    level: B3,
+}
+
+fn main() {
+    let config = Config::try_from_le_bytes_all([0b10_1111_01]).unwrap();
+    assert_eq!(config.primary(), Mode::Idle);
+    assert_eq!(config.level(), 0b1111);
+    assert_eq!(config.secondary(), Mode::Active);
+
+    assert_eq!(
+        Config::try_from_le_bytes_all([0b11_0000_11]),
+        Err(vec![
+            FieldError::InvalidBitPattern("primary"),
+            FieldError::InvalidBitPattern("secondary"),
+        ]),
+    );
+    assert_eq!(
+        Config::try_from_le_bytes_all([0b00_0000_11]),
+        Err(vec![FieldError::InvalidBitPattern("primary")]),
+    );
+
+    assert_eq!(
+        Unfilled::try_from_le_bytes_all([0b010_10]),
+        Ok(Unfilled::new().with_mode(Mode::Active).with_level(0b010)),
+    );
+    assert_eq!(
+        Unfilled::try_from_le_bytes_all([0b1_010_11]),
+        Err(vec![
+            FieldError::UndefinedBits,
+            FieldError::InvalidBitPattern("mode"),
+        ]),
+    );
+}
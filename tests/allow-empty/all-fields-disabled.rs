@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Disabled {
+    #[cfg(any())]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Empty {}
+
+fn main() {}
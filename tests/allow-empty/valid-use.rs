@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(allow_empty)]
+pub struct Empty {}
+
+fn main() {
+    let empty = Empty::new();
+    assert_eq!(Empty::BYTES, 0);
+    assert_eq!(empty.to_le_bytes(), [0u8; 0]);
+}
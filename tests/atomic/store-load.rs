@@ -0,0 +1,68 @@
+// Tests converting bitfields from and into atomic integers with the `atomic` crate feature.
+
+use modular_bitfield::prelude::*;
+use std::sync::atomic::{
+    AtomicU16,
+    AtomicU32,
+    Ordering,
+};
+
+#[bitfield]
+#[repr(u32)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Control {
+    enable: bool,
+    mode: B3,
+    count: B12,
+    reserved: u16,
+}
+
+#[bitfield(packed = false)]
+#[repr(u16)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Unpacked {
+    low: B4,
+    high: B12,
+}
+
+fn main() {
+    let register = AtomicU32::new(0);
+    let control = Control::new().with_enable(true).with_mode(0b101).with_count(0x123);
+    control.store_into(&register, Ordering::SeqCst);
+    assert_eq!(register.load(Ordering::SeqCst), u32::from(control));
+    assert_eq!(Control::load_from(&register, Ordering::SeqCst), control);
+
+    // Concurrent read-modify-write of a single field never loses updates.
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..100 {
+                    Control::fetch_update_in(&register, Ordering::SeqCst, Ordering::SeqCst, |control| {
+                        Some(control.with_count(control.count() + 1))
+                    })
+                    .unwrap();
+                }
+            });
+        }
+    });
+    let updated = Control::load_from(&register, Ordering::SeqCst);
+    assert_eq!(updated.count(), 0x123 + 400);
+    assert!(updated.enable());
+    assert_eq!(updated.mode(), 0b101);
+
+    // Returning `None` leaves the atomic unchanged and yields the current bitfield.
+    let current = Control::fetch_update_in(&register, Ordering::SeqCst, Ordering::SeqCst, |_| None);
+    assert_eq!(current, Err(updated));
+    assert_eq!(Control::load_from(&register, Ordering::SeqCst), updated);
+
+    let atomic = AtomicU16::new(0);
+    let unpacked = Unpacked::new().with_low(0xA).with_high(0x123);
+    unpacked.store_into(&atomic, Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), 0x123A);
+    assert_eq!(Unpacked::load_from(&atomic, Ordering::Relaxed), unpacked);
+    let previous = Unpacked::fetch_update_in(&atomic, Ordering::Relaxed, Ordering::Relaxed, |unpacked| {
+        Some(unpacked.with_low(0x1))
+    });
+    assert_eq!(previous, Ok(unpacked));
+    assert_eq!(atomic.load(Ordering::Relaxed), 0x1231);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, bcd)]
+#[derive(Clone, Copy)]
+pub struct Counter {
+    ones: B4,
+    tens: B4,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bcd, bcd)]
+pub struct Counter {
+    ones: B4,
+    tens: B4,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bcd)]
+pub struct Seconds {
+    ones: B4,
+    tens: B3,
+    halt: bool,
+}
+
+fn main() {
+    let _ = Seconds::new().add_bcd(Seconds::new());
+}
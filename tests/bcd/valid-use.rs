@@ -0,0 +1,37 @@
+// `bcd` bitfields can add their binary coded decimal digits with decimal carry.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bcd)]
+#[derive(Clone, Copy)]
+pub struct Counter {
+    ones: B4,
+    tens: B4,
+    hundreds: B8,
+}
+
+fn main() {
+    let a = Counter::new().with_ones(7).with_tens(3).with_hundreds(0x01);
+    let b = Counter::new().with_ones(5).with_tens(2);
+    // 137 + 25 = 162 with a carry from the ones into the tens digit.
+    let (sum, carry) = a.add_bcd(b);
+    assert!(!carry);
+    assert_eq!((sum.ones(), sum.tens(), sum.hundreds()), (2, 6, 0x01));
+
+    // 0999 + 1 carries through every digit, the `B8` field holding two digits.
+    let a = Counter::new().with_ones(9).with_tens(9).with_hundreds(0x09);
+    let one = Counter::new().with_ones(1);
+    let sum = a + one;
+    assert_eq!((sum.ones(), sum.tens(), sum.hundreds()), (0, 0, 0x10));
+
+    // 9999 + 1 carries out of the most significant digit.
+    let max = Counter::from_le_bytes([0x99, 0x99]);
+    let (sum, carry) = max.add_bcd(one);
+    assert!(carry);
+    assert_eq!(sum.to_le_bytes(), [0x00, 0x00]);
+
+    const SUM: (Counter, bool) =
+        Counter::from_le_bytes([0x45, 0x00]).add_bcd(Counter::from_le_bytes([0x55, 0x00]));
+    assert_eq!(SUM.0.to_le_bytes(), [0x00, 0x01]);
+    assert!(!SUM.1);
+}
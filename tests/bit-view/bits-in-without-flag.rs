@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    low: B4,
+    high: B4,
+}
+
+fn main() {
+    let register = Register::new();
+    let _ = register.bits_in(2..6);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12, packed = false, bit_view)]
+pub struct Register {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
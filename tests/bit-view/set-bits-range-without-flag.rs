@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    low: B4,
+    high: B4,
+}
+
+fn main() {
+    let mut register = Register::new();
+    register.set_bits_range(2..6, 0b1111);
+}
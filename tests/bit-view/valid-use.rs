@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bit_view)]
+pub struct Register {
+    low: B4,
+    flag: bool,
+    high: B11,
+}
+
+#[bitfield(filled = false, bit_view)]
+pub struct Unfilled {
+    a: B3,
+    b: B7,
+}
+
+fn main() {
+    let mut register = Register::new().with_low(0b1010).with_high(0b100_0000_0001);
+    assert_eq!(Register::BIT_LEN, 16);
+
+    let bits = register.iter_bits().collect::<Vec<_>>();
+    assert_eq!(bits.len(), 16);
+    let expected = [
+        false, true, false, true, // low
+        false, // flag
+        true, false, false, false, false, false, false, false, false, false, true, // high
+    ];
+    assert_eq!(bits, expected);
+    for (index, bit) in expected.iter().enumerate() {
+        assert_eq!(register[index], *bit);
+        assert_eq!(register.bit(index), *bit);
+    }
+
+    register.set_bit(4, true);
+    assert!(register.flag());
+    register.set_bit(1, false);
+    assert_eq!(register.low(), 0b1000);
+
+    let unfilled = Unfilled::new().with_b(0b100_0000);
+    assert_eq!(Unfilled::BIT_LEN, 10);
+    assert_eq!(unfilled.iter_bits().filter(|bit| *bit).count(), 1);
+    assert!(unfilled[9]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12)]
+pub struct Header {
+    kind: B4,
+    flags: B4,
+    len: B6,
+}
+
+fn main() {}
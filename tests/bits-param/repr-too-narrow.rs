@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 20, packed = false, filled = false)]
+#[repr(u16)]
+pub struct Register {
+    low: B8,
+    high: B8,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[repr(u16)]
+#[derive(Clone)]
+pub struct Register {
+    low: B4,
+    high: B8,
+}
+
+fn main() {
+    let register = Register::new().with_low(0xA).with_high(0x5C);
+    let value = u16::from(register.clone());
+    assert_eq!(value, 0x5CA);
+    let register = Register::from(value);
+    assert_eq!(register.low(), 0xA);
+    assert_eq!(register.high(), 0x5C);
+}
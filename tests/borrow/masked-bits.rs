@@ -0,0 +1,24 @@
+// Bitfields ignoring some bits in `PartialEq` and `Hash` must not borrow as their bytes
+// since lookups by bytes would disagree with lookups by bitfield.
+
+use modular_bitfield::prelude::*;
+use std::borrow::Borrow;
+
+#[bitfield]
+#[derive(Hash, Eq)]
+pub struct Tagged {
+    value: B12,
+    #[eq(ignore)]
+    generation: B4,
+}
+
+#[bitfield(filled = false)]
+#[derive(Hash, PartialEq, Eq)]
+pub struct Unfilled {
+    value: B12,
+}
+
+fn main() {
+    let _: &[u8] = Tagged::new().borrow();
+    let _: &[u8] = Unfilled::new().borrow();
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, borrowed_view)]
+pub struct Record {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use modular_bitfield::prelude::*;
+use core::convert::TryInto;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Kind {
+    Data,
+    Ack,
+    Nack,
+}
+
+#[bitfield(borrowed_view)]
+pub struct Record {
+    kind: Kind,
+    seq: B6,
+    #[skip]
+    __: B4,
+    len: B12,
+}
+
+#[bitfield(filled = false, borrowed_view)]
+pub struct Pair(B3, bool);
+
+fn main() {
+    let first = Record::new().with_kind(Kind::Data).with_seq(1).with_len(0x123);
+    let second = Record::new().with_kind(Kind::Ack).with_seq(2).with_len(0xABC);
+    let bytes = [first.to_le_bytes(), second.to_le_bytes()].concat();
+
+    // Reading the fields of every record directly from a large byte buffer.
+    let views = bytes
+        .chunks_exact(Record::BYTES)
+        .map(|chunk| RecordView(chunk.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(views[0].kind(), Kind::Data);
+    assert_eq!(views[0].seq(), 1);
+    assert_eq!(views[0].len(), 0x123);
+    assert_eq!(views[1].kind(), Kind::Ack);
+    assert_eq!(views[1].seq(), 2);
+    assert_eq!(views[1].len(), 0xABC);
+
+    // Invalid bit patterns are reported by the checked getters.
+    let invalid = [0b0000_0011, 0x00, 0x00];
+    assert!(RecordView(&invalid).kind_or_err().is_err());
+
+    let pair = Pair::new().with_0(5).with_1(true).to_le_bytes();
+    let view = PairView(&pair);
+    assert_eq!(view.get_0(), 5);
+    assert!(view.get_1());
+}
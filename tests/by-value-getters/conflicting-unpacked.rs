@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, by_value_getters)]
+#[derive(Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    level: B7,
+}
+
+fn main() {}
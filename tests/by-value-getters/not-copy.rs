@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(by_value_getters)]
+pub struct Status {
+    ready: bool,
+    level: B7,
+}
+
+fn main() {}
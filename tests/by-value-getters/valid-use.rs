@@ -0,0 +1,49 @@
+// Tests getters taking `self` by value with `#[bitfield(by_value_getters)]`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield(by_value_getters)]
+#[derive(Debug, Clone, Copy)]
+pub struct Status {
+    mode: Mode,
+    ready: bool,
+    level: B5,
+}
+
+#[bitfield(by_value_getters, clone_copy)]
+#[repr(u8)]
+pub struct Flags {
+    a: bool,
+    b: B7,
+}
+
+const FLAGS_B: u8 = Flags::from_le_bytes([0b1000_0011]).b();
+
+fn main() {
+    let status = Status::new().with_mode(Mode::Active).with_ready(true).with_level(17);
+    // The getters consume a copy of `status` which stays usable.
+    assert_eq!(status.mode(), Mode::Active);
+    assert!(status.ready());
+    assert_eq!(status.level(), 17);
+    assert_eq!(status.level_or_err(), Ok(17));
+    assert_eq!(status.get_mode_checked(), Some(Mode::Active));
+    assert_eq!(status.get_level_raw(), 17);
+
+    let statuses = [status, Status::new()];
+    let levels = statuses.iter().map(|status| status.level()).collect::<Vec<_>>();
+    assert_eq!(levels, vec![17, 0]);
+    assert_eq!(format!("{:?}", Status::new()), "Status { mode: Sleep, ready: false, level: 0 }");
+
+    let flags = Flags::new().with_a(true).with_b(0x41);
+    assert!(flags.a());
+    assert_eq!(flags.b(), 0x41);
+    assert_eq!(FLAGS_B, 0x41);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(chained_setters, chained_setters)]
+pub struct Control {
+    mode: B4,
+    divider: B4,
+}
+
+fn main() {}
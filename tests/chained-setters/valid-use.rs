@@ -0,0 +1,31 @@
+// Tests the `set_f_chained` setters generated by `#[bitfield(chained_setters)]`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(chained_setters)]
+#[derive(Debug, PartialEq)]
+pub struct Control {
+    mode: B3,
+    enabled: bool,
+    divider: B4,
+}
+
+#[bitfield(chained_setters, bits = 12, filled = false, packed = false)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unpacked {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let mut control = Control::new();
+    control.set_mode_chained(5).set_enabled_chained(true).set_divider_chained(0xA);
+    assert_eq!(control, Control::new().with_mode(5).with_enabled(true).with_divider(0xA));
+    control.set_mode_chained(1);
+    assert_eq!(control.mode(), 1);
+    assert_eq!(control.divider(), 0xA);
+
+    let mut unpacked = Unpacked::new();
+    unpacked.set_a_chained(1).set_b_chained(2);
+    assert_eq!(unpacked, Unpacked::new().with_a(1).with_b(2));
+}
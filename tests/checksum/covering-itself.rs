@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    #[checksum(over = kind..version, algo = xor)]
+    crc: B4,
+    version: u8,
+}
+
+fn main() {}
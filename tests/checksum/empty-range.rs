@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    #[checksum(over = length..=kind, algo = xor)]
+    crc: u8,
+    kind: u8,
+    length: u8,
+    flags: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    version: B4,
+    #[checksum(over = kind..length, algo = xor)]
+    crc: u8,
+}
+
+fn main() {}
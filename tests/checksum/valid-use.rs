@@ -0,0 +1,93 @@
+// Tests `#[checksum(..)]` fields checked by `validate` and recomputed by `set_checksum`.
+
+use modular_bitfield::{
+    error::ValidateError,
+    prelude::*,
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Header {
+    kind: B4,
+    version: B4,
+    length: u8,
+    flags: u8,
+    #[checksum(over = kind..crc, algo = xor)]
+    crc: u8,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Narrow {
+    #[checksum(over = a..c, algo = xor)]
+    crc: B4,
+    #[reserved]
+    reserved: B4,
+    a: B4,
+    b: B8,
+    c: B4,
+}
+
+// Checksums in front of the covered fields may cover up to and including the last field.
+#[bitfield]
+#[derive(Debug)]
+pub struct Trailer {
+    #[checksum(over = a..=c, algo = xor)]
+    crc: u8,
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct OpenEnded {
+    #[checksum(over = a.., algo = xor)]
+    crc: u8,
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+fn main() {
+    let mut header = Header::new()
+        .with_kind(0x2)
+        .with_version(0x1)
+        .with_length(0x40)
+        .with_flags(0x0F);
+    assert_eq!(
+        header.validate(),
+        Err(ValidateError::ChecksumMismatch { field: "crc", expected: 0x12 ^ 0x40 ^ 0x0F, found: 0 }),
+    );
+    header.set_checksum();
+    assert_eq!(header.crc(), 0x12 ^ 0x40 ^ 0x0F);
+    assert_eq!(header.validate(), Ok(()));
+    header.set_length(0x41);
+    assert!(header.validate().is_err());
+
+    // The 12 covered bits are folded into chunks of 4 bits.
+    let mut narrow = Narrow::new().with_a(0x3).with_b(0xA5).with_c(0xF);
+    narrow.set_checksum();
+    assert_eq!(narrow.crc(), 0x3 ^ 0x5 ^ 0xA);
+    assert_eq!(narrow.c(), 0xF);
+    assert_eq!(narrow.validate(), Ok(()));
+    let narrow = Narrow::from_le_bytes([0x10 | narrow.crc(), 0x53, 0xFA]);
+    assert!(matches!(narrow.validate(), Err(ValidateError::NonZeroReserved(_))));
+
+    // The last field is part of the covered bits.
+    let mut trailer = Trailer::new().with_a(0x01).with_b(0x02).with_c(0x40);
+    trailer.set_checksum();
+    assert_eq!(trailer.crc(), 0x43);
+    assert_eq!(trailer.validate(), Ok(()));
+    trailer.set_c(0x41);
+    assert!(trailer.validate().is_err());
+
+    let mut open_ended = OpenEnded::new().with_a(0x01).with_b(0x02).with_c(0x40);
+    open_ended.set_checksum();
+    assert_eq!(open_ended.crc(), 0x43);
+    open_ended.set_c(0x41);
+    assert_eq!(
+        open_ended.validate(),
+        Err(ValidateError::ChecksumMismatch { field: "crc", expected: 0x42, found: 0x43 }),
+    );
+}
@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+
+pub struct Level(u8);
+
+pub struct LevelSpecifier;
+
+impl Specifier for LevelSpecifier {
+    const BITS: usize = 6;
+    type Bytes = u8;
+    type InOut = Level;
+
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, modular_bitfield::error::OutOfBounds> {
+        Ok(input.0)
+    }
+
+    fn from_bytes(
+        bytes: Self::Bytes,
+    ) -> Result<Self::InOut, modular_bitfield::error::InvalidBitPattern<Self::Bytes>> {
+        Ok(Level(bytes))
+    }
+}
+
+#[bitfield(clone_copy)]
+pub struct Packed {
+    mode: B2,
+    level: LevelSpecifier,
+}
+
+fn main() {}
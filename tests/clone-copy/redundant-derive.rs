@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(clone_copy)]
+#[derive(Clone)]
+pub struct Packed {
+    mode: B2,
+    level: B6,
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield(clone_copy)]
+#[derive(Debug)]
+pub struct Packed {
+    mode: Mode,
+    level: B6,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false, clone_copy)]
+pub struct Unpacked {
+    mode: Mode,
+    level: B6,
+}
+
+fn main() {
+    let original = Packed::new().with_mode(Mode::Active).with_level(42);
+    let mut copy = original;
+    copy.set_level(7);
+    assert_eq!(original.level(), 42);
+    assert_eq!(copy.level(), 7);
+    assert_eq!(copy.clone().mode(), Mode::Active);
+
+    let original = Unpacked::new().with_mode(Mode::Idle).with_level(3);
+    let mut copy = original;
+    copy.set_mode(Mode::Sleep);
+    assert_eq!(original.mode(), Mode::Idle);
+    assert_eq!(copy.mode(), Mode::Sleep);
+}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield(bits = 8, packed = false)]
+pub struct Entry {
+    #[const_enum]
+    mode: Mode,
+    value: B7,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield]
+pub struct Entry {
+    #[const_enum(yes)]
+    mode: Mode,
+    value: B7,
+}
+
+fn main() {}
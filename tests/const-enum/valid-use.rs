@@ -0,0 +1,45 @@
+// Tests reading `#[const_enum]` fields in `const` contexts.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield]
+pub struct Entry {
+    flag: bool,
+    #[const_enum]
+    mode: Mode,
+    value: B5,
+    #[const_enum]
+    other: Mode,
+    #[skip]
+    __: B6,
+}
+
+const ENTRIES: [Entry; 2] = [
+    Entry::from_le_bytes([0b0000_0100, 0b0000_0001]),
+    Entry::from_le_bytes([0b0000_0010, 0b0000_0011]),
+];
+const FIRST: Option<Mode> = ENTRIES[0].get_mode_const();
+const SECOND: Option<Mode> = ENTRIES[1].get_mode_const();
+const FIRST_OTHER: Option<Mode> = ENTRIES[0].get_other_const();
+const SECOND_OTHER: Option<Mode> = ENTRIES[1].get_other_const();
+const IS_C: bool = matches!(FIRST, Some(Mode::C));
+
+fn main() {
+    assert_eq!(FIRST, Some(Mode::C));
+    assert_eq!(SECOND, Some(Mode::B));
+    assert_eq!(FIRST_OTHER, Some(Mode::B));
+    assert_eq!(SECOND_OTHER, None);
+    assert!(IS_C);
+
+    let entry = Entry::new().with_mode(Mode::A).with_other(Mode::C);
+    assert_eq!(entry.get_mode_const(), Some(Mode::A));
+    assert_eq!(entry.get_other_const(), Some(Mode::C));
+}
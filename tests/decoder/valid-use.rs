@@ -0,0 +1,58 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(decoder)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Header {
+    kind: B4,
+    len: B12,
+    id: B16,
+}
+
+#[bitfield(filled = false, decoder)]
+#[derive(Debug)]
+pub struct Unfilled {
+    a: B4,
+    b: B8,
+}
+
+fn main() {
+    let header = Header::new().with_kind(0x3).with_len(0x123).with_id(0xBEEF);
+    let bytes = header.to_le_bytes();
+
+    // Feeding bytes one at a time completes the header on the fourth byte.
+    let mut decoder = HeaderDecoder::new();
+    for (index, byte) in bytes.iter().enumerate() {
+        let (decoded, rest) = decoder.push_bytes(core::slice::from_ref(byte));
+        assert!(rest.is_empty());
+        if index + 1 < Header::BYTES {
+            assert_eq!(decoded, None);
+            assert_eq!(decoder.buffered(), index + 1);
+        } else {
+            let decoded = decoded.unwrap();
+            assert_eq!(decoded.kind(), 0x3);
+            assert_eq!(decoded.len(), 0x123);
+            assert_eq!(decoded.id(), 0xBEEF);
+            assert_eq!(decoder.buffered(), 0);
+        }
+    }
+
+    // Messages split across reads resume where the previous read ended.
+    let stream = [bytes, bytes].concat();
+    let mut decoder = HeaderDecoder::default();
+    let (decoded, rest) = decoder.push_bytes(&stream[..3]);
+    assert_eq!(decoded, None);
+    assert!(rest.is_empty());
+    let (decoded, rest) = decoder.push_bytes(&stream[3..]);
+    assert_eq!(decoded.unwrap().id(), 0xBEEF);
+    assert_eq!(rest.len(), Header::BYTES);
+    let (decoded, rest) = decoder.push_bytes(rest);
+    assert_eq!(decoded.unwrap().len(), 0x123);
+    assert!(rest.is_empty());
+
+    // Unfilled bitfields report invalid trailing bits.
+    let mut decoder = UnfilledDecoder::new();
+    let (decoded, _) = decoder.push_bytes(&[0xFF, 0x0F]);
+    assert_eq!(decoded.unwrap().unwrap().b(), 0xFF);
+    let (decoded, _) = decoder.push_bytes(&[0x00, 0x10]);
+    assert!(decoded.unwrap().is_err());
+}
@@ -0,0 +1,26 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(defmt::Format)]
+pub struct Header {
+    kind: B4,
+    ready: bool,
+    #[skip]
+    __: B3,
+    length: u8,
+}
+
+#[bitfield]
+#[derive(Debug, Format)]
+pub struct Pair(B3, B5);
+
+use defmt::Format;
+
+fn assert_format<T: defmt::Format>() {}
+
+fn main() {
+    assert_format::<Header>();
+    assert_format::<Pair>();
+    assert_format::<modular_bitfield::error::OutOfBounds>();
+    assert_format::<modular_bitfield::error::InvalidBitPattern<u8>>();
+}
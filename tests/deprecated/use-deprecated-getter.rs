@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    #[deprecated = "use `mode` instead"]
+    old_mode: B4,
+    mode: B4,
+}
+
+fn main() {
+    let register = Register::new().with_mode(1);
+    let _ = register.old_mode(); // ERROR!
+}
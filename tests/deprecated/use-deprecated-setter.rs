@@ -0,0 +1,15 @@
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Register {
+    #[deprecated = "use `mode` instead"]
+    old_mode: B4,
+    mode: B4,
+}
+
+fn main() {
+    let _ = Register::new().with_old_mode(1); // ERROR!
+}
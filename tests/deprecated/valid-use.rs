@@ -0,0 +1,45 @@
+// Tests that the generated code itself does not use the accessors of `#[deprecated]` fields
+// in a way that triggers deprecation warnings.
+
+#![deny(deprecated)]
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(iter_flags, ord = fields)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Register {
+    #[deprecated = "use `mode` instead"]
+    old_mode: B4,
+    mode: B3,
+    #[deprecated = "use `mode` instead"]
+    #[group(flags)]
+    enabled: bool,
+}
+
+#[bitfield(accessors_in_trait)]
+pub struct InTrait {
+    #[deprecated = "use `b` instead"]
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Debug, Clone)]
+pub struct Unpacked {
+    #[deprecated = "use `mode` instead"]
+    old_mode: B4,
+    mode: B4,
+}
+
+fn main() {
+    let register = Register::new().with_mode(0b101);
+    assert_eq!(register.mode(), 0b101);
+    assert_eq!(format!("{:?}", register), "Register { old_mode: 0, mode: 5, enabled: false }");
+    assert!(register > Register::new());
+
+    let in_trait = InTrait::new().with_b(3);
+    assert_eq!(in_trait.b(), 3);
+
+    let unpacked = Unpacked::new().with_mode(5);
+    assert_eq!(unpacked.mode(), 5);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 14, packed = false, filled = false, deref_mut)]
+#[derive(Clone)]
+pub struct UserId {
+    value: B12,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 14, packed = false, filled = false, deref)]
+#[derive(Clone)]
+pub struct UserId {
+    value: B12,
+}
+
+fn main() {
+    let mut id = UserId::new();
+    *id = 0x1FFF;
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 14, packed = false, filled = false, deref)]
+#[derive(Clone)]
+pub struct Pair {
+    low: B6,
+    high: B6,
+}
+
+fn main() {}
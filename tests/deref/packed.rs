@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(deref)]
+pub struct UserId {
+    value: B16,
+}
+
+fn main() {}
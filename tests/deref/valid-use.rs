@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 14, packed = false, filled = false, deref)]
+#[derive(Clone)]
+pub struct UserId {
+    value: B12,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false, deref, deref_mut)]
+#[derive(Clone)]
+pub struct GroupId {
+    value: B12,
+}
+
+fn takes_u16(value: &u16) -> u16 {
+    *value
+}
+
+fn main() {
+    let id = UserId::new().with_value(0x123);
+    assert_eq!(*id, 0x123);
+    assert_eq!(takes_u16(&id), 0x123);
+    assert_eq!(id.count_ones(), 4);
+
+    let mut group = GroupId::new().with_value(0x123);
+    assert_eq!(*group, 0x123);
+    *group = 0x456;
+    assert_eq!(group.value(), 0x456);
+}
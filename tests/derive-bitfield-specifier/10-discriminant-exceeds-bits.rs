@@ -0,0 +1,15 @@
+// A discriminant that does not fit into the explicitly declared `#[bits = N]`
+// must be rejected instead of being silently truncated.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Priority {
+    Low = 0,
+    Medium = 1,
+    High = 2,
+    Critical = 4,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield(debug = by_byte)]
+#[derive(Debug)]
+pub struct Color {
+    r: B6,
+    g: B6,
+    b: B6,
+    #[skip]
+    __: B6,
+    mode: Mode,
+    #[units = "ms"]
+    delay: B6,
+}
+
+fn main() {
+    let color = Color::new()
+        .with_r(63)
+        .with_g(32)
+        .with_b(16)
+        .with_mode(Mode::B)
+        .with_delay(5);
+    assert_eq!(
+        format!("{:?}", color),
+        "Color {byte_0: {r: 63, g: 32}, byte_1: {b: 16}, byte_3: {mode: B, delay: 5 ms}}",
+    );
+    assert_eq!(
+        format!("{:#?}", Color::new().with_r(1)),
+        "Color {\n    byte_0: {\n        r: 1,\n        g: 0,\n    },\n    byte_1: {\n        b: 0,\n    },\n    byte_3: {\n        mode: A,\n        delay: 0 ms,\n    },\n}",
+    );
+
+    // Invalid bit patterns are printed as their error.
+    let invalid = Color::from_le_bytes([0x00, 0x00, 0x00, 0b0000_0011]);
+    assert_eq!(
+        format!("{:?}", invalid),
+        "Color {byte_0: {r: 0, g: 0}, byte_1: {b: 0}, byte_3: {mode: InvalidBitPattern { invalid_bytes: 3 }, delay: 0 ms}}",
+    );
+}
@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield(debug = compact)]
+#[derive(Debug)]
+pub struct Status {
+    ready: bool,
+    code: B5,
+    mode: Mode,
+    #[skip]
+    __: B4,
+    #[units = "ms"]
+    delay: B4,
+}
+
+#[bitfield(debug = compact)]
+#[derive(Debug)]
+pub struct Pair(B4, B4);
+
+fn main() {
+    let status = Status::new()
+        .with_ready(true)
+        .with_code(17)
+        .with_mode(Mode::B)
+        .with_delay(5);
+    assert_eq!(format!("{:?}", status), "Status(ready=true,code=17,mode=B,delay=5 ms)");
+    assert_eq!(format!("{:?}", Pair::new().with_0(1).with_1(2)), "Pair(0=1,1=2)");
+
+    // Invalid bit patterns are printed as their error.
+    let invalid = Status::from_le_bytes([0b1100_0000, 0x00]);
+    assert_eq!(
+        format!("{:?}", invalid),
+        "Status(ready=false,code=0,mode=InvalidBitPattern { invalid_bytes: 3 },delay=0 ms)",
+    );
+}
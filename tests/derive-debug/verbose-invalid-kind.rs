@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(debug = pretty)]
+#[derive(Debug)]
+pub struct MyField {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(debug = verbose)]
+pub struct MyField {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(debug = verbose)]
+#[derive(Debug)]
+pub struct MyField {
+    a: B4,
+    b: B4,
+}
+
+#[bitfield(debug = verbose)]
+#[derive(Debug)]
+pub struct Color {
+    r: B6,
+    g: B6,
+    b: B6,
+    a: B6,
+}
+
+fn main() {
+    let field = MyField::new().with_a(1).with_b(2);
+    assert_eq!(format!("{:?}", field), "MyField { a: 1, b: 2, raw: 0x21 }");
+
+    let color = Color::new()
+        .with_r(63)
+        .with_g(32)
+        .with_b(16)
+        .with_a(8);
+    assert_eq!(
+        format!("{:?}", color),
+        "Color { r: 63, g: 32, b: 16, a: 8, raw: 0x21083f }",
+    );
+    assert_eq!(
+        format!("{:#?}", color),
+        "Color {\n    r: 63,\n    g: 32,\n    b: 16,\n    a: 8,\n    raw: 0x21083f,\n}",
+    );
+}
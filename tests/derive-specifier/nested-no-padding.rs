@@ -0,0 +1,34 @@
+// Nested unfilled bitfields occupy exactly their bit width within the outer bitfield,
+// so two 4-bit structs share a single byte without any padding in between.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false)]
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Nibble {
+    flag: bool,
+    value: B3,
+}
+
+#[bitfield]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Pair {
+    low: Nibble,
+    high: Nibble,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Nibble>(), 1);
+    assert_eq!(core::mem::size_of::<Pair>(), 1);
+    assert_eq!(Pair::low_range(), 0..4);
+    assert_eq!(Pair::high_range(), 4..8);
+
+    let pair = Pair::new()
+        .with_low(Nibble::new().with_flag(true).with_value(0b010))
+        .with_high(Nibble::new().with_flag(false).with_value(0b111));
+    assert_eq!(pair.to_le_bytes(), [0b1110_0101]);
+
+    let pair = Pair::from_le_bytes([0b0011_1000]);
+    assert_eq!(pair.low(), Nibble::new().with_value(0b100));
+    assert_eq!(pair.high(), Nibble::new().with_flag(true).with_value(0b001));
+}
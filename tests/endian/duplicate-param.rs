@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(endian = both, endian = both)]
+pub struct Word {
+    low: B8,
+    high: B8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(endian = big)]
+pub struct Word {
+    low: B8,
+    high: B8,
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(endian = both)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Word {
+    low: B8,
+    high: B8,
+}
+
+#[bitfield(endian = both, filled = false)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Partial {
+    value: B12,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false, endian = both)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Unpacked {
+    flag: bool,
+    value: B11,
+}
+
+fn main() {
+    let word = Word::new().with_low(0x34).with_high(0x12);
+    assert_eq!(word.to_le_bytes(), [0x34, 0x12]);
+    assert_eq!(word.to_be_bytes(), [0x12, 0x34]);
+    assert_eq!(Word::from_le_bytes([0x34, 0x12]), word);
+    assert_eq!(Word::from_be_bytes([0x12, 0x34]), word);
+
+    let mut loaded = Word::new();
+    loaded.load_be_bytes([0xAB, 0xCD]);
+    assert_eq!(loaded.high(), 0xAB);
+    assert_eq!(loaded.low(), 0xCD);
+
+    let (parsed, rest) = Word::parse_be(&[0x12, 0x34, 0xFF]).unwrap();
+    assert_eq!(parsed, word);
+    assert_eq!(rest, &[0xFF]);
+    assert!(Word::parse_be(&[0x12]).is_err());
+
+    let mut updated = Word::new();
+    updated.update_byte_be(0, 0x12);
+    updated.update_byte_le(0, 0x34);
+    assert_eq!(updated, word);
+    assert!(updated.try_update_byte_le(1, 0x56).is_ok());
+    assert_eq!(updated.high(), 0x56);
+    assert!(updated.try_update_byte_le(2, 0x00).is_err());
+    assert!(updated.try_update_byte_be(2, 0x00).is_err());
+
+    let partial = Partial::new().with_value(0xABC);
+    assert_eq!(partial.to_be_bytes(), [0x0A, 0xBC]);
+    assert_eq!(Partial::from_be_bytes([0x0A, 0xBC]), Ok(partial));
+    assert!(Partial::from_be_bytes([0x1A, 0xBC]).is_err());
+    let mut partial_loaded = partial;
+    assert!(partial_loaded.load_be_bytes([0xF0, 0x00]).is_err());
+    assert_eq!(partial_loaded, partial);
+    assert!(Partial::parse_be(&[0x10, 0x00]).is_err());
+
+    let unpacked = Unpacked::new().with_flag(true).with_value(0x0123);
+    let be = unpacked.to_be_bytes();
+    assert_eq!(Unpacked::from_be_bytes(be), Ok(unpacked));
+    let mut unpacked_loaded = Unpacked::new();
+    assert!(unpacked_loaded.load_be_bytes(be).is_ok());
+    assert_eq!(unpacked_loaded, unpacked);
+    assert!(unpacked_loaded.load_be_bytes([0xC0, 0x00]).is_err());
+    assert_eq!(unpacked_loaded, unpacked);
+    assert!(unpacked_loaded.try_update_byte_le(0, 0x00).is_ok());
+    assert!(unpacked_loaded.try_update_byte_le(2, 0x00).is_err());
+}
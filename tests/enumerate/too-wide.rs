@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(enumerate)]
+pub struct Wide {
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+fn main() {}
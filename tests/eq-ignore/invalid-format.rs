@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Snapshot {
+    enabled: bool,
+    mode: B3,
+    #[eq(skip)]
+    counter: B12,
+}
+
+fn main() {}
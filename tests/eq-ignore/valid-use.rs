@@ -0,0 +1,33 @@
+// Tests the `PartialEq` impl generated for bitfields with `#[eq(ignore)]` fields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Clone, Copy, Debug)]
+pub struct Snapshot {
+    enabled: bool,
+    mode: B3,
+    #[eq(ignore)]
+    counter: B12,
+}
+
+#[bitfield(bits = 16, packed = false)]
+#[derive(Clone, Debug)]
+pub struct Unpacked {
+    #[eq(ignore)]
+    timestamp: B8,
+    status: B8,
+}
+
+fn main() {
+    let first = Snapshot::new().with_enabled(true).with_mode(5).with_counter(100);
+    let second = Snapshot::new().with_enabled(true).with_mode(5).with_counter(4000);
+    assert_eq!(first, second);
+    assert_ne!(first, second.with_mode(4));
+    assert_ne!(first, second.with_enabled(false));
+
+    let first = Unpacked::new().with_timestamp(1).with_status(0xA5);
+    let second = Unpacked::new().with_timestamp(2).with_status(0xA5);
+    assert_eq!(first, second);
+    assert_ne!(first, second.with_status(0xA4));
+}
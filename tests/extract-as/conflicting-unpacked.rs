@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false)]
+pub struct Header {
+    #[extract_as(Priority)]
+    priority: B4,
+    kind: B4,
+}
+
+fn main() {}
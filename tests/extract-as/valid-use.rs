@@ -0,0 +1,58 @@
+// Tests extracting a field into its own standalone type that can be used independently.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Copy, Clone, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Header {
+    #[extract_as(Priority)]
+    priority: B4,
+    #[extract_as(HeaderMode)]
+    mode: Mode,
+    rest: B2,
+}
+
+// The extracted type is itself a specifier and can be used in other bitfields.
+#[bitfield]
+pub struct Task {
+    priority: Priority,
+    id: B4,
+}
+
+fn takes_priority(priority: Priority) -> u8 {
+    priority.get() * 2
+}
+
+fn main() {
+    let header = Header::new().with_priority(0xA).with_mode(Mode::C);
+    let priority = header.extract_priority();
+    assert_eq!(priority, Priority::new(0xA));
+    assert_eq!(priority.get(), 0xA);
+    assert_eq!(priority.into_bits(), 0xA);
+    assert_eq!(takes_priority(priority), 20);
+    assert_eq!(header.extract_mode().get(), Mode::C);
+
+    // Out of bounds values and invalid bit patterns are rejected.
+    assert!(Priority::new_checked(0x10).is_err());
+    assert!(Priority::from_bits(0x10).is_err());
+    assert!(HeaderMode::from_bits(0b11).is_err());
+    assert_eq!(HeaderMode::from_bits(0b01).map(HeaderMode::get), Ok(Mode::B));
+
+    let header = header.with_priority_extracted(Priority::new(0x3));
+    assert_eq!(header.priority(), 0x3);
+    let mut header = header;
+    header.set_mode_extracted(HeaderMode::new(Mode::A));
+    assert_eq!(header.mode(), Mode::A);
+
+    let task = Task::new().with_priority(priority).with_id(0x5);
+    assert_eq!(task.priority(), Priority::new(0xA));
+    assert_eq!(task.to_le_bytes(), [0x5A]);
+}
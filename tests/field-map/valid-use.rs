@@ -0,0 +1,71 @@
+use modular_bitfield::error::FieldError;
+use modular_bitfield::prelude::*;
+use std::collections::HashMap;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Config {
+    enabled: bool,
+    mode: Mode,
+    level: B5,
+    #[skip(setters)]
+    status: B8,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    enabled: bool,
+    level: B11,
+}
+
+fn main() {
+    let mut map = HashMap::new();
+    map.insert("enabled", 1);
+    map.insert("mode", 2);
+    map.insert("level", 17);
+    let config = Config::from_field_map(&map).unwrap();
+    assert!(config.enabled());
+    assert_eq!(config.mode(), Mode::Active);
+    assert_eq!(config.level(), 17);
+    assert_eq!(config.status(), 0);
+
+    map.remove("mode");
+    let unpacked = Unpacked::from_field_map(&map).unwrap();
+    assert!(unpacked.enabled());
+    assert_eq!(unpacked.level(), 17);
+
+    map.insert("mode", 2);
+    map.insert("level", 32);
+    assert_eq!(
+        Config::from_field_map(&map).err(),
+        Some(FieldError::OutOfBounds("level"))
+    );
+    map.insert("level", 31);
+    map.insert("mode", 3);
+    assert_eq!(
+        Config::from_field_map(&map).err(),
+        Some(FieldError::InvalidBitPattern("mode"))
+    );
+    map.insert("mode", 0);
+    map.insert("status", 1);
+    assert_eq!(
+        Config::from_field_map(&map).err(),
+        Some(FieldError::UnknownField(String::from("status")))
+    );
+
+    let mut unknown = HashMap::new();
+    unknown.insert("missing", 0);
+    assert_eq!(
+        Config::from_field_map(&unknown).err(),
+        Some(FieldError::UnknownField(String::from("missing")))
+    );
+    assert_eq!(Config::from_field_map(&HashMap::new()).unwrap().to_le_bytes(), [0x00; 2]);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(float_view(mantissa = mantissa, exponent = exponent))]
+pub struct Minifloat {
+    mantissa: B7,
+    exponent: bool,
+}
+
+fn main() {}
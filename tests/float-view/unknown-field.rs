@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(float_view(mantissa = mantissa, exponent = exp))]
+pub struct Minifloat {
+    mantissa: B4,
+    exponent: B4,
+}
+
+fn main() {}
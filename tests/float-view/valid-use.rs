@@ -0,0 +1,39 @@
+use modular_bitfield::prelude::*;
+
+// A tiny 1.4.3 layout without the implicit leading one.
+#[bitfield(float_view(mantissa = mantissa, exponent = exponent, bias = 7))]
+#[derive(Clone, Copy)]
+pub struct Minifloat {
+    mantissa: B3,
+    exponent: B4,
+    sign: bool,
+}
+
+#[bitfield(bits = 24, packed = false, float_view(exponent = exp, mantissa = frac))]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    frac: B12,
+    exp: B6,
+    #[skip]
+    __: B6,
+}
+
+#[bitfield(float_view(mantissa = mantissa, exponent = exponent, bias = -2))]
+pub struct Wide {
+    mantissa: u64,
+    exponent: u16,
+}
+
+fn main() {
+    assert_eq!(Minifloat::new().to_f64(), 0.0);
+    assert_eq!(Minifloat::new().with_mantissa(5).with_exponent(7).to_f64(), 5.0);
+    assert_eq!(Minifloat::new().with_mantissa(3).with_exponent(9).to_f64(), 12.0);
+    assert_eq!(Minifloat::new().with_mantissa(1).with_exponent(0).to_f64(), 1.0 / 128.0);
+    assert_eq!(Minifloat::new().with_mantissa(7).with_exponent(15).to_f64(), 1792.0);
+
+    assert_eq!(Unpacked::new().with_frac(3).with_exp(2).to_f64(), 12.0);
+
+    assert_eq!(Wide::new().with_mantissa(1).with_exponent(0).to_f64(), 4.0);
+    assert_eq!(Wide::new().with_mantissa(1).with_exponent(1020).to_f64(), 2f64.powi(1022));
+    assert_eq!(Wide::new().with_mantissa(1).with_exponent(1100).to_f64(), f64::INFINITY);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(from_field)]
+pub struct Pair {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
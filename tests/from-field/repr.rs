@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(from_field)]
+#[repr(u8)]
+pub struct Byte {
+    value: u8,
+}
+
+fn main() {}
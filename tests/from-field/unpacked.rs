@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(packed = false, from_field)]
+pub struct Unpacked {
+    value: u16,
+}
+
+fn main() {}
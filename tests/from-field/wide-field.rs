@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+// The `TryFrom<u128>` impl would overlap with the one every bitfield has.
+#[bitfield(from_field)]
+pub struct Wide {
+    value: u128,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(from_str, from_str)]
+pub struct Register {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+// Tests the `FromStr` impl generated by `#[bitfield(from_str)]`.
+
+use modular_bitfield::{
+    error::ParseFieldsError,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    Slow = 1,
+    Fast = 2,
+}
+
+#[bitfield(from_str)]
+#[derive(Debug, PartialEq)]
+pub struct Register {
+    a: B4,
+    b: bool,
+    mode: Mode,
+    #[skip(setters)]
+    status: bool,
+    wide: u8,
+}
+
+#[bitfield(from_str, bits = 12, filled = false, packed = false)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unpacked {
+    a: B4,
+    b: B4,
+}
+
+fn main() {
+    let register: Register = "a=3,b=true, mode = 2 ,wide=0xF_F".parse().unwrap();
+    assert_eq!(register.a(), 3);
+    assert!(register.b());
+    assert_eq!(register.mode(), Mode::Fast);
+    assert_eq!(register.wide(), 0xFF);
+    assert_eq!("".parse::<Register>(), Ok(Register::new()));
+    assert_eq!("a=0b101,".parse::<Register>(), Ok(Register::new().with_a(0b101)));
+
+    assert_eq!("a".parse::<Register>(), Err(ParseFieldsError::InvalidSyntax));
+    assert_eq!("c=1".parse::<Register>(), Err(ParseFieldsError::UnknownField));
+    assert_eq!("status=1".parse::<Register>(), Err(ParseFieldsError::UnknownField));
+    assert_eq!("a=yes".parse::<Register>(), Err(ParseFieldsError::InvalidValue("a")));
+    assert_eq!("a=16".parse::<Register>(), Err(ParseFieldsError::OutOfBounds("a")));
+    assert_eq!("b=2".parse::<Register>(), Err(ParseFieldsError::OutOfBounds("b")));
+    assert_eq!("mode=3".parse::<Register>(), Err(ParseFieldsError::InvalidBitPattern("mode")));
+
+    let unpacked: Unpacked = "a=1,b=15".parse().unwrap();
+    assert_eq!(unpacked, Unpacked::new().with_a(1).with_b(15));
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register<const N: usize> {
+    flag: bool,
+    value: B7,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register<T> {
+    flag: bool,
+    value: B7,
+}
+
+fn main() {}
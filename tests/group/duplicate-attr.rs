@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Cursor {
+    #[group(position)]
+    #[group(location)]
+    x: B12,
+    visible: bool,
+    y: B11,
+    blink: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Cursor {
+    #[group(position)]
+    x: B12,
+    visible: bool,
+    #[group(position)]
+    y: B11,
+    blink: u8,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Cursor {
+    visible: bool,
+    #[group(position)]
+    x: B12,
+    #[group(position)]
+    y: B11,
+    blink: u8,
+}
+
+fn main() {
+    let cursor = Cursor::new().with_visible(true).with_x(640).with_y(480).with_blink(3);
+    assert_eq!(cursor.position(), (640, 480));
+    let (x, y) = cursor.position();
+    assert_eq!(x, cursor.x());
+    assert_eq!(y, cursor.y());
+}
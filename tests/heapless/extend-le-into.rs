@@ -0,0 +1,30 @@
+// Tests appending the bytes of a bitfield to a `heapless::Vec`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+#[bitfield(filled = false)]
+pub struct Flags {
+    a: bool,
+    b: B3,
+}
+
+fn main() {
+    let mut packet = heapless::Vec::<u8, 4>::new();
+    Header::new()
+        .with_kind(0x2)
+        .with_len(0x345)
+        .extend_le_into(&mut packet)
+        .unwrap();
+    Flags::new().with_a(true).with_b(0b101).extend_le_into(&mut packet).unwrap();
+    assert_eq!(&packet[..], &[0x52, 0x34, 0b1011]);
+
+    // Not enough spare capacity leaves the vector unchanged.
+    assert_eq!(Header::new().extend_le_into(&mut packet), Err(()));
+    assert_eq!(&packet[..], &[0x52, 0x34, 0b1011]);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    #[hidden]
+    #[hidden]
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    #[hidden(getters)]
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// The accessors of `#[hidden]` fields remain fully usable.
+//
+// The `#[doc(hidden)]` attribute they receive only affects rustdoc output which cannot be
+// observed by a compile test, so this only checks that hiding leaves the accessors intact.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Status {
+    ready: bool,
+    /// Reserved for internal use.
+    #[hidden]
+    reserved: B7,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    #[hidden]
+    reserved: B4,
+    value: B4,
+}
+
+fn main() {
+    let mut status = Status::new().with_ready(true).with_reserved(0b101);
+    assert!(status.ready());
+    assert_eq!(status.reserved(), 0b101);
+    status.set_reserved(0b11);
+    assert_eq!(status.reserved_or_err(), Ok(0b11));
+
+    let unpacked = Unpacked::new().with_reserved(3).with_value(5);
+    assert_eq!(unpacked.reserved(), 3);
+    assert_eq!(unpacked.value(), 5);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+pub trait RegisterCommon {
+    fn code(&self) -> u8;
+}
+
+#[bitfield(accessors_in_trait, impl_trait = RegisterCommon(code))]
+pub struct Control {
+    enabled: bool,
+    code: B7,
+}
+
+fn main() {}
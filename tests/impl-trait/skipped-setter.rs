@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+pub trait RegisterCommon {
+    fn code(&self) -> u8;
+    fn set_code(&mut self, new_val: u8);
+}
+
+#[bitfield(impl_trait = RegisterCommon(code, set_code))]
+pub struct Control {
+    enabled: bool,
+    #[skip(setters)]
+    code: B7,
+}
+
+fn main() {}
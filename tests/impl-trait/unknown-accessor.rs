@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+pub trait RegisterCommon {
+    fn enabled(&self) -> bool;
+    fn set_enabled(&mut self, new_val: bool);
+}
+
+#[bitfield(impl_trait = RegisterCommon(enabled, set_enable))]
+pub struct Control {
+    enabled: bool,
+    code: B7,
+}
+
+fn main() {}
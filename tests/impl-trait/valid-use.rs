@@ -0,0 +1,54 @@
+use modular_bitfield::prelude::*;
+
+pub trait RegisterCommon {
+    fn enabled(&self) -> bool;
+    fn set_enabled(&mut self, new_val: bool);
+    fn code(&self) -> u8;
+    fn set_code(&mut self, new_val: u8);
+}
+
+pub trait Readable {
+    fn code(&self) -> u8;
+}
+
+#[bitfield(impl_trait = RegisterCommon(enabled, set_enabled, code, set_code), impl_trait = Readable(code))]
+pub struct Control {
+    enabled: bool,
+    code: B7,
+    prescaler: B8,
+}
+
+#[bitfield(bits = 16, packed = false, impl_trait = RegisterCommon(enabled, set_enabled, code, set_code))]
+#[derive(Clone, Copy)]
+pub struct Status {
+    code: B4,
+    enabled: bool,
+    #[skip]
+    __: B11,
+}
+
+#[bitfield(by_value_getters, impl_trait = RegisterCommon(enabled, set_enabled, code, set_code))]
+#[derive(Clone, Copy)]
+pub struct Config {
+    enabled: bool,
+    code: B7,
+}
+
+fn main() {
+    let mut registers: Vec<Box<dyn RegisterCommon>> = vec![
+        Box::new(Control::new().with_prescaler(0xFF)),
+        Box::new(Status::new()),
+        Box::new(Config::new()),
+    ];
+    for (code, register) in registers.iter_mut().enumerate() {
+        register.set_enabled(true);
+        register.set_code(code as u8 + 1);
+    }
+    for (code, register) in registers.iter().enumerate() {
+        assert!(register.enabled());
+        assert_eq!(register.code(), code as u8 + 1);
+    }
+
+    let control = Control::new().with_code(42);
+    assert_eq!(Readable::code(&control), 42);
+}
@@ -0,0 +1,13 @@
+use modular_bitfield::prelude::*;
+
+pub enum Flag {
+    Enable,
+}
+
+#[bitfield(index_by = Flag, index_by = Flag)]
+pub struct Control {
+    enable: bool,
+    mode: B7,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+pub enum Flag {
+    Enable,
+}
+
+#[bitfield(index_by = Flag)]
+pub struct Control {
+    enable: bool,
+    ready: bool,
+    mode: B6,
+}
+
+fn main() {}
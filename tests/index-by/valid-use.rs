@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+pub enum Flag {
+    Enable,
+    IrqPending,
+    Ready,
+}
+
+#[bitfield(index_by = Flag, bit_view)]
+pub struct Control {
+    enable: bool,
+    irq_pending: bool,
+    mode: B5,
+    ready: bool,
+}
+
+// Raw identifiers are accepted as enum names.
+#[bitfield(bits = 8, packed = false, index_by = r#Flag)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    enable: bool,
+    irq_pending: bool,
+    #[skip(getters)]
+    hidden: bool,
+    ready: bool,
+    level: B4,
+}
+
+fn main() {
+    let control = Control::new().with_enable(true).with_mode(0b10101).with_ready(true);
+    assert!(control[Flag::Enable]);
+    assert!(!control[Flag::IrqPending]);
+    assert!(control[Flag::Ready]);
+    // Integer indexing of the `bit_view` parameter remains available.
+    assert!(control[0]);
+    assert!(!control[1]);
+
+    let unpacked = Unpacked::new().with_irq_pending(true).with_hidden(true).with_level(0xF);
+    assert!(!unpacked[Flag::Enable]);
+    assert!(unpacked[Flag::IrqPending]);
+    assert!(!unpacked[Flag::Ready]);
+}
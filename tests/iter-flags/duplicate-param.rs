@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(iter_flags, iter_flags)]
+pub struct Permissions {
+    read: bool,
+    write: bool,
+    rest: B6,
+}
+
+fn main() {}
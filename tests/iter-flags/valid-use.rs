@@ -0,0 +1,30 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(iter_flags)]
+pub struct Permissions {
+    read: bool,
+    write: bool,
+    // Non-`bool` fields are not yielded.
+    mode: B5,
+    execute: bool,
+}
+
+#[bitfield(iter_flags)]
+pub struct Pair(bool, B7);
+
+fn main() {
+    let permissions = Permissions::new().with_read(true).with_execute(true).with_mode(3);
+    let flags = (&permissions).into_iter().collect::<Vec<_>>();
+    assert_eq!(flags, vec![("read", true), ("write", false), ("execute", true)]);
+
+    let mut enabled = Vec::new();
+    for (name, value) in &permissions {
+        if value {
+            enabled.push(name);
+        }
+    }
+    assert_eq!(enabled, vec!["read", "execute"]);
+
+    let pair = Pair::new().with_0(true);
+    assert_eq!((&pair).into_iter().collect::<Vec<_>>(), vec![("0", true)]);
+}
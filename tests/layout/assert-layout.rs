@@ -0,0 +1,34 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    mode: Mode,
+    is_valid: bool,
+    #[skip]
+    __: B1,
+    length: B16,
+}
+
+#[bitfield]
+pub struct Pair(B3, B5);
+
+modular_bitfield::assert_bitfield_layout!(
+    Header,
+    kind: 0..4,
+    mode: 4..6,
+    is_valid: 6..7,
+    length: 8..24,
+);
+
+modular_bitfield::assert_bitfield_layout!(Pair, 0: 0..3, 1: 3..8);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u8)]
+pub struct Interrupts {
+    irq: B4,
+    IRQ: B4,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct OldHeader {
+    kind: B4,
+    length: B12,
+}
+
+#[bitfield]
+pub struct NewHeader {
+    kind: B6,
+    length: B10,
+}
+
+modular_bitfield::assert_same_layout!(OldHeader, NewHeader);
+
+fn main() {}
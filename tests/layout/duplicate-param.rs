@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(layout, layout)]
+pub struct Header {
+    kind: B4,
+    len: B12,
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Color {
+    r: B8,
+    g: B6,
+    b: B10,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield]
+pub struct Pair(B3, B5);
+
+const R_BITS: usize = modular_bitfield::field_bits!(Color::r);
+
+fn mask<const N: usize>() -> u32 {
+    (1 << N) - 1
+}
+
+fn main() {
+    assert_eq!(R_BITS, 8);
+    assert_eq!(modular_bitfield::field_bits!(Color::r), 8);
+    assert_eq!(modular_bitfield::field_bits!(Color::g), 6);
+    assert_eq!(modular_bitfield::field_bits!(Color::b), 10);
+    assert_eq!(mask::<{ modular_bitfield::field_bits!(Color::g) }>(), 0b11_1111);
+
+    assert_eq!(modular_bitfield::field_bits!(Pair::0), 3);
+    assert_eq!(modular_bitfield::field_bits!(Pair::1), 5);
+
+    assert_eq!(modular_bitfield::field_bits!(<regs::Status>::code), 7);
+}
+
+mod regs {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield]
+    pub struct Status {
+        pub ready: bool,
+        pub code: B7,
+    }
+}
@@ -0,0 +1,41 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u32)]
+#[derive(Clone, Copy)]
+pub struct Status {
+    ready: bool,
+    error: bool,
+    code: B6,
+    count: B8,
+    #[skip]
+    __: B16,
+}
+
+#[bitfield]
+#[repr(u8)]
+pub struct Pair(B3, B5);
+
+const READY_AND_CODE: u32 = modular_bitfield::field_mask!(Status, ready, code);
+
+fn main() {
+    assert_eq!(Status::READY_MASK, 0b1);
+    assert_eq!(Status::ERROR_MASK, 0b10);
+    assert_eq!(Status::CODE_MASK, 0b1111_1100);
+    assert_eq!(Status::COUNT_MASK, 0xFF00);
+    assert_eq!(READY_AND_CODE, 0b1111_1101);
+    assert_eq!(modular_bitfield::field_mask!(Status, count), Status::COUNT_MASK);
+    assert_eq!(
+        modular_bitfield::field_mask!(Status, ready, error, code, count),
+        0xFFFF,
+    );
+
+    let status = Status::new().with_ready(true).with_code(0x3F);
+    assert!(status.contains(READY_AND_CODE));
+    assert!(!status.contains(modular_bitfield::field_mask!(Status, ready, error)));
+    assert_eq!(u32::from(status.masked(Status::CODE_MASK)), 0b1111_1100);
+
+    assert_eq!(Pair::_0_MASK, 0b111);
+    assert_eq!(Pair::_1_MASK, 0b1111_1000);
+    assert_eq!(modular_bitfield::field_mask!(Pair, 0, 1), 0xFF);
+}
@@ -0,0 +1,33 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    length: B12,
+    #[skip]
+    __: B4,
+    checksum: B4,
+}
+
+#[bitfield]
+pub struct Pair(B3, B5);
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct Unpacked {
+    low: B2,
+    high: B6,
+}
+
+const LENGTH_RANGE: core::ops::Range<usize> = Header::length_range();
+
+fn main() {
+    assert_eq!(Header::kind_range(), 0..4);
+    assert_eq!(LENGTH_RANGE, 4..16);
+    assert_eq!(Header::checksum_range(), 20..24);
+
+    assert_eq!(Pair::get_0_range(), 0..3);
+    assert_eq!(Pair::get_1_range(), 3..8);
+
+    assert_eq!(Unpacked::high_range(), 2..8);
+}
@@ -0,0 +1,30 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(layout)]
+pub struct Header {
+    kind: B4,
+    r#type: B4,
+    #[skip]
+    __: B4,
+    length: B12,
+}
+
+#[bitfield(layout)]
+pub struct Pair(B3, B5);
+
+const LENGTH_MASK: u32 = ((1 << HeaderLayout::LENGTH_WIDTH) - 1) << HeaderLayout::LENGTH_OFFSET;
+
+fn main() {
+    assert_eq!(HeaderLayout::KIND_OFFSET, 0);
+    assert_eq!(HeaderLayout::KIND_WIDTH, 4);
+    assert_eq!(HeaderLayout::TYPE_OFFSET, 4);
+    assert_eq!(HeaderLayout::TYPE_WIDTH, 4);
+    assert_eq!(HeaderLayout::LENGTH_OFFSET, 12);
+    assert_eq!(HeaderLayout::LENGTH_WIDTH, 12);
+    assert_eq!(LENGTH_MASK, 0x00FF_F000);
+
+    assert_eq!(PairLayout::_0_OFFSET, 0);
+    assert_eq!(PairLayout::_0_WIDTH, 3);
+    assert_eq!(PairLayout::_1_OFFSET, 3);
+    assert_eq!(PairLayout::_1_WIDTH, 5);
+}
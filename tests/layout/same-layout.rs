@@ -0,0 +1,42 @@
+// Bitfields with the same fields at the same bit ranges have identical layouts
+// regardless of the types of their fields or their representation.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+#[bits = 4]
+pub enum Kind {
+    Data = 0,
+    Ack = 1,
+}
+
+#[bitfield]
+pub struct OldHeader {
+    kind: B4,
+    is_valid: bool,
+    #[skip]
+    __: B3,
+    length: B8,
+}
+
+#[bitfield]
+#[repr(u16)]
+pub struct NewHeader {
+    kind: Kind,
+    is_valid: bool,
+    #[skip]
+    __: B3,
+    length: B8,
+}
+
+#[bitfield]
+pub struct OldPair(B3, B5);
+
+#[bitfield]
+pub struct NewPair(B3, B5);
+
+modular_bitfield::assert_same_layout!(OldHeader, NewHeader);
+modular_bitfield::assert_same_layout!(NewHeader, OldHeader);
+modular_bitfield::assert_same_layout!(OldPair, NewPair);
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[repr(u8)]
+pub struct Status {
+    ready: bool,
+    code: B7,
+}
+
+fn main() {
+    let _ = modular_bitfield::field_mask!(Status, ready, cod);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    kind: B4,
+    length: B12,
+}
+
+modular_bitfield::assert_bitfield_layout!(Header, kind: 0..4, length: 4..8);
+
+fn main() {}
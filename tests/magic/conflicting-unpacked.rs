@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Header {
+    #[magic = 0xAB]
+    magic: B8,
+    version: B8,
+}
+
+fn main() {}
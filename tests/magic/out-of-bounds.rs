@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    #[magic = 0x1AB]
+    magic: B8,
+    version: B8,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use modular_bitfield::{
+    error::ValidateError,
+    prelude::*,
+};
+
+#[bitfield]
+pub struct Header {
+    #[magic = 0xAB]
+    magic: B8,
+    version: B4,
+    #[reserved]
+    __: B4,
+}
+
+fn main() {
+    let header = Header::new();
+    assert_eq!(header.magic(), 0xAB);
+    assert_eq!(header.version(), 0);
+    assert_eq!(header.validate(), Ok(()));
+
+    let header = Header::from_le_bytes([0xAC, 0x03]);
+    assert_eq!(header.version(), 3);
+    assert_eq!(
+        header.validate(),
+        Err(ValidateError::MagicMismatch {
+            field: "magic",
+            expected: 0xAB,
+            found: 0xAC,
+        })
+    );
+
+    let header = Header::from_le_bytes([0xAB, 0x13]);
+    assert_eq!(
+        header.validate(),
+        Err(ValidateError::NonZeroReserved(
+            modular_bitfield::error::NonZeroReserved { field: "__" }
+        ))
+    );
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 24, transparent, max_align)]
+pub struct Rgb {
+    rgb: B24,
+}
+
+fn main() {}
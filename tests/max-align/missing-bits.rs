@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(max_align)]
+pub struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+fn main() {}
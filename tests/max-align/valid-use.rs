@@ -0,0 +1,44 @@
+use core::mem::{align_of, size_of};
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 24, max_align)]
+pub struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[bitfield(bits = 40, max_align)]
+pub struct Wide {
+    low: u32,
+    high: u8,
+}
+
+#[bitfield(max_align)]
+#[repr(u16)]
+pub struct Half {
+    a: B4,
+    b: B12,
+}
+
+#[bitfield(bits = 24)]
+pub struct Unaligned {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+fn main() {
+    assert_eq!(align_of::<Rgb>(), align_of::<u32>());
+    assert_eq!(size_of::<Rgb>(), 4);
+    assert_eq!(align_of::<Wide>(), 8);
+    assert_eq!(size_of::<Wide>(), 8);
+    assert_eq!(align_of::<Half>(), align_of::<u16>());
+    assert_eq!(size_of::<Half>(), 2);
+    assert_eq!(align_of::<Unaligned>(), 1);
+    assert_eq!(size_of::<Unaligned>(), 3);
+
+    // The byte representation is unaffected by the alignment.
+    let rgb = Rgb::new().with_r(1).with_g(2).with_b(3);
+    assert_eq!(rgb.to_le_bytes(), [1, 2, 3]);
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(max_const)]
+pub struct Slot {
+    #[sentinel = 0xF]
+    index: B4,
+    value: B4,
+}
+
+fn main() {}
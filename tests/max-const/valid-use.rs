@@ -0,0 +1,67 @@
+use core::cmp::Ordering;
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq)]
+#[bits = 3]
+pub enum Level {
+    Low = 0,
+    High = 5,
+    Medium = 2,
+}
+
+#[bitfield(max_const)]
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    enabled: bool,
+    level: Level,
+    count: B4,
+    #[reserved]
+    __: B4,
+    value: u32,
+    #[skip]
+    unused: B4,
+}
+
+#[bitfield(max_const)]
+#[derive(Debug, Clone, Copy)]
+pub struct Header {
+    #[magic = 0x5]
+    version: B4,
+    order: Ordering,
+    pair: (Ordering, Level),
+    levels: [Level; 2],
+    #[skip]
+    __: B7,
+}
+
+#[bitfield(max_const)]
+#[derive(Debug, Clone, Copy)]
+pub struct Packet {
+    level: Level,
+    flags: B5,
+    length: u8,
+    #[checksum(over = level..crc, algo = xor)]
+    crc: u8,
+}
+
+const MAX: Limits = Limits::MAX;
+
+fn main() {
+    assert!(MAX.enabled());
+    assert_eq!(MAX.level(), Level::High);
+    assert_eq!(MAX.count(), 0xF);
+    assert_eq!(MAX.value(), u32::MAX);
+    assert!(MAX.validate().is_ok());
+    assert_eq!(MAX.to_le_bytes(), [0xFB, 0xF0, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+    assert_eq!(Header::MAX.version(), 0x5);
+    assert_eq!(Header::MAX.order(), Ordering::Greater);
+    assert_eq!(Header::MAX.pair(), (Ordering::Greater, Level::High));
+    assert_eq!(Header::MAX.levels(), [Level::High, Level::High]);
+    assert!(Header::MAX.validate().is_ok());
+
+    // The checksum is computed from the maximum values of the fields it covers.
+    assert_eq!(Packet::MAX.level(), Level::High);
+    assert_eq!(Packet::MAX.crc(), 0xFD ^ 0xFF);
+    assert!(Packet::MAX.validate().is_ok());
+}
@@ -0,0 +1,63 @@
+// Bitfields and their fallible conversions compile within `no_std` crates.
+
+#![no_std]
+
+use core::convert::TryFrom;
+use modular_bitfield::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    A,
+    B,
+    C,
+}
+
+#[bitfield]
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Header {
+    mode: Mode,
+    #[reserved]
+    __: B2,
+    len: B12,
+}
+
+#[bitfield(packed = false)]
+#[repr(u16)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    low: B4,
+    high: B12,
+}
+
+// The test binary still needs `std` for its runtime, but not in the extern prelude.
+mod runtime {
+    extern crate std;
+}
+
+fn set_len(header: &mut Header, len: u16) -> Result<(), OutOfBounds> {
+    header.set_len_checked(len)
+}
+
+fn main() {
+    let mut header = Header::new();
+    assert_eq!(set_len(&mut header, 0x1000), Err(OutOfBounds));
+    assert_eq!(set_len(&mut header, 0xFFF), Ok(()));
+    assert_eq!(header.len(), 0xFFF);
+
+    let header = Header::from_le_bytes([0b0000_0011, 0x00]);
+    let error: Result<Mode, InvalidBitPattern<u8>> = header.mode_or_err();
+    assert!(error.is_err());
+
+    let header = Header::from_le_bytes([0b0000_0100, 0x00]);
+    assert!(matches!(header.validate(), Err(error) if error.field == "__"));
+
+    assert!(Unpacked::try_from(0x1_0000_u128).is_err());
+    assert_eq!(u16::from(Unpacked::new().with_high(1)), 0x10);
+}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Clone)]
+pub enum Mode {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield]
+pub struct Entry {
+    #[non_copy]
+    mode: Mode,
+    value: B7,
+}
+
+fn main() {}
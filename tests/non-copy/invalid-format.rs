@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Clone)]
+pub enum Mode {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield(bits = 8, packed = false)]
+pub struct Entry {
+    #[non_copy(yes)]
+    mode: Mode,
+    value: B7,
+}
+
+fn main() {}
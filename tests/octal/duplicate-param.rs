@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(octal, octal)]
+pub struct Permissions {
+    other: B3,
+    group: B3,
+    owner: B3,
+    #[skip]
+    __: B7,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(octal)]
+pub struct Wide {
+    low: B64,
+    mid: B64,
+    high: B8,
+}
+
+fn main() {}
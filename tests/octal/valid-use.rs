@@ -0,0 +1,38 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(octal)]
+pub struct Permissions {
+    other: B3,
+    group: B3,
+    owner: B3,
+    #[skip]
+    __: B7,
+}
+
+#[bitfield(filled = false, octal)]
+pub struct Unfilled {
+    low: B3,
+    high: B3,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false, octal)]
+#[derive(Clone)]
+pub struct Unpacked {
+    low: B6,
+    high: B6,
+}
+
+fn main() {
+    let permissions = Permissions::new().with_owner(0o7).with_group(0o5).with_other(0o4);
+    assert_eq!(format!("{:o}", permissions), "754");
+    assert_eq!(format!("{:#o}", permissions), "0o754");
+    assert_eq!(format!("{:06o}", permissions), "000754");
+    assert_eq!(format!("{:>5o}", permissions), "  754");
+    assert_eq!(format!("{:o}", Permissions::new()), "0");
+
+    let unfilled = Unfilled::new().with_low(0o1).with_high(0o6);
+    assert_eq!(format!("{:o}", unfilled), "61");
+
+    let unpacked = Unpacked::new().with_low(0o12).with_high(0o34);
+    assert_eq!(format!("{:#o}", unpacked), "0o3412");
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, on_overflow = saturate)]
+#[derive(Clone, Copy)]
+pub struct Sample {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
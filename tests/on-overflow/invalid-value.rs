@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(on_overflow = wrap)]
+pub struct Sample {
+    low: B4,
+    high: B4,
+}
+
+fn main() {}
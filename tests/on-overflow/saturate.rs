@@ -0,0 +1,48 @@
+use core::convert::TryFrom;
+use modular_bitfield::prelude::*;
+
+#[bitfield(on_overflow = saturate)]
+#[derive(Clone, Copy)]
+pub struct Sample {
+    low: B3,
+    full: u8,
+    high: B13,
+    flag: bool,
+    #[skip]
+    __: B7,
+}
+
+#[bitfield(bits = 12, on_overflow = saturate, filled = false, from_field)]
+pub struct Unfilled {
+    value: B10,
+}
+
+fn main() {
+    let mut sample = Sample::new().with_low(9).with_full(200).with_high(0xFFFF);
+    assert_eq!(sample.low(), 7);
+    assert_eq!(sample.full(), 200);
+    assert_eq!(sample.high(), 0x1FFF);
+    assert!(!sample.flag());
+
+    sample.set_low(2);
+    assert_eq!(sample.low(), 2);
+    assert!(sample.set_low_if_changed(42));
+    assert_eq!(sample.low(), 7);
+    assert!(!sample.set_low_if_changed(8));
+    assert_eq!(sample.set_low_checked(8), Err(modular_bitfield::error::OutOfBounds));
+    assert_eq!(sample.low(), 7);
+
+    // Raw values beyond the bit width saturate instead of erroring.
+    let sample = Sample::try_from(u128::MAX).unwrap();
+    assert_eq!(sample.to_le_bytes(), [0xFF; 4]);
+    let sample = Sample::try_from(0x1_0000_0001).unwrap();
+    assert_eq!(sample.low(), 7);
+    assert_eq!(sample.high(), 0x1FFF);
+    let sample = Sample::try_from(0x0A).unwrap();
+    assert_eq!((sample.low(), sample.full()), (2, 1));
+
+    let unfilled = Unfilled::try_from(0xFFFF_u128).unwrap();
+    assert_eq!(unfilled.value(), 0x3FF);
+    assert_eq!(Unfilled::new().with_value(2000).value(), 0x3FF);
+    assert_eq!(Unfilled::try_from(5000_u16).unwrap().value(), 0x3FF);
+}
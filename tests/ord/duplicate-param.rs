@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord = fields, ord = fields)]
+pub struct Version {
+    major: B4,
+    minor: B4,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord = bytes)]
+pub struct Version {
+    major: B4,
+    minor: B4,
+}
+
+fn main() {}
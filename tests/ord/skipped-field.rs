@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord = fields)]
+#[derive(PartialEq, Eq)]
+pub struct Version {
+    major: B4,
+    #[skip(getters)]
+    minor: B4,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+use core::cmp::Ordering;
+use modular_bitfield::prelude::*;
+
+#[bitfield(ord = fields)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Version {
+    major: B4,
+    minor: B4,
+}
+
+#[bitfield(bits = 14, packed = false, filled = false, ord = fields)]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Unpacked {
+    flag: bool,
+    value: B11,
+}
+
+fn main() {
+    let newer = Version::new().with_major(1).with_minor(0);
+    let older = Version::new().with_major(0).with_minor(2);
+
+    // Field order compares `major` first, whereas `minor` occupies the more
+    // significant bits of the raw integer, so both orderings disagree.
+    assert_eq!(newer.cmp(&older), Ordering::Greater);
+    assert!(newer > older);
+    let raw = |version: Version| u8::from_le_bytes(version.to_le_bytes());
+    assert_eq!(raw(newer).cmp(&raw(older)), Ordering::Less);
+
+    assert_eq!(newer.cmp(&newer), Ordering::Equal);
+    let mut versions = [newer, older, Version::new().with_major(1).with_minor(1)];
+    versions.sort();
+    assert_eq!(versions[0], older);
+    assert_eq!(versions[1], newer);
+
+    let set = Unpacked::new().with_flag(true);
+    let big = Unpacked::new().with_value(0x7FF);
+    assert!(set > big);
+    assert_eq!(big.partial_cmp(&set), Some(Ordering::Less));
+}
@@ -37,3 +37,77 @@ fn invalid_access_d() {
     let mut bytes = EdgeCaseBytes::new();
     bytes.set_d(0b0001_0000_u8);
 }
+
+#[bitfield(track_caller)]
+pub struct TrackedBytes {
+    a: B9,
+    b: B7,
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field TrackedBytes.a")]
+fn track_caller_set_out_of_bounds() {
+    let mut bytes = TrackedBytes::new();
+    bytes.set_a(0b0010_0000_0000_u16);
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field TrackedBytes.b")]
+fn track_caller_with_out_of_bounds() {
+    let _ = TrackedBytes::new().with_b(0b1000_0000_u8);
+}
+
+#[test]
+#[should_panic(expected = "big endian byte index out of bounds: the len is 4 but the index is 4")]
+#[cfg(debug_assertions)]
+fn update_byte_be_out_of_bounds() {
+    let mut bytes = EdgeCaseBytes::new();
+    bytes.update_byte_be(4, 0xFF);
+}
+
+#[bitfield(bit_view)]
+pub struct BitView {
+    a: B4,
+    b: B4,
+}
+
+#[test]
+#[should_panic(expected = "bit index out of bounds: the len is 8 but the index is 8")]
+fn bit_view_out_of_bounds() {
+    let bits = BitView::new();
+    let _ = bits.bit(8);
+}
+
+#[test]
+#[should_panic(expected = "bit range out of bounds: the len is 8 but the range is 6..9")]
+fn set_bits_range_out_of_bounds() {
+    let mut bits = BitView::new();
+    bits.set_bits_range(6..9, 0);
+}
+
+#[test]
+#[should_panic(expected = "value 4 does not fit into 2 bits")]
+fn set_bits_value_too_wide() {
+    let mut bits = BitView::new();
+    bits.set_bits(0, 2, 4);
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds for field EdgeCaseBytes.d")]
+fn modify_out_of_bounds() {
+    let mut bytes = EdgeCaseBytes::new().with_d(0b1111);
+    bytes.modify_d(|d| d + 1);
+}
+
+#[bitfield]
+pub struct Slot {
+    #[sentinel = 0xF]
+    channel: B4,
+    volume: B4,
+}
+
+#[test]
+#[should_panic(expected = "sentinel value 0xf passed as `Some` for field Slot.channel")]
+fn set_opt_sentinel_value() {
+    Slot::new().set_channel_opt(Some(0xF));
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false)]
+pub struct Header {
+    #[payload_len]
+    len: B4,
+    kind: B4,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Header {
+    #[payload_len]
+    first: B4,
+    #[payload_len]
+    second: B4,
+}
+
+fn main() {}
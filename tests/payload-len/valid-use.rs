@@ -0,0 +1,44 @@
+// Tests splitting a buffer into a header and its payload via the `#[payload_len]` field.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug, Clone, Copy)]
+pub struct Header {
+    kind: B4,
+    #[payload_len]
+    len: B12,
+}
+
+#[bitfield(filled = false)]
+#[derive(Debug, Clone, Copy)]
+pub struct Short {
+    #[payload_len]
+    len: B3,
+    flag: bool,
+}
+
+fn main() {
+    let header = Header::new().with_kind(0x3).with_len(4);
+    assert_eq!(header.payload_len(), 4);
+
+    let mut buffer = header.to_le_bytes().to_vec();
+    buffer.extend_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02]);
+    let (parsed, payload, rest) = Header::split_at(&buffer).unwrap();
+    assert_eq!(parsed.kind(), 0x3);
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(payload, &[0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(rest, &[0x01, 0x02]);
+
+    // The buffer is too short for the header or the payload.
+    assert!(Header::split_at(&buffer[..1]).is_none());
+    assert!(Header::split_at(&buffer[..5]).is_none());
+
+    let (parsed, payload, rest) = Short::split_at(&[0b0000_1010, 0xAA, 0xBB, 0xCC]).unwrap();
+    assert!(parsed.flag());
+    assert_eq!(payload, &[0xAA, 0xBB]);
+    assert_eq!(rest, &[0xCC]);
+
+    // The undefined bits of the header are set.
+    assert!(Short::split_at(&[0b1000_0000]).is_none());
+}
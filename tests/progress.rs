@@ -27,12 +27,68 @@ fn tests() {
     t.compile_fail("tests/26-invalid-struct-specifier.rs");
     t.compile_fail("tests/27-invalid-union-specifier.rs");
     t.pass("tests/28-single-bit-enum.rs");
+    t.pass("tests/29-set-if-changed.rs");
+    t.pass("tests/30-bytes-len.rs");
+    t.pass("tests/31-load-bytes.rs");
+    t.pass("tests/32-tuple-specifiers.rs");
+    t.pass("tests/33-try-from-u128.rs");
+    t.pass("tests/34-ipv4-specifier.rs");
+    t.pass("tests/35-swap-bytes.rs");
+    t.pass("tests/36-widened-bits-field.rs");
+    t.pass("tests/37-try-update-byte-be.rs");
+    t.pass("tests/38-enum-array-specifiers.rs");
+    t.pass("tests/39-set-bits-range.rs");
+    t.pass("tests/40-borrow-bytes.rs");
+    t.pass("tests/41-rotate.rs");
+    t.pass("tests/42-u32-words.rs");
+    t.pass("tests/43-repr-partial-eq.rs");
+    t.pass("tests/44-from-bytes-at.rs");
+    t.pass("tests/45-modify-field.rs");
+    t.pass("tests/46-const-getters.rs");
+    t.pass("tests/47-diff.rs");
+    t.pass("tests/48-from-single-field.rs");
+    t.pass("tests/49-non-snake-case-fields.rs");
+    t.pass("tests/50-u24-u48-specifiers.rs");
+    t.pass("tests/51-from-bits-iter.rs");
+    t.pass("tests/52-write-le-into.rs");
+    t.pass("tests/53-enumerate.rs");
+    t.pass("tests/54-is-zero-all-ones.rs");
+    t.pass("tests/55-units.rs");
+    t.pass("tests/56-pack-unpack.rs");
+    t.pass("tests/57-plain-fields.rs");
+    t.pass("tests/58-repr-byte-len.rs");
+    t.pass("tests/59-bit-offset-of.rs");
+    t.pass("tests/60-masked.rs");
+    t.pass("tests/61-ordering-specifier.rs");
+    t.pass("tests/62-bool-array.rs");
+    t.pass("tests/63-bits-in.rs");
+    t.pass("tests/64-byte-array-field.rs");
+    t.pass("tests/65-widening-from.rs");
+    t.pass("tests/66-contains-flags.rs");
+    t.pass("tests/67-swap-raw.rs");
+    t.pass("tests/68-from-bytes-args.rs");
+    t.pass("tests/69-hash-map-key.rs");
+    t.pass("tests/70-as-bytes-tuple.rs");
+    t.pass("tests/71-truncated-to-bits.rs");
+    t.pass("tests/72-parse-le.rs");
+    t.pass("tests/73-default-variant.rs");
+    t.pass("tests/74-try-set-many.rs");
+    t.pass("tests/75-plain-fields-eq.rs");
+    t.pass("tests/76-reverse-bits.rs");
+    t.pass("tests/77-hamming-distance.rs");
+    t.pass("tests/78-into-bool-array.rs");
+    t.pass("tests/79-patch.rs");
 
     // Tests specific to the `#[derive(BitfieldSpecifier)]` proc. macro:
     t.pass("tests/derive-bitfield-specifier/06-enums.rs");
     t.pass("tests/derive-bitfield-specifier/07-optional-discriminant.rs");
     t.compile_fail("tests/derive-bitfield-specifier/08-non-power-of-two.rs");
     t.compile_fail("tests/derive-bitfield-specifier/09-variant-out-of-range.rs");
+    t.compile_fail("tests/derive-bitfield-specifier/10-discriminant-exceeds-bits.rs");
+
+    // Tests for generic `#[bitfield]` structs:
+    t.compile_fail("tests/generics/const-width.rs");
+    t.compile_fail("tests/generics/type-param.rs");
 
     // Tests for regressions found in published versions:
     t.pass("tests/regressions/no-implicit-prelude.rs");
@@ -41,6 +97,7 @@ fn tests() {
     t.pass("tests/regressions/deny_elided_lifetime.rs");
     t.pass("tests/regressions/regression-v0.11.rs");
     t.compile_fail("tests/regressions/invalid_bits_field_attr.rs");
+    t.pass("tests/regressions/cfg-fields.rs");
 
     // Tests for `bytes = N` #[bitfield] parameter:
     t.pass("tests/bytes-param/valid-bitfield.rs");
@@ -76,6 +133,13 @@ fn tests() {
     t.compile_fail("tests/repr/invalid-repr-width-2.rs");
     t.compile_fail("tests/repr/conflicting-ignored-reprs.rs");
     t.compile_fail("tests/repr/invalid-repr-unfilled.rs");
+    t.pass("tests/repr/byte-array-use.rs");
+    t.compile_fail("tests/repr/invalid-byte-array-width.rs");
+    t.compile_fail("tests/repr/invalid-byte-array-unpacked.rs");
+    t.pass("tests/repr/bool-use.rs");
+    t.compile_fail("tests/repr/invalid-bool-width.rs");
+    t.compile_fail("tests/repr/invalid-bool-filled.rs");
+    t.compile_fail("tests/repr/widening-from-without-repr.rs");
 
     // Tests for `#[derive(Debug)]`:
     t.pass("tests/derive-debug/valid-use.rs");
@@ -85,6 +149,11 @@ fn tests() {
     t.pass("tests/derive-debug/respects-other-derives.rs");
     t.compile_fail("tests/derive-debug/duplicate-derive-debug.rs");
     t.compile_fail("tests/derive-debug/duplicate-derive-debug-2.rs");
+    t.pass("tests/derive-debug/verbose.rs");
+    t.compile_fail("tests/derive-debug/verbose-without-derive.rs");
+    t.compile_fail("tests/derive-debug/verbose-invalid-kind.rs");
+    t.pass("tests/derive-debug/by-byte.rs");
+    t.pass("tests/derive-debug/compact.rs");
 
     // Tests for `#[skip(..)]`:
     t.pass("tests/skip/skip-default.rs");
@@ -111,9 +180,35 @@ fn tests() {
     t.compile_fail("tests/skip/duplicate-setters-2.rs");
     t.compile_fail("tests/skip/duplicate-setters-3.rs");
 
+    // Tests for `#[deprecated]` fields:
+    t.pass("tests/deprecated/valid-use.rs");
+    t.compile_fail("tests/deprecated/use-deprecated-getter.rs");
+    t.compile_fail("tests/deprecated/use-deprecated-setter.rs");
+
+    // Tests for `#[reserved]`:
+    t.pass("tests/reserved/valid-use.rs");
+    t.compile_fail("tests/reserved/no-accessors.rs");
+    t.compile_fail("tests/reserved/conflicting-skip.rs");
+    t.compile_fail("tests/reserved/duplicate-attr.rs");
+
+    // Tests for `#[hidden]`:
+    t.pass("tests/hidden/valid-use.rs");
+    t.compile_fail("tests/hidden/duplicate-attr.rs");
+    t.compile_fail("tests/hidden/invalid-format.rs");
+
+    // Tests for `#[eq(ignore)]`:
+    t.pass("tests/eq-ignore/valid-use.rs");
+    t.compile_fail("tests/eq-ignore/invalid-format.rs");
+
+    // Tests for `#[group(name)]`:
+    t.pass("tests/group/valid-use.rs");
+    t.compile_fail("tests/group/non-adjacent.rs");
+    t.compile_fail("tests/group/duplicate-attr.rs");
+
     // Tests for `#[derive(BitfieldSpecifier)] using `#[bitfield]`:
     t.pass("tests/derive-specifier/valid-use.rs");
     t.pass("tests/derive-specifier/struct-in-struct.rs");
+    t.pass("tests/derive-specifier/nested-no-padding.rs");
     t.pass("tests/derive-specifier/unfilled-from-bytes.rs");
     t.compile_fail("tests/derive-specifier/out-of-bounds.rs");
     t.compile_fail("tests/derive-specifier/duplicate-derive-1.rs");
@@ -130,6 +225,8 @@ fn tests() {
     t.pass("tests/bits-param/complex-use-case.rs");
     t.compile_fail("tests/bits-param/conflicting-params.rs");
     t.compile_fail("tests/bits-param/conflicting-repr.rs");
+    t.compile_fail("tests/bits-param/repr-too-narrow.rs");
+    t.pass("tests/bits-param/unpacked-repr-unfilled.rs");
     t.compile_fail("tests/bits-param/duplicate-param-1.rs");
     t.compile_fail("tests/bits-param/duplicate-param-2.rs");
     t.compile_fail("tests/bits-param/invalid-param-value-1.rs");
@@ -137,4 +234,278 @@ fn tests() {
     t.compile_fail("tests/bits-param/missing-param-value.rs");
     t.compile_fail("tests/bits-param/too-few-bits.rs");
     t.compile_fail("tests/bits-param/too-many-bits.rs");
+    t.compile_fail("tests/bits-param/field-exceeds-bits.rs");
+
+    // Tests for `#[bitfield(transparent)]`:
+    t.pass("tests/transparent/valid-use.rs");
+    t.compile_fail("tests/transparent/multiple-fields.rs");
+    t.compile_fail("tests/transparent/unfilled.rs");
+    t.compile_fail("tests/transparent/duplicate-param.rs");
+
+    // Tests for `#[bitfield(clone_copy)]`:
+    t.pass("tests/clone-copy/valid-use.rs");
+    t.compile_fail("tests/clone-copy/redundant-derive.rs");
+    t.compile_fail("tests/clone-copy/non-copy-field.rs");
+
+    // Tests for `#[bitfield(zero_const)]`:
+    t.pass("tests/zero-const/valid-use.rs");
+    t.compile_fail("tests/zero-const/duplicate-param.rs");
+    t.compile_fail("tests/zero-const/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(max_const)]`:
+    t.pass("tests/max-const/valid-use.rs");
+    t.compile_fail("tests/max-const/conflicting-unpacked.rs");
+    t.compile_fail("tests/max-const/conflicting-sentinel.rs");
+
+    // Tests for `#[bitfield(on_overflow = saturate)]`:
+    t.pass("tests/on-overflow/saturate.rs");
+    t.compile_fail("tests/on-overflow/invalid-value.rs");
+    t.compile_fail("tests/on-overflow/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(reorder = false)]`:
+    t.pass("tests/reorder/valid-use.rs");
+    t.compile_fail("tests/reorder/reorder-true.rs");
+
+    // Tests for `#[bitfield(iter_flags)]`:
+    t.pass("tests/iter-flags/valid-use.rs");
+    t.compile_fail("tests/iter-flags/duplicate-param.rs");
+
+    // Tests for `#[bitfield(allow_empty)]`:
+    t.pass("tests/allow-empty/valid-use.rs");
+    t.compile_fail("tests/allow-empty/empty-struct.rs");
+    t.compile_fail("tests/allow-empty/all-fields-disabled.rs");
+
+    // Tests for `#[bitfield(bit_view)]`:
+    t.pass("tests/bit-view/valid-use.rs");
+    t.compile_fail("tests/bit-view/conflicting-unpacked.rs");
+    t.compile_fail("tests/bit-view/set-bits-range-without-flag.rs");
+    t.compile_fail("tests/bit-view/bits-in-without-flag.rs");
+
+    // Tests for `#[bitfield(ord = fields)]`:
+    t.pass("tests/ord/valid-use.rs");
+    t.compile_fail("tests/ord/invalid-value.rs");
+    t.compile_fail("tests/ord/duplicate-param.rs");
+    t.compile_fail("tests/ord/skipped-field.rs");
+
+    // Tests for `#[bitfield(octal)]`:
+    t.pass("tests/octal/valid-use.rs");
+    t.compile_fail("tests/octal/duplicate-param.rs");
+    t.compile_fail("tests/octal/too-wide.rs");
+
+    // Tests for `#[bitfield(view(Name = start..end))]`:
+    t.pass("tests/view/valid-use.rs");
+    t.compile_fail("tests/view/out-of-bounds.rs");
+    t.compile_fail("tests/view/duplicate-name.rs");
+
+    // Tests for fields whose accessors collide with generated methods:
+    t.pass("tests/accessor-collision/skipped-accessors.rs");
+    t.compile_fail("tests/accessor-collision/field-named-new.rs");
+    t.compile_fail("tests/accessor-collision/field-named-bits.rs");
+    t.compile_fail("tests/accessor-collision/field-named-patch.rs");
+    t.compile_fail("tests/accessor-collision/field-named-contains.rs");
+
+    // Tests for `Borrow<[u8]>`:
+    t.compile_fail("tests/borrow/masked-bits.rs");
+
+    // Tests for `#[bitfield(enumerate)]`:
+    t.compile_fail("tests/enumerate/too-wide.rs");
+
+    // Tests for `#[checksum(over = a..c, algo = xor)]` fields:
+    t.pass("tests/checksum/valid-use.rs");
+    t.compile_fail("tests/checksum/unknown-field.rs");
+    t.compile_fail("tests/checksum/covering-itself.rs");
+    t.compile_fail("tests/checksum/empty-range.rs");
+
+    // Tests for `#[payload_len]` fields:
+    t.pass("tests/payload-len/valid-use.rs");
+    t.compile_fail("tests/payload-len/multiple-fields.rs");
+    t.compile_fail("tests/payload-len/conflicting-unpacked.rs");
+
+    // Tests for `#[extract_as(Name)]` fields:
+    t.pass("tests/extract-as/valid-use.rs");
+    t.compile_fail("tests/extract-as/invalid-format.rs");
+    t.compile_fail("tests/extract-as/conflicting-unpacked.rs");
+
+    // Tests for `#[const_enum]` fields:
+    t.pass("tests/const-enum/valid-use.rs");
+    t.compile_fail("tests/const-enum/invalid-format.rs");
+    t.compile_fail("tests/const-enum/conflicting-unpacked.rs");
+
+    // Tests for `#[non_copy]` fields:
+    t.compile_fail("tests/non-copy/invalid-format.rs");
+    t.compile_fail("tests/non-copy/conflicting-packed.rs");
+
+    // Tests for `#[units = ".."]` fields:
+    t.compile_fail("tests/units/conflicting-unpacked.rs");
+
+    // Tests for `#[magic = N]` fields:
+    t.pass("tests/magic/valid-use.rs");
+    t.compile_fail("tests/magic/invalid-format.rs");
+    t.compile_fail("tests/magic/conflicting-unpacked.rs");
+    t.compile_fail("tests/magic/out-of-bounds.rs");
+
+    // Tests for `#[sentinel = N]` fields:
+    t.pass("tests/sentinel/valid-use.rs");
+    t.compile_fail("tests/sentinel/invalid-format.rs");
+    t.compile_fail("tests/sentinel/out-of-bounds.rs");
+    t.compile_fail("tests/sentinel/tuple-struct.rs");
+    t.compile_fail("tests/sentinel/conflicting-unpacked.rs");
+
+    // Tests for `#[tag]` fields:
+    t.pass("tests/tag/valid-use.rs");
+    t.compile_fail("tests/tag/invalid-format.rs");
+    t.compile_fail("tests/tag/multiple-tags.rs");
+    t.compile_fail("tests/tag/tuple-struct.rs");
+    t.compile_fail("tests/tag/conflicting-unpacked.rs");
+
+    // Tests for `#[variants(..)]` fields:
+    t.pass("tests/variants/valid-use.rs");
+    t.compile_fail("tests/variants/invalid-format.rs");
+    t.compile_fail("tests/variants/unknown-variant.rs");
+    t.compile_fail("tests/variants/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(chained_setters)]`:
+    t.pass("tests/chained-setters/valid-use.rs");
+    t.compile_fail("tests/chained-setters/duplicate-param.rs");
+
+    // Tests for `#[bitfield(from_str)]`:
+    t.pass("tests/from-str/valid-use.rs");
+    t.compile_fail("tests/from-str/duplicate-param.rs");
+
+    // Tests for `#[bitfield(decoder)]`:
+    t.pass("tests/decoder/valid-use.rs");
+    t.compile_fail("tests/decoder/duplicate-param.rs");
+
+    // Tests for `#[bitfield(borrowed_view)]`:
+    t.pass("tests/borrowed-view/valid-use.rs");
+    t.compile_fail("tests/borrowed-view/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(rich_errors)]`:
+    t.pass("tests/rich-errors/valid-use.rs");
+    t.compile_fail("tests/rich-errors/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(by_value_getters)]`:
+    t.pass("tests/by-value-getters/valid-use.rs");
+    t.compile_fail("tests/by-value-getters/not-copy.rs");
+    t.compile_fail("tests/by-value-getters/conflicting-unpacked.rs");
+
+    // Tests for `#[bitfield(bcd)]`:
+    t.pass("tests/bcd/valid-use.rs");
+    t.compile_fail("tests/bcd/not-nibble-aligned.rs");
+    t.compile_fail("tests/bcd/conflicting-unpacked.rs");
+    t.compile_fail("tests/bcd/duplicate-param.rs");
+
+    // Tests for `#[bitfield(storage = name)]`:
+    t.pass("tests/storage/ffi-embedding.rs");
+    t.compile_fail("tests/storage/conflicting-unpacked.rs");
+    t.compile_fail("tests/storage/duplicate-param.rs");
+
+    // Tests for `#[bitfield(max_align)]`:
+    t.pass("tests/max-align/valid-use.rs");
+    t.compile_fail("tests/max-align/missing-bits.rs");
+    t.compile_fail("tests/max-align/conflicting-unpacked.rs");
+    t.compile_fail("tests/max-align/conflicting-transparent.rs");
+
+    // Tests for `#[bitfield(deref)]`:
+    t.pass("tests/deref/valid-use.rs");
+    t.compile_fail("tests/deref/multiple-fields.rs");
+    t.compile_fail("tests/deref/packed.rs");
+    t.compile_fail("tests/deref/immutable.rs");
+    t.compile_fail("tests/deref/deref-mut-without-deref.rs");
+
+    // Tests for `#[bitfield(from_field)]`:
+    t.compile_fail("tests/from-field/multiple-fields.rs");
+    t.compile_fail("tests/from-field/repr.rs");
+    t.compile_fail("tests/from-field/wide-field.rs");
+    t.compile_fail("tests/from-field/unpacked.rs");
+
+    // Tests for `#[bitfield(accessors_in_trait)]`:
+    t.pass("tests/accessors-in-trait/valid-use.rs");
+    t.compile_fail("tests/accessors-in-trait/not-imported.rs");
+    t.compile_fail("tests/accessors-in-trait/duplicate-param.rs");
+
+    // Tests for `#[bitfield(impl_trait = Trait(a, set_a))]`:
+    t.pass("tests/impl-trait/valid-use.rs");
+    t.compile_fail("tests/impl-trait/unknown-accessor.rs");
+    t.compile_fail("tests/impl-trait/signature-mismatch.rs");
+    t.compile_fail("tests/impl-trait/skipped-setter.rs");
+    t.compile_fail("tests/impl-trait/duplicate-param.rs");
+    t.compile_fail("tests/impl-trait/conflicting-accessors-in-trait.rs");
+
+    // Tests for `#[bitfield(endian = both)]`:
+    t.pass("tests/endian/valid-use.rs");
+    t.compile_fail("tests/endian/invalid-value.rs");
+    t.compile_fail("tests/endian/duplicate-param.rs");
+
+    // Tests for `#[bitfield(index_by = Enum)]`:
+    t.pass("tests/index-by/valid-use.rs");
+    t.compile_fail("tests/index-by/missing-variant.rs");
+    t.compile_fail("tests/index-by/duplicate-param.rs");
+
+    // Tests for `#[bitfield(float_view(mantissa = m, exponent = e))]`:
+    t.pass("tests/float-view/valid-use.rs");
+    t.compile_fail("tests/float-view/unknown-field.rs");
+    t.compile_fail("tests/float-view/invalid-field-type.rs");
+    t.compile_fail("tests/float-view/missing-exponent.rs");
+
+    // Tests for bitfields within `no_std` crates:
+    t.pass("tests/no-std/valid-use.rs");
+
+    // Tests for `try_from_le_bytes_all` with the `alloc` crate feature:
+    #[cfg(feature = "alloc")]
+    t.pass("tests/alloc/try-from-bytes-all.rs");
+
+    // Tests for `from_field_map` with the `std` crate feature:
+    #[cfg(feature = "std")]
+    t.pass("tests/field-map/valid-use.rs");
+
+    // Tests for `std::error::Error` impls with the `std` crate feature:
+    #[cfg(feature = "std")]
+    t.pass("tests/std/error-trait.rs");
+
+    // Tests for `#[bitfield(serde)]` with the `serde` crate feature:
+    #[cfg(feature = "serde")]
+    t.pass("tests/serde/postcard.rs");
+    #[cfg(not(feature = "serde"))]
+    t.compile_fail("tests/serde/missing-feature.rs");
+
+    // Tests for `store_into` and `load_from` with the `atomic` crate feature:
+    #[cfg(feature = "atomic")]
+    t.pass("tests/atomic/store-load.rs");
+
+    // Tests for `extend_le_into` with the `heapless` crate feature:
+    #[cfg(feature = "heapless")]
+    t.pass("tests/heapless/extend-le-into.rs");
+
+    // Tests for `#[derive(defmt::Format)]` with the `defmt` crate feature:
+    #[cfg(feature = "defmt")]
+    t.pass("tests/defmt/valid-use.rs");
+
+    // Tests for raw field accessors:
+    t.pass("tests/raw-accessors/get-raw.rs");
+    t.pass("tests/raw-accessors/get-checked.rs");
+    t.pass("tests/raw-accessors/set-raw.rs");
+    t.pass("tests/raw-accessors/with-raw.rs");
+
+    // Tests for `assert_bitfield_layout!`:
+    t.pass("tests/layout/assert-layout.rs");
+    t.compile_fail("tests/layout/wrong-layout.rs");
+    t.pass("tests/layout/same-layout.rs");
+    t.compile_fail("tests/layout/different-layout.rs");
+    t.pass("tests/layout/field-ranges.rs");
+    t.pass("tests/layout/field-bits.rs");
+    t.pass("tests/layout/field-mask.rs");
+    t.compile_fail("tests/layout/unknown-mask-field.rs");
+    t.compile_fail("tests/layout/colliding-masks.rs");
+    t.pass("tests/layout/layout-struct.rs");
+    t.compile_fail("tests/layout/duplicate-param.rs");
+
+    // Tests for `#[bitfield(packed = false)]`:
+    t.pass("tests/unpacked/get-ref.rs");
+    t.pass("tests/unpacked/non-copy-clone.rs");
+    t.pass("tests/unpacked/const-getters.rs");
+    t.pass("tests/unpacked/byte-aligned-conversions.rs");
+    t.pass("tests/unpacked/byte-aligned-fast-path.rs");
+    t.pass("tests/unpacked/big-endian.rs");
+    t.pass("tests/unpacked/repr-round-trip.rs");
 }
@@ -0,0 +1,29 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Packed {
+    flag: bool,
+    mode: Mode,
+    level: B5,
+}
+
+fn main() {
+    let packed = Packed::new().with_mode(Mode::Idle).with_level(17);
+    assert_eq!(packed.get_mode_checked(), Some(Mode::Idle));
+    assert_eq!(packed.get_level_checked(), Some(17));
+    assert_eq!(packed.get_flag_checked(), Some(false));
+
+    // Untrusted bytes with the invalid `0b11` pattern for `mode`.
+    let corrupt = Packed::from_le_bytes([0b0000_0111]);
+    assert_eq!(corrupt.get_mode_checked(), None);
+    assert_eq!(corrupt.get_flag_checked(), Some(true));
+    assert_eq!(corrupt.get_level_checked(), Some(0));
+}
@@ -0,0 +1,42 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Packed {
+    flag: bool,
+    mode: Mode,
+    level: B5,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    mode: Mode,
+    level: B5,
+}
+
+fn main() {
+    let packed = Packed::new()
+        .with_flag(true)
+        .with_mode(Mode::Active)
+        .with_level(17);
+    assert_eq!(packed.get_flag_raw(), 1);
+    assert_eq!(packed.get_mode_raw(), 2);
+    assert_eq!(packed.get_level_raw(), 17);
+
+    // The raw getter bypasses the validation of the invalid `0b11` pattern.
+    let invalid = Packed::from_le_bytes([0b0000_0110]);
+    assert!(invalid.mode_or_err().is_err());
+    assert_eq!(invalid.get_mode_raw(), 0b11);
+
+    let unpacked = Unpacked::new().with_mode(Mode::Idle).with_level(5);
+    assert_eq!(unpacked.get_mode_raw(), 1);
+    assert_eq!(unpacked.get_level_raw(), 5);
+}
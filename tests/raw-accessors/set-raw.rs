@@ -0,0 +1,37 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[bitfield]
+pub struct Packed {
+    flag: bool,
+    mode: Mode,
+    level: B5,
+}
+
+fn main() {
+    let mut packed = Packed::new();
+
+    // Set an enum field from its raw discriminant.
+    packed.set_mode_raw(2);
+    assert_eq!(packed.mode(), Mode::Active);
+    assert_eq!(packed.get_mode_raw(), 2);
+
+    // Invalid bit patterns are written as is.
+    packed.set_mode_raw(0b11);
+    assert!(packed.mode_or_err().is_err());
+
+    // Bits beyond the bit width of the field are masked off.
+    packed.set_level_raw(0b1110_0101);
+    assert_eq!(packed.level(), 0b0_0101);
+    packed.set_flag_raw(0b10);
+    assert!(!packed.flag());
+    assert_eq!(packed.get_mode_raw(), 0b11);
+    assert_eq!(packed.to_le_bytes(), [0b0010_1110]);
+}
@@ -0,0 +1,40 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Sleep = 0,
+    Idle = 1,
+    Active = 2,
+}
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low = 0,
+    High = 1,
+}
+
+#[bitfield]
+pub struct Packed {
+    mode: Mode,
+    priority: Priority,
+    level: B5,
+}
+
+fn main() {
+    // Build the bitfield fluently from raw enum discriminants.
+    let packed = Packed::new().with_mode_raw(2).with_priority_raw(1).with_level_raw(0b1_0101);
+    assert_eq!(packed.mode(), Mode::Active);
+    assert_eq!(packed.priority(), Priority::High);
+    assert_eq!(packed.level(), 0b1_0101);
+
+    // Bits beyond the bit width of the field are masked off.
+    let packed = Packed::new().with_mode_raw(0b101).with_priority_raw(0b10);
+    assert_eq!(packed.mode(), Mode::Idle);
+    assert_eq!(packed.priority(), Priority::Low);
+    assert_eq!(packed.level(), 0);
+
+    // Invalid bit patterns are written as is.
+    let packed = Packed::new().with_mode_raw(0b11);
+    assert!(packed.mode_or_err().is_err());
+}
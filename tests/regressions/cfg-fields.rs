@@ -0,0 +1,64 @@
+// Fields annotated with `#[cfg(..)]` are only part of the bitfield if their
+// configuration predicate holds, including their accessors and bit offsets.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Disabled {
+    low: B4,
+    #[cfg(any())]
+    hidden: B8,
+    high: B4,
+}
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Enabled {
+    low: B4,
+    #[cfg(all())]
+    shown: B8,
+    high: B4,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct UnpackedDisabled {
+    low: B2,
+    #[cfg(any())]
+    hidden: B4,
+    high: B6,
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone)]
+pub struct UnpackedEnabled {
+    low: B2,
+    #[cfg(all())]
+    shown: B4,
+    high: B4,
+}
+
+fn main() {
+    let disabled = Disabled::new().with_low(0x1).with_high(0x2);
+    assert_eq!(format!("{:?}", disabled), "Disabled { low: 1, high: 2 }");
+    assert_eq!(disabled.to_le_bytes(), [0x21]);
+
+    let enabled = Enabled::new().with_low(0x1).with_shown(0xFF).with_high(0x2);
+    assert_eq!(enabled.shown(), 0xFF);
+    assert_eq!(
+        format!("{:?}", enabled),
+        "Enabled { low: 1, shown: 255, high: 2 }"
+    );
+    assert_eq!(enabled.to_le_bytes(), [0xF1, 0x2F]);
+
+    let unpacked = UnpackedDisabled::new().with_low(0b11).with_high(0b10_1010);
+    assert_eq!(u16::from(unpacked), 0b1010_1011);
+
+    let unpacked = UnpackedEnabled::new()
+        .with_low(0b01)
+        .with_shown(0b1111)
+        .with_high(0b0001);
+    assert_eq!(unpacked.shown(), 0b1111);
+    assert_eq!(u16::from(unpacked), 0b0001_1111_01);
+}
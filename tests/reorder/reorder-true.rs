@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(reorder = true)]
+pub struct Packet {
+    flag: bool,
+    wide: u16,
+    kind: B7,
+}
+
+fn main() {}
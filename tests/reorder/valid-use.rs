@@ -0,0 +1,53 @@
+// Tests that fields map to bits in declaration order, regardless of how well they pack.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(reorder = false)]
+pub struct Packet {
+    flag: bool,
+    wide: u16,
+    kind: B3,
+    small: B4,
+    #[skip]
+    __: B8,
+}
+
+#[bitfield(bits = 16, packed = false, reorder = false)]
+#[derive(Clone, Copy)]
+pub struct Unpacked {
+    a: B3,
+    b: B9,
+    c: B4,
+}
+
+#[bitfield]
+pub struct Default {
+    flag: bool,
+    wide: u16,
+    kind: B3,
+    small: B4,
+    #[skip]
+    __: B8,
+}
+
+fn main() {
+    assert_eq!(Packet::bit_offset_of("flag"), Some(0));
+    assert_eq!(Packet::bit_offset_of("wide"), Some(1));
+    assert_eq!(Packet::bit_offset_of("kind"), Some(17));
+    assert_eq!(Packet::bit_offset_of("small"), Some(20));
+    assert_eq!(Packet::bit_offset_of("__"), Some(24));
+
+    // Without the parameter the layout is the very same.
+    for name in ["flag", "wide", "kind", "small", "__"] {
+        assert_eq!(Default::bit_offset_of(name), Packet::bit_offset_of(name));
+    }
+
+    let packet = Packet::new().with_flag(true).with_wide(0xFFFF).with_small(0b1010);
+    assert_eq!(packet.to_le_bytes(), [0xFF, 0xFF, 0b1010_0001, 0x00]);
+
+    assert_eq!(Unpacked::bit_offset_of("a"), Some(0));
+    assert_eq!(Unpacked::bit_offset_of("b"), Some(3));
+    assert_eq!(Unpacked::bit_offset_of("c"), Some(12));
+    let unpacked = Unpacked::new().with_a(0b101).with_c(0xF);
+    assert_eq!(u16::from(unpacked), 0xF005);
+}
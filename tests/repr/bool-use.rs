@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = bool)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Enabled {
+    enabled: bool,
+}
+
+fn main() {
+    let on = Enabled::new().with_enabled(true);
+    assert!(bool::from(on));
+    assert!(!bool::from(Enabled::new()));
+
+    let off = Enabled::from(false);
+    assert!(!off.enabled());
+    assert_eq!(Enabled::from(true), on);
+    assert_eq!(on.to_le_bytes(), [0x01]);
+}
@@ -0,0 +1,25 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = [u8; 12])]
+#[derive(Debug, PartialEq, Eq)]
+pub struct Wide {
+    header: B16,
+    payload: B64,
+    checksum: B16,
+}
+
+fn main() {
+    let wide = Wide::new()
+        .with_header(0xABCD)
+        .with_payload(0x0123_4567_89AB_CDEF)
+        .with_checksum(0x1234);
+    let bytes = <[u8; 12]>::from(wide);
+    assert_eq!(
+        bytes,
+        [0xCD, 0xAB, 0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01, 0x34, 0x12]
+    );
+    let wide = Wide::from(bytes);
+    assert_eq!(wide.header(), 0xABCD);
+    assert_eq!(wide.payload(), 0x0123_4567_89AB_CDEF);
+    assert_eq!(wide.checksum(), 0x1234);
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = bool, filled = true)]
+pub struct Enabled {
+    enabled: bool,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = bool)] // Too many bits!
+pub struct Mode {
+    enabled: bool,
+    fast: bool,
+}
+
+fn main() {}
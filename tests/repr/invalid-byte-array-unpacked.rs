@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = [u8; 2], packed = false)]
+pub struct Wide {
+    header: B8,
+    payload: B8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(repr = [u8; 4])] // Too few bytes!
+pub struct Wide {
+    header: B16,
+    payload: B32,
+}
+
+fn main() {}
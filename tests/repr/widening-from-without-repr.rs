@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(widening_from)]
+pub struct NoRepr {
+    low: B4,
+    high: B12,
+}
+
+fn main() {}
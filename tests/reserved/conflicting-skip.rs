@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    #[skip]
+    #[reserved]
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    #[reserved]
+    #[reserved]
+    reserved: B7,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Register {
+    enabled: bool,
+    #[reserved]
+    reserved: B7,
+}
+
+fn main() {
+    let mut register = Register::new();
+    register.set_reserved(0); // ERROR!
+    let _ = register.reserved(); // ERROR!
+}
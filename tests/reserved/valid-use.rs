@@ -0,0 +1,36 @@
+// Tests that `#[reserved]` fields reserve their bits and are checked by `validate`.
+
+use modular_bitfield::{
+    error::NonZeroReserved,
+    prelude::*,
+};
+
+#[bitfield]
+#[derive(Debug)]
+pub struct Register {
+    enabled: bool,
+    #[reserved]
+    reserved_0: B3,
+    mode: B4,
+    #[reserved]
+    reserved_1: B8,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Register>(), 2);
+    let register = Register::new().with_enabled(true).with_mode(0xF);
+    assert_eq!(register.to_le_bytes(), [0xF1, 0x00]);
+    assert_eq!(Register::from_le_bytes([0xF1, 0x00]).validate(), Ok(()));
+    assert_eq!(
+        Register::from_le_bytes([0xF3, 0x00]).validate(),
+        Err(NonZeroReserved { field: "reserved_0" }),
+    );
+    assert_eq!(
+        Register::from_le_bytes([0xF1, 0x80]).validate(),
+        Err(NonZeroReserved { field: "reserved_1" }),
+    );
+    assert_eq!(
+        format!("{:?}", Register::from_le_bytes([0xFF, 0xFF])),
+        "Register { enabled: true, mode: 15 }",
+    );
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, rich_errors)]
+pub struct Config {
+    mode: B3,
+    level: B5,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+// Tests the field context of the errors returned by checked setters with `rich_errors`.
+
+use modular_bitfield::error::{FieldOutOfBounds, OutOfBounds};
+use modular_bitfield::prelude::*;
+
+#[bitfield(rich_errors)]
+#[derive(Debug)]
+pub struct Config {
+    mode: B3,
+    level: B5,
+}
+
+fn configure(mode: u8, level: u8) -> Result<Config, FieldOutOfBounds> {
+    Config::new().with_mode_checked(mode)?.with_level_checked(level)
+}
+
+fn configure_compat(mode: u8, level: u8) -> Result<Config, OutOfBounds> {
+    Ok(configure(mode, level)?)
+}
+
+fn main() {
+    let config = configure(5, 17).unwrap();
+    assert_eq!(config.mode(), 5);
+    assert_eq!(config.level(), 17);
+
+    let error = configure(5, 32).unwrap_err();
+    assert_eq!(error.bitfield, "Config");
+    assert_eq!(error.field, "level");
+    assert_eq!(error.to_string(), "encountered an out of bounds value for field `Config.level`");
+    assert_eq!(configure(8, 0).unwrap_err(), FieldOutOfBounds { bitfield: "Config", field: "mode" });
+
+    let mut config = Config::new();
+    assert_eq!(config.set_mode_checked(8).unwrap_err().field, "mode");
+    assert_eq!(config.set_level_checked(31), Ok(()));
+
+    // The rich error converts into a plain `OutOfBounds`.
+    assert_eq!(configure_compat(9, 0).unwrap_err(), OutOfBounds);
+}
@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Slot {
+    #[sentinel = 0]
+    channel: B4,
+    volume: B4,
+}
+
+fn main() {}
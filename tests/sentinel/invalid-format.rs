@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Slot {
+    #[sentinel = "none"]
+    channel: B4,
+    volume: B4,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Slot {
+    #[sentinel = 0x10]
+    channel: B4,
+    volume: B4,
+}
+
+fn main() {
+    let _ = Slot::new().get_channel();
+}
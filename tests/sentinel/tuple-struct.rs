@@ -0,0 +1,6 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+pub struct Slot(#[sentinel = 0] B4, B4);
+
+fn main() {}
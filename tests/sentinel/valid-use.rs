@@ -0,0 +1,71 @@
+use modular_bitfield::{
+    error::OutOfBounds,
+    prelude::*,
+};
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Clone, Copy)]
+#[bits = 2]
+pub enum Mode {
+    A = 0,
+    B = 1,
+    C = 2,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Slot {
+    #[sentinel = 0xF]
+    channel: B4,
+    #[sentinel = 0]
+    id: B12,
+    #[sentinel = 3]
+    mode: Mode,
+    #[skip(setters)]
+    #[sentinel = 1]
+    flag: bool,
+    #[skip]
+    __: B5,
+}
+
+fn main() {
+    // `new()` zero-initializes, so only the fields with a zero sentinel start out unset.
+    let slot = Slot::new();
+    assert_eq!(slot.get_channel(), Some(0));
+    assert_eq!(slot.get_id(), None);
+    assert_eq!(slot.get_mode(), Some(Mode::A));
+    assert_eq!(slot.get_flag(), Some(false));
+
+    let mut slot = slot
+        .with_channel_opt(None)
+        .with_id_opt(Some(0xABC))
+        .with_mode_opt(None);
+    assert_eq!(slot.get_channel(), None);
+    assert_eq!(slot.channel(), 0xF);
+    assert_eq!(slot.get_id(), Some(0xABC));
+    assert_eq!(slot.id(), 0xABC);
+    assert_eq!(slot.get_mode(), None);
+    assert_eq!(slot.get_mode_raw(), 3);
+
+    slot.set_channel_opt(Some(7));
+    slot.set_id_opt(None);
+    slot.set_mode_opt(Some(Mode::C));
+    assert_eq!(slot.get_channel(), Some(7));
+    assert_eq!(slot.get_id(), None);
+    assert_eq!(slot.get_mode(), Some(Mode::C));
+    assert_eq!(slot.mode(), Mode::C);
+
+    // The sentinel value itself cannot be written as `Some` value.
+    assert_eq!(slot.set_channel_opt_checked(Some(0xF)), Err(OutOfBounds));
+    assert_eq!(slot.set_id_opt_checked(Some(0x1000)), Err(OutOfBounds));
+    assert_eq!(slot.get_channel(), Some(7));
+    assert_eq!(slot.set_channel_opt_checked(Some(0xE)), Ok(()));
+    assert_eq!(slot.get_channel(), Some(0xE));
+    assert_eq!(slot.set_mode_opt_checked(None), Ok(()));
+    assert_eq!(slot.get_mode(), None);
+    let slot = slot.with_id_opt_checked(Some(1)).unwrap();
+    assert_eq!(slot.get_id(), Some(1));
+    assert!(slot.with_id_opt_checked(Some(0)).is_err());
+
+    let slot = Slot::from_le_bytes([0x00, 0x00, 0x04]);
+    assert_eq!(slot.get_flag(), None);
+}
@@ -0,0 +1,64 @@
+// Round trips bitfields through the `postcard` serializer without allocations.
+
+#![no_std]
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    Idle = 0,
+    Active = 1,
+}
+
+#[bitfield(serde)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Header {
+    mode: Mode,
+    kind: B3,
+    len: B12,
+}
+
+#[bitfield(serde, filled = false)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Flags {
+    a: bool,
+    b: B3,
+}
+
+#[bitfield(bits = 16, packed = false, serde)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Unpacked {
+    flag: bool,
+    value: B15,
+}
+
+// The test binary still needs `std` for its runtime, but not in the extern prelude.
+mod runtime {
+    extern crate std;
+}
+
+fn main() {
+    let mut buffer = [0x00_u8; 8];
+
+    let header = Header::new().with_mode(Mode::Active).with_kind(0b101).with_len(0x123);
+    let encoded = postcard::to_slice(&header, &mut buffer).unwrap();
+    // The bytes are encoded without a length prefix.
+    assert_eq!(encoded, &header.to_le_bytes()[..]);
+    let decoded: Header = postcard::from_bytes(encoded).unwrap();
+    assert_eq!(decoded, header);
+
+    let flags = Flags::new().with_a(true).with_b(0b110);
+    let encoded = postcard::to_slice(&flags, &mut buffer).unwrap();
+    assert_eq!(encoded, &[0b1101]);
+    let decoded: Flags = postcard::from_bytes(encoded).unwrap();
+    assert_eq!(decoded, flags);
+    // Undefined bits and missing bytes are rejected.
+    assert!(postcard::from_bytes::<Flags>(&[0xF0]).is_err());
+    assert!(postcard::from_bytes::<Header>(&[0x01]).is_err());
+
+    let unpacked = Unpacked::new().with_flag(true).with_value(0x1234);
+    let encoded = postcard::to_slice(&unpacked, &mut buffer).unwrap();
+    assert_eq!(encoded.len(), 2);
+    let decoded: Unpacked = postcard::from_bytes(encoded).unwrap();
+    assert_eq!(decoded, unpacked);
+}
@@ -0,0 +1,36 @@
+// The error types implement `std::error::Error` with the `std` crate feature.
+
+use modular_bitfield::{
+    error::{
+        NonZeroReserved,
+        OutOfBounds,
+        ValidateError,
+    },
+    prelude::*,
+};
+use std::error::Error;
+
+#[bitfield]
+pub struct Header {
+    len: B12,
+    #[reserved]
+    __: B4,
+}
+
+fn set_len(header: &mut Header, len: u16) -> Result<(), Box<dyn Error>> {
+    header.set_len_checked(len)?;
+    Ok(())
+}
+
+fn main() {
+    let mut header = Header::new();
+    let error = set_len(&mut header, 0x1000).unwrap_err();
+    assert_eq!(error.to_string(), OutOfBounds.to_string());
+    assert!(set_len(&mut header, 0xFFF).is_ok());
+
+    let error = ValidateError::from(NonZeroReserved { field: "__" });
+    assert_eq!(
+        error.source().map(ToString::to_string),
+        Some("encountered nonzero bits in reserved field `__`".to_string()),
+    );
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, storage = raw)]
+pub struct Flags {
+    enabled: bool,
+    priority: B7,
+}
+
+fn main() {}
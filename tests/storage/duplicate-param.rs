@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(storage = raw, storage = data)]
+pub struct Flags {
+    enabled: bool,
+    priority: B7,
+}
+
+fn main() {}
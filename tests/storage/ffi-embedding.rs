@@ -0,0 +1,46 @@
+mod ffi {
+    use modular_bitfield::prelude::*;
+
+    #[bitfield(storage = raw)]
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    pub struct Flags {
+        pub enabled: bool,
+        pub priority: B7,
+    }
+
+    // Raw identifiers are accepted as storage names.
+    #[bitfield(storage = r#type)]
+    pub struct Kind {
+        pub value: u8,
+    }
+
+    #[repr(C)]
+    pub struct Packet {
+        pub header: u16,
+        pub flags: Flags,
+        pub payload: u8,
+    }
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<ffi::Packet>(), 4);
+
+    // The renamed storage is accessible to code outside of the defining module.
+    let mut packet = ffi::Packet {
+        header: 0xCAFE,
+        flags: ffi::Flags { raw: [0b0000_0101] },
+        payload: 0x42,
+    };
+    assert!(packet.flags.enabled());
+    assert_eq!(packet.flags.priority(), 0b10);
+
+    packet.flags.set_priority(0x7F);
+    assert_eq!(packet.flags.raw, [0xFF]);
+    packet.flags.raw[0] = 0x00;
+    assert!(!packet.flags.enabled());
+    assert_eq!(packet.flags.to_le_bytes(), [0x00]);
+
+    let kind = ffi::Kind { r#type: [0x2A] };
+    assert_eq!(kind.value(), 0x2A);
+}
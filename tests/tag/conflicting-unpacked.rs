@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Clone, Copy)]
+pub enum Kind {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Frame {
+    #[tag]
+    kind: Kind,
+    payload: B7,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Kind {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield]
+pub struct Frame {
+    #[tag(kind)]
+    kind: Kind,
+    payload: B7,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Kind {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield]
+pub struct Frame {
+    #[tag]
+    kind: Kind,
+    #[tag]
+    other: Kind,
+    payload: B6,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Kind {
+    A = 0,
+    B = 1,
+}
+
+#[bitfield]
+pub struct Frame(#[tag] Kind, B7);
+
+fn main() {}
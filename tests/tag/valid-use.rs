@@ -0,0 +1,60 @@
+// `#[tag]` fields get helpers to validate and exhaustively match on their value.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Kind {
+    Data = 0,
+    Ack = 1,
+    Nack = 2,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Frame {
+    #[tag]
+    kind: Kind,
+    payload: B6,
+}
+
+#[bitfield]
+#[derive(Clone, Copy)]
+pub struct Named {
+    #[tag]
+    tag: Kind,
+    payload: B6,
+}
+
+fn describe(frame: &Frame) -> &'static str {
+    frame
+        .match_tag(|kind| match kind {
+            Kind::Data => "data",
+            Kind::Ack => "ack",
+            Kind::Nack => "nack",
+        })
+        .unwrap_or("invalid")
+}
+
+fn main() {
+    let frame = Frame::new().with_kind(Kind::Ack).with_payload(5);
+    assert_eq!(frame.tag(), Kind::Ack);
+    assert_eq!(describe(&frame), "ack");
+    assert_eq!(describe(&frame.with_kind(Kind::Nack)), "nack");
+
+    // Valid tags pass the check at construction.
+    let checked = Frame::from_le_bytes([0b0001_0100]).check_tag().unwrap();
+    assert_eq!(checked.tag(), Kind::Data);
+    assert_eq!(checked.payload(), 5);
+
+    // The bit pattern `0b11` is not a valid `Kind` but validating the tag is opt-in.
+    let invalid = Frame::from_le_bytes([0b0000_0011]);
+    assert!(invalid.kind_or_err().is_err());
+    assert!(invalid.check_tag().is_err());
+    assert_eq!(describe(&invalid), "invalid");
+
+    // A field named `tag` keeps its getter.
+    let named = Named::new().with_tag(Kind::Nack);
+    assert_eq!(named.tag(), Kind::Nack);
+    assert_eq!(named.match_tag(|kind| kind == Kind::Nack), Ok(true));
+}
@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent, transparent)]
+pub struct Duplicate {
+    a: u8,
+}
+
+fn main() {}
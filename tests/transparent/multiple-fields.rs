@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent)]
+pub struct Multiple {
+    a: B4,
+    b: B4,
+}
+
+fn main() {}
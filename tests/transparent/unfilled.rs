@@ -0,0 +1,8 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false, transparent)]
+pub struct Unfilled {
+    a: B7,
+}
+
+fn main() {}
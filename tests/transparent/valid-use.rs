@@ -0,0 +1,15 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packed {
+    value: u32,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Packed>(), core::mem::size_of::<[u8; 4]>());
+    assert_eq!(core::mem::align_of::<Packed>(), core::mem::align_of::<[u8; 4]>());
+    let packed = Packed::new().with_value(0xDEAD_BEEF);
+    let bytes: [u8; 4] = unsafe { core::mem::transmute(packed) };
+    assert_eq!(bytes, 0xDEAD_BEEF_u32.to_le_bytes());
+}
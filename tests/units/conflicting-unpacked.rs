@@ -0,0 +1,11 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16, packed = false)]
+#[derive(Debug)]
+pub struct Clock {
+    #[units = "Hz"]
+    freq: B12,
+    divider: B4,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+// Tests the big endian byte conversions of `packed = false` bitfields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Header {
+    value: B10,
+}
+
+fn main() {
+    let header = Header::new().with_value(0x2A5);
+    let bytes = header.clone().to_be_bytes();
+    assert_eq!(bytes, [0x02, 0xA5]);
+    let mut le_bytes = header.clone().to_le_bytes();
+    le_bytes.reverse();
+    assert_eq!(bytes, le_bytes);
+    assert_eq!(Header::from_be_bytes(bytes), Ok(header));
+
+    // The undefined bits are the most significant ones and reside in the first byte.
+    assert!(Header::from_be_bytes([0x10, 0x00]).is_err());
+    assert!(Header::from_be_bytes([0x03, 0xFF]).is_ok());
+    assert!(Header::from_le_bytes([0xFF, 0x03]).is_ok());
+}
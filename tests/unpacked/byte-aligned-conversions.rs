@@ -0,0 +1,50 @@
+// Checks that the shift based conversions of `packed = false` bitfields
+// agree with the byte layout of an equivalent packed bitfield.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield]
+#[derive(Clone)]
+pub struct Packed {
+    r: u8,
+    g: u8,
+    len: u16,
+    tag: B16,
+}
+
+#[bitfield(bits = 60, filled = false, packed = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unpacked {
+    r: u8,
+    g: u8,
+    len: u16,
+    tag: B16,
+}
+
+fn main() {
+    let packed = Packed::new()
+        .with_r(0x01)
+        .with_g(0x02)
+        .with_len(0x0403)
+        .with_tag(0x0605);
+    let unpacked = Unpacked::new()
+        .with_r(0x01)
+        .with_g(0x02)
+        .with_len(0x0403)
+        .with_tag(0x0605);
+
+    let packed_bytes = packed.to_le_bytes();
+    let unpacked_bytes = unpacked.clone().to_le_bytes();
+    assert_eq!(packed_bytes, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    assert_eq!(&unpacked_bytes[..6], &packed_bytes[..]);
+    assert_eq!(&unpacked_bytes[6..], &[0x00, 0x00]);
+    assert_eq!(u64::from(unpacked.clone()), 0x0605_0403_0201);
+
+    let restored = Unpacked::from_le_bytes(unpacked_bytes).unwrap();
+    assert_eq!(restored, unpacked);
+    assert_eq!(restored.r(), 0x01);
+    assert_eq!(restored.g(), 0x02);
+    assert_eq!(restored.len(), 0x0403);
+    assert_eq!(restored.tag(), 0x0605);
+    assert_eq!(Unpacked::from(0x0605_0403_0201_u64), unpacked);
+}
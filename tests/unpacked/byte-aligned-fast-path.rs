@@ -0,0 +1,35 @@
+// Checks that the byte copying `to_le_bytes` of byte-aligned `packed = false` bitfields
+// agrees with the shift based conversion through their primitive representation.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 60, filled = false, packed = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Aligned {
+    r: u8,
+    g: B8,
+    len: u16,
+}
+
+#[bitfield(bits = 124, filled = false, packed = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlignedWide {
+    tag: B24,
+    #[skip]
+    __: u8,
+    value: u32,
+}
+
+fn main() {
+    let aligned = Aligned::new().with_r(0x01).with_g(0x02).with_len(0x0403);
+    assert_eq!(aligned.clone().to_le_bytes(), [0x01, 0x02, 0x03, 0x04, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(aligned.clone().to_le_bytes(), u64::from(aligned.clone()).to_le_bytes());
+    assert_eq!(Aligned::from_le_bytes(aligned.clone().to_le_bytes()), Ok(aligned));
+
+    let wide = AlignedWide::new().with_tag(0x030201).with_value(0x0807_0605);
+    assert_eq!(
+        wide.clone().to_le_bytes(),
+        [0x01, 0x02, 0x03, 0x00, 0x05, 0x06, 0x07, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    );
+    assert_eq!(wide.clone().to_le_bytes(), u128::from(wide.clone()).to_le_bytes());
+}
@@ -0,0 +1,45 @@
+// Getters of unpacked bitfields are `const fn` for all kinds of fields without `#[non_copy]`
+// whether or not the bitfield itself is `Copy`.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+#[bits = 2]
+pub enum Mode {
+    Off = 0,
+    On = 1,
+}
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Control {
+    mode: Mode,
+    level: B6,
+}
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone)]
+pub struct Unique {
+    mode: Mode,
+    level: B6,
+}
+
+const fn mode_of(control: &Control) -> Mode {
+    control.mode()
+}
+
+const fn level_of(control: &Control) -> u8 {
+    control.level()
+}
+
+const fn unique_mode_of(unique: &Unique) -> Mode {
+    unique.mode()
+}
+
+fn main() {
+    let control = Control::new().with_mode(Mode::On).with_level(5);
+    assert_eq!(mode_of(&control), Mode::On);
+    assert_eq!(level_of(&control), 5);
+    let unique = Unique::new().with_mode(Mode::On);
+    assert_eq!(unique_mode_of(&unique), Mode::On);
+}
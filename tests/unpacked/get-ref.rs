@@ -0,0 +1,30 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Sleep,
+    Idle,
+    Active,
+    Turbo,
+}
+
+#[bitfield(bits = 16, packed = false)]
+#[derive(Debug, Clone, Copy)]
+pub struct Control {
+    mode: Mode,
+    level: B6,
+    pair: (B4, B4),
+}
+
+fn main() {
+    let control = Control::new()
+        .with_mode(Mode::Active)
+        .with_level(7)
+        .with_pair((1, 3));
+    let mode: &Mode = control.get_mode_ref();
+    assert_eq!(*mode, Mode::Active);
+    assert_eq!(control.mode(), Mode::Active);
+    // Borrowing getters are generated for all non-primitive specifiers, not only enums.
+    let pair: &(u8, u8) = control.get_pair_ref();
+    assert_eq!(*pair, (1, 3));
+}
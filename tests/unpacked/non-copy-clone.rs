@@ -0,0 +1,57 @@
+use modular_bitfield::{
+    error::{
+        InvalidBitPattern,
+        OutOfBounds,
+    },
+    prelude::*,
+};
+
+/// A specifier value that is `Clone` but deliberately not `Copy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Level {
+    value: u8,
+}
+
+pub enum LevelSpecifier {}
+
+impl Specifier for LevelSpecifier {
+    const BITS: usize = 4;
+    type Bytes = u8;
+    type InOut = Level;
+
+    fn into_bytes(input: Self::InOut) -> Result<Self::Bytes, OutOfBounds> {
+        if input.value >= 16 {
+            return Err(OutOfBounds)
+        }
+        Ok(input.value)
+    }
+
+    fn from_bytes(bytes: Self::Bytes) -> Result<Self::InOut, InvalidBitPattern<Self::Bytes>> {
+        if bytes >= 16 {
+            return Err(InvalidBitPattern::new(bytes))
+        }
+        Ok(Level { value: bytes })
+    }
+}
+
+#[bitfield(bits = 12, filled = false, packed = false)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    #[non_copy]
+    level: LevelSpecifier,
+    extra: B4,
+}
+
+fn main() {
+    let mut settings = Settings::new()
+        .with_level(Level { value: 9 })
+        .with_extra(3);
+    let cloned = settings.clone();
+    assert_eq!(cloned, settings);
+    assert_eq!(cloned.level(), Level { value: 9 });
+    assert_eq!(cloned.get_level_ref(), &Level { value: 9 });
+
+    settings.set_level(Level { value: 2 });
+    assert_eq!(settings.level(), Level { value: 2 });
+    assert_eq!(cloned.level(), Level { value: 9 });
+}
@@ -0,0 +1,43 @@
+// Tests that converting a `packed = false` bitfield into its repr and back
+// preserves the values of all of its fields.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 16, packed = false)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Foo {
+    a: B3,
+    b: B5,
+    c: bool,
+    d: B7,
+}
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Full {
+    value: B8,
+}
+
+fn main() {
+    let foo = Foo::new().with_a(0b101).with_b(0b10011).with_c(true).with_d(0b1011001);
+    let repr = u16::from(foo);
+    assert_eq!(repr, 0b1011001_1_10011_101);
+    let round_trip = Foo::from(repr);
+    assert_eq!(round_trip, foo);
+    assert_eq!(round_trip.a(), 0b101);
+    assert_eq!(round_trip.b(), 0b10011);
+    assert!(round_trip.c());
+    assert_eq!(round_trip.d(), 0b1011001);
+
+    let all_ones = Foo::from(u16::MAX);
+    assert_eq!(all_ones.a(), 0b111);
+    assert_eq!(all_ones.b(), 0b11111);
+    assert!(all_ones.c());
+    assert_eq!(all_ones.d(), 0b1111111);
+    assert_eq!(u16::from(all_ones), u16::MAX);
+
+    // A field spanning the entire repr keeps all of its bits.
+    let full = Full::new().with_value(0xA5);
+    assert_eq!(Full::from(u8::from(full)), full);
+    assert_eq!(Full::from(0xFF).value(), 0xFF);
+}
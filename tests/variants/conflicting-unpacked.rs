@@ -0,0 +1,17 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Clone, Copy)]
+pub enum Mode {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+#[bitfield(bits = 8, packed = false)]
+#[derive(Clone, Copy)]
+pub struct Control {
+    #[variants(Enabled)]
+    mode: Mode,
+    level: B7,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier)]
+pub enum Mode {
+    Disabled = 0,
+    Enabled = 1,
+}
+
+#[bitfield]
+pub struct Control {
+    #[variants(Disabled, Sleeping)]
+    mode: Mode,
+    level: B7,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// `#[variants(..)]` fields get an `is_variant()` predicate per listed variant.
+
+use modular_bitfield::prelude::*;
+
+#[derive(BitfieldSpecifier, Debug, PartialEq)]
+#[bits = 2]
+pub enum Mode {
+    Disabled = 0,
+    Enabled = 1,
+    LowPower = 2,
+}
+
+#[bitfield]
+pub struct Control {
+    #[variants(Disabled, Enabled, LowPower)]
+    mode: Mode,
+    level: B6,
+}
+
+#[bitfield]
+pub struct Pair(#[variants(LowPower)] Mode, B6);
+
+fn main() {
+    let control = Control::new().with_mode(Mode::Enabled);
+    assert!(control.is_enabled());
+    assert!(!control.is_disabled());
+    assert!(!control.is_low_power());
+
+    let control = control.with_mode(Mode::LowPower);
+    assert!(control.is_low_power());
+    assert!(!control.is_enabled());
+
+    // Invalid bit patterns match no variant.
+    let invalid = Control::from_le_bytes([0b0000_0011]);
+    assert!(!invalid.is_disabled());
+    assert!(!invalid.is_enabled());
+    assert!(!invalid.is_low_power());
+
+    let pair = Pair::new().with_0(Mode::LowPower);
+    assert!(pair.is_low_power());
+}
@@ -0,0 +1,10 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(view(Header = 0..1), view(Header = 1..2))]
+pub struct Packet {
+    kind: B4,
+    version: B4,
+    flags: u8,
+}
+
+fn main() {}
@@ -0,0 +1,43 @@
+// Tests the `#[repr(C)]` byte range views generated by `#[bitfield(view(Name = start..end))]`.
+
+use modular_bitfield::prelude::*;
+
+#[bitfield(filled = false, view(Header = 0..2), view(Trailer = 2..4))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packet {
+    kind: B4,
+    version: B4,
+    flags: u8,
+    payload_len: u8,
+    checksum: B7,
+}
+
+fn main() {
+    assert_eq!(core::mem::size_of::<Header>(), 2);
+    assert_eq!(core::mem::align_of::<Header>(), 1);
+    assert_eq!(Header::BYTES, 2);
+    assert_eq!(Trailer::BYTES, 2);
+
+    let mut packet = Packet::new()
+        .with_kind(0x3)
+        .with_version(0xA)
+        .with_flags(0x5C)
+        .with_payload_len(42)
+        .with_checksum(0x7F);
+    let header = packet.header();
+    assert_eq!(header.to_le_bytes(), [0xA3, 0x5C]);
+    assert_eq!(packet.trailer().to_le_bytes(), [42, 0x7F]);
+
+    packet.set_header(Header::from_le_bytes([0x21, 0xFF]));
+    assert_eq!(packet.kind(), 0x1);
+    assert_eq!(packet.version(), 0x2);
+    assert_eq!(packet.flags(), 0xFF);
+    assert_eq!(packet.payload_len(), 42);
+    assert_eq!(packet.checksum(), 0x7F);
+
+    // Bits beyond the bitfield's width are masked off.
+    packet.set_trailer(Trailer::from_le_bytes([7, 0xFF]));
+    assert_eq!(packet.payload_len(), 7);
+    assert_eq!(packet.checksum(), 0x7F);
+    assert_eq!(packet.clone().to_le_bytes()[3], 0x7F);
+}
@@ -0,0 +1,9 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(bits = 8, packed = false, zero_const)]
+pub struct MyField {
+    is_ready: bool,
+    counter: B7,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use modular_bitfield::prelude::*;
+
+#[bitfield(zero_const)]
+#[derive(Debug, PartialEq)]
+pub struct MyField {
+    is_ready: bool,
+    counter: B7,
+    value: u8,
+}
+
+#[bitfield(filled = false, zero_const)]
+#[derive(Debug, PartialEq)]
+pub struct Unfilled {
+    a: B4,
+    b: u8,
+}
+
+const DEFAULT_FIELD: MyField = MyField::ZERO;
+const DEFAULT_FIELDS: [MyField; 2] = [MyField::ZERO, MyField::ZERO];
+
+fn main() {
+    assert_eq!(DEFAULT_FIELD, MyField::new());
+    assert_eq!(DEFAULT_FIELD.to_le_bytes(), [0x00, 0x00]);
+    assert!(!DEFAULT_FIELDS[1].is_ready());
+    assert_eq!(Unfilled::ZERO, Unfilled::new());
+}